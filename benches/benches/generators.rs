@@ -11,7 +11,7 @@ use criterion::measurement::WallTime;
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkGroup, Criterion};
 use rand::prelude::*;
 use rand::rngs::ReseedingRng;
-use rand::rngs::{mock::StepRng, OsRng};
+use rand::rngs::{mock::StepRng, OsRng, Xoshiro256PlusPlusX4, Xoshiro256PlusPlusX8};
 use rand_chacha::rand_core::UnwrapErr;
 use rand_chacha::{ChaCha12Rng, ChaCha20Core, ChaCha20Rng, ChaCha8Rng};
 use rand_pcg::{Pcg32, Pcg64, Pcg64Dxsm, Pcg64Mcg};
@@ -19,7 +19,7 @@ use rand_pcg::{Pcg32, Pcg64, Pcg64Dxsm, Pcg64Mcg};
 criterion_group!(
     name = benches;
     config = Criterion::default();
-    targets = random_bytes, random_u32, random_u64, init_gen, init_from_u64, init_from_seed, reseeding_bytes
+    targets = random_bytes, random_bytes_large, random_u32, random_u64, init_gen, init_from_u64, init_from_seed, reseeding_bytes
 );
 criterion_main!(benches);
 
@@ -49,12 +49,40 @@ pub fn random_bytes(c: &mut Criterion) {
     bench(&mut g, "chacha20", ChaCha20Rng::from_rng(&mut rand::rng()));
     bench(&mut g, "std", StdRng::from_rng(&mut rand::rng()));
     bench(&mut g, "small", SmallRng::from_rng(&mut rand::rng()));
+    bench(&mut g, "xoshiro256pp_x4", Xoshiro256PlusPlusX4::from_rng(&mut rand::rng()));
+    bench(&mut g, "xoshiro256pp_x8", Xoshiro256PlusPlusX8::from_rng(&mut rand::rng()));
     bench(&mut g, "os", UnwrapErr(OsRng));
     bench(&mut g, "thread", rand::rng());
 
     g.finish()
 }
 
+pub fn random_bytes_large(c: &mut Criterion) {
+    const LEN: usize = 1 << 16;
+    let mut g = c.benchmark_group("random_bytes_large");
+    g.warm_up_time(Duration::from_millis(500));
+    g.measurement_time(Duration::from_millis(1000));
+    g.throughput(criterion::Throughput::Bytes(LEN as u64));
+
+    fn bench(g: &mut BenchmarkGroup<WallTime>, name: &str, mut rng: impl Rng) {
+        g.bench_function(name, |b| {
+            let mut buf = vec![0u8; LEN];
+            b.iter(|| {
+                rng.fill_bytes(&mut buf);
+                black_box(&buf);
+            });
+        });
+    }
+
+    bench(&mut g, "chacha8", ChaCha8Rng::from_rng(&mut rand::rng()));
+    bench(&mut g, "chacha12", ChaCha12Rng::from_rng(&mut rand::rng()));
+    bench(&mut g, "chacha20", ChaCha20Rng::from_rng(&mut rand::rng()));
+    bench(&mut g, "xoshiro256pp_x8", Xoshiro256PlusPlusX8::from_rng(&mut rand::rng()));
+    bench(&mut g, "thread", rand::rng());
+
+    g.finish()
+}
+
 pub fn random_u32(c: &mut Criterion) {
     let mut g = c.benchmark_group("random_u32");
     g.sample_size(1000);
@@ -107,6 +135,8 @@ pub fn random_u64(c: &mut Criterion) {
     bench(&mut g, "chacha20", ChaCha20Rng::from_rng(&mut rand::rng()));
     bench(&mut g, "std", StdRng::from_rng(&mut rand::rng()));
     bench(&mut g, "small", SmallRng::from_rng(&mut rand::rng()));
+    bench(&mut g, "xoshiro256pp_x4", Xoshiro256PlusPlusX4::from_rng(&mut rand::rng()));
+    bench(&mut g, "xoshiro256pp_x8", Xoshiro256PlusPlusX8::from_rng(&mut rand::rng()));
     bench(&mut g, "os", UnwrapErr(OsRng));
     bench(&mut g, "thread", rand::rng());
 
@@ -7,7 +7,7 @@
 // except according to those terms.
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use rand::distr::weighted::WeightedIndex;
+use rand::distr::weighted::{WeightedAliasIndex, WeightedIndex, WeightedTreeIndex};
 use rand::prelude::*;
 use rand::seq::index::sample_weighted;
 
@@ -38,6 +38,44 @@ pub fn bench(c: &mut Criterion) {
         })
     });
 
+    c.bench_function("weighted_tree_index_modification", |b| {
+        let mut rng = rand::rng();
+        let weights = black_box([1u32, 2, 3, 0, 5, 6, 7, 1, 2, 3, 4, 5, 6, 7]);
+        let mut distr = WeightedTreeIndex::new(weights.to_vec()).unwrap();
+        b.iter(|| {
+            distr.update(2, 4).unwrap();
+            distr.update(5, 1).unwrap();
+            rng.sample(&distr)
+        })
+    });
+
+    c.bench_function("weighted_alias_index_creation", |b| {
+        let mut rng = rand::rng();
+        let weights = black_box([1u32, 2, 4, 0, 5, 1, 7, 1, 2, 3, 4, 5, 6, 7]);
+        b.iter(|| {
+            let distr = WeightedAliasIndex::new(weights.to_vec()).unwrap();
+            rng.sample(distr)
+        })
+    });
+
+    for (length, len_name) in [(1000, "1k"), (1_000_000, "1M")] {
+        let weights: Vec<u32> = (0..length).map(|idx| 1 + (idx % 100)).collect();
+
+        let name = format!("weighted_index_sample_{}", len_name);
+        let distr = WeightedIndex::new(&weights).unwrap();
+        c.bench_function(name.as_str(), |b| {
+            let mut rng = SmallRng::from_rng(&mut rand::rng());
+            b.iter(|| rng.sample(&distr))
+        });
+
+        let name = format!("weighted_alias_index_sample_{}", len_name);
+        let distr = WeightedAliasIndex::new(weights).unwrap();
+        c.bench_function(name.as_str(), |b| {
+            let mut rng = SmallRng::from_rng(&mut rand::rng());
+            b.iter(|| rng.sample(&distr))
+        });
+    }
+
     let lens = [
         (1, 1000, "1k"),
         (10, 1000, "1k"),
@@ -0,0 +1,166 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! AVX-512 backend of the wide ChaCha kernel (feature `avx512`).
+//!
+//! `ppv-lite86` stops at AVX2, so this kernel uses `core::arch` directly. Each
+//! 512-bit register holds one state word of all `WIDE_BLOCKS` blocks; the
+//! output is transposed back to block order before it is written.
+#![allow(unsafe_code)]
+
+use crate::guts::{ChaCha, WideOut};
+use core::arch::x86_64::*;
+
+/// Compute the next `WIDE_BLOCKS` blocks from `state` into `out` if the CPU
+/// supports AVX-512F, returning `false` (and writing nothing) otherwise.
+///
+/// Does not advance the block counter of `state`.
+#[inline]
+pub(crate) fn try_refill_wide<O: WideOut>(state: &ChaCha, drounds: u32, out: &mut O) -> bool {
+    if std::is_x86_feature_detected!("avx512f") {
+        // SAFETY: the required target feature was detected above.
+        unsafe { refill_wide(state, drounds, out) };
+        true
+    } else {
+        false
+    }
+}
+
+#[inline(always)]
+unsafe fn quarter_round(x: &mut [__m512i; 16], a: usize, b: usize, c: usize, d: usize) {
+    x[a] = _mm512_add_epi32(x[a], x[b]);
+    x[d] = _mm512_rol_epi32::<16>(_mm512_xor_si512(x[d], x[a]));
+    x[c] = _mm512_add_epi32(x[c], x[d]);
+    x[b] = _mm512_rol_epi32::<12>(_mm512_xor_si512(x[b], x[c]));
+    x[a] = _mm512_add_epi32(x[a], x[b]);
+    x[d] = _mm512_rol_epi32::<8>(_mm512_xor_si512(x[d], x[a]));
+    x[c] = _mm512_add_epi32(x[c], x[d]);
+    x[b] = _mm512_rol_epi32::<7>(_mm512_xor_si512(x[b], x[c]));
+}
+
+// SAFETY: the caller must ensure that AVX-512F is available.
+#[target_feature(enable = "avx512f")]
+unsafe fn refill_wide<O: WideOut>(state: &ChaCha, drounds: u32, out: &mut O) {
+    let b: [u32; 4] = state.b.into();
+    let c: [u32; 4] = state.c.into();
+    let d: [u32; 4] = state.d.into();
+    let words = [
+        0x6170_7865,
+        0x3320_646e,
+        0x7962_2d32,
+        0x6b20_6574,
+        b[0],
+        b[1],
+        b[2],
+        b[3],
+        c[0],
+        c[1],
+        c[2],
+        c[3],
+        d[0],
+        d[1],
+        d[2],
+        d[3],
+    ];
+
+    let mut init = [_mm512_setzero_si512(); 16];
+    for (v, w) in init.iter_mut().zip(words.iter()) {
+        *v = _mm512_set1_epi32(*w as i32);
+    }
+    // Block `i` uses the 64-bit block counter in words 12 and 13 plus `i`
+    let lanes = _mm512_set_epi32(15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0);
+    let pos_lo = _mm512_add_epi32(init[12], lanes);
+    let carry = _mm512_cmplt_epu32_mask(pos_lo, init[12]);
+    init[13] = _mm512_mask_add_epi32(init[13], carry, init[13], _mm512_set1_epi32(1));
+    init[12] = pos_lo;
+
+    let mut x = init;
+    for _ in 0..drounds {
+        quarter_round(&mut x, 0, 4, 8, 12);
+        quarter_round(&mut x, 1, 5, 9, 13);
+        quarter_round(&mut x, 2, 6, 10, 14);
+        quarter_round(&mut x, 3, 7, 11, 15);
+        quarter_round(&mut x, 0, 5, 10, 15);
+        quarter_round(&mut x, 1, 6, 11, 12);
+        quarter_round(&mut x, 2, 7, 8, 13);
+        quarter_round(&mut x, 3, 4, 9, 14);
+    }
+    for (x, init) in x.iter_mut().zip(init.iter()) {
+        *x = _mm512_add_epi32(*x, *init);
+    }
+
+    // Transpose within 128-bit lanes: `rows[g][r]` holds words `4g..4g + 4`
+    // of block `4k + r` in lane `k`.
+    let mut rows = [[_mm512_setzero_si512(); 4]; 4];
+    for (g, rows) in rows.iter_mut().enumerate() {
+        let x = &x[4 * g..4 * g + 4];
+        let t0 = _mm512_unpacklo_epi32(x[0], x[1]);
+        let t1 = _mm512_unpackhi_epi32(x[0], x[1]);
+        let t2 = _mm512_unpacklo_epi32(x[2], x[3]);
+        let t3 = _mm512_unpackhi_epi32(x[2], x[3]);
+        rows[0] = _mm512_unpacklo_epi64(t0, t2);
+        rows[1] = _mm512_unpackhi_epi64(t0, t2);
+        rows[2] = _mm512_unpacklo_epi64(t1, t3);
+        rows[3] = _mm512_unpackhi_epi64(t1, t3);
+    }
+    // Then transpose the lanes: `blocks[k][r]` is block `4k + r`
+    let mut blocks = [[_mm512_setzero_si512(); 4]; 4];
+    for r in 0..4 {
+        let lo01 = _mm512_shuffle_i32x4::<0x44>(rows[0][r], rows[1][r]);
+        let lo23 = _mm512_shuffle_i32x4::<0x44>(rows[2][r], rows[3][r]);
+        let hi01 = _mm512_shuffle_i32x4::<0xee>(rows[0][r], rows[1][r]);
+        let hi23 = _mm512_shuffle_i32x4::<0xee>(rows[2][r], rows[3][r]);
+        blocks[0][r] = _mm512_shuffle_i32x4::<0x88>(lo01, lo23);
+        blocks[1][r] = _mm512_shuffle_i32x4::<0xdd>(lo01, lo23);
+        blocks[2][r] = _mm512_shuffle_i32x4::<0x88>(hi01, hi23);
+        blocks[3][r] = _mm512_shuffle_i32x4::<0xdd>(hi01, hi23);
+    }
+
+    for (i, block) in blocks.iter().flatten().enumerate() {
+        // x86-64 is little-endian, so this is the byte order of `write_le`.
+        out.write_block(i, |o| _mm512_storeu_si512(o.as_mut_ptr().cast(), *block));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::guts::{ChaCha, WIDE_BLOCKS};
+
+    #[test]
+    fn test_avx512_matches_portable() {
+        if !std::is_x86_feature_detected!("avx512f") {
+            return;
+        }
+        let key = [0x5a; 32];
+        // Positions straddling both halves of the 64-bit counter wrapping
+        for &pos in [0, 7, 0xffff_fffa, u64::MAX - 3].iter() {
+            for &drounds in [4, 6, 10].iter() {
+                let mut state = ChaCha::new(&key, &[1, 2, 3, 4, 5, 6, 7, 8]);
+                state.set_block_pos(pos);
+
+                let mut expected = [0u8; 64 * WIDE_BLOCKS];
+                for block in expected.chunks_exact_mut(64) {
+                    let mut buf = [0u32; 64];
+                    let mut s = state.clone();
+                    s.refill4(drounds, &mut buf);
+                    for (b, w) in block.chunks_exact_mut(4).zip(buf.iter()) {
+                        b.copy_from_slice(&w.to_le_bytes());
+                    }
+                    state.set_block_pos(state.get_block_pos().wrapping_add(1));
+                }
+
+                let mut state = ChaCha::new(&key, &[1, 2, 3, 4, 5, 6, 7, 8]);
+                state.set_block_pos(pos);
+                let mut out = [0u8; 64 * WIDE_BLOCKS];
+                assert!(super::try_refill_wide(&state, drounds, &mut out));
+                assert_eq!(out, expected);
+                assert_eq!(state.get_block_pos(), pos);
+            }
+        }
+    }
+}
@@ -10,19 +10,21 @@
 
 use crate::guts::ChaCha;
 use core::fmt;
+use core::mem::MaybeUninit;
 use rand_core::block::{BlockRng, BlockRngCore, CryptoBlockRng};
+use rand_core::impls::UninitBytes;
 use rand_core::{CryptoRng, RngCore, SeedableRng};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 // NB. this must remain consistent with some currently hard-coded numbers in this module
-const BUF_BLOCKS: u8 = 4;
+pub(crate) const BUF_BLOCKS: u8 = 4;
 // number of 32-bit words per ChaCha block (fixed by algorithm definition)
-const BLOCK_WORDS: u8 = 16;
+pub(crate) const BLOCK_WORDS: u8 = 16;
 
 #[repr(transparent)]
-pub struct Array64<T>([T; 64]);
+pub struct Array64<T>(pub(crate) [T; 64]);
 impl<T> Default for Array64<T>
 where
     T: Default,
@@ -90,6 +92,17 @@ macro_rules! chacha_impl {
             fn generate(&mut self, r: &mut Self::Results) {
                 self.state.refill4($rounds, &mut r.0);
             }
+
+            #[inline]
+            fn generate_bytes(&mut self, dest: &mut [u8]) -> bool {
+                self.state.refill_bytes($rounds, dest);
+                true
+            }
+
+            #[inline]
+            fn generate_uninit(&mut self, dest: &mut UninitBytes<'_>) {
+                self.state.refill_uninit($rounds, dest);
+            }
         }
 
         impl SeedableRng for $ChaChaXCore {
@@ -137,6 +150,9 @@ macro_rules! chacha_impl {
         ///
         /// This implementation uses an output buffer of sixteen `u32` words, and uses
         /// [`BlockRng`] to implement the [`RngCore`] methods.
+        /// The buffer is refilled four blocks at a time. Only large
+        /// [`RngCore::fill_bytes`] and [`RngCore::fill_uninit`] requests bypass
+        /// it, writing sixteen blocks at a time directly to the destination.
         ///
         /// [^1]: D. J. Bernstein, [*ChaCha, a variant of Salsa20*](
         ///       https://cr.yp.to/chacha.html)
@@ -175,6 +191,11 @@ macro_rules! chacha_impl {
             fn fill_bytes(&mut self, bytes: &mut [u8]) {
                 self.rng.fill_bytes(bytes)
             }
+
+            #[inline]
+            fn fill_uninit<'a>(&mut self, bytes: &'a mut [MaybeUninit<u8>]) -> &'a mut [u8] {
+                self.rng.fill_uninit(bytes)
+            }
         }
 
         impl $ChaChaXRng {
@@ -638,6 +659,57 @@ mod test {
         assert_eq!(rng.get_word_pos(), 0);
     }
 
+    #[test]
+    fn test_chacha_fill_bytes_direct() {
+        // Large fills bypass the buffer; output must match the word stream
+        let starts = [0, (1 << 68) - 48];
+        for &start in starts.iter() {
+            for &skip in [0, 1, 17, 63].iter() {
+                for &len in [1024, 1025, 4099, 5000].iter() {
+                    let mut rng1 = ChaChaRng::from_seed([3; 32]);
+                    rng1.set_word_pos(start);
+                    let mut rng2 = rng1.clone();
+                    for _ in 0..skip {
+                        assert_eq!(rng1.next_u32(), rng2.next_u32());
+                    }
+
+                    let mut buf1 = [0u8; 5000];
+                    let mut buf2 = [0u8; 5000];
+                    rng1.fill_bytes(&mut buf1[..len]);
+                    for chunk in buf2[..len].chunks_mut(4) {
+                        let word = rng2.next_u32().to_le_bytes();
+                        chunk.copy_from_slice(&word[..chunk.len()]);
+                    }
+                    assert_eq!(buf1[..], buf2[..]);
+                    assert_eq!(rng1.get_word_pos(), rng2.get_word_pos());
+                    assert_eq!(rng1.next_u32(), rng2.next_u32());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_chacha_fill_uninit() {
+        use core::mem::MaybeUninit;
+
+        for &skip in [0, 1, 17, 63].iter() {
+            for &len in [1024, 1025, 4099, 5000].iter() {
+                let mut rng1 = ChaChaRng::from_seed([3; 32]);
+                let mut rng2 = rng1.clone();
+                for _ in 0..skip {
+                    assert_eq!(rng1.next_u32(), rng2.next_u32());
+                }
+
+                let mut buf1 = [0u8; 5000];
+                let mut buf2 = [MaybeUninit::uninit(); 5000];
+                rng1.fill_bytes(&mut buf1[..len]);
+                assert_eq!(rng2.fill_uninit(&mut buf2[..len]), &buf1[..len]);
+                assert_eq!(rng1.get_word_pos(), rng2.get_word_pos());
+                assert_eq!(rng1.next_u32(), rng2.next_u32());
+            }
+        }
+    }
+
     #[test]
     fn test_trait_objects() {
         use rand_core::CryptoRng;
@@ -15,6 +15,10 @@ pub use ppv_lite86::Machine;
 use ppv_lite86::{
     vec128_storage, ArithOps, BitOps32, LaneWords4, MultiLane, StoreBytes, Vec4, Vec4Ext, Vector,
 };
+use rand_core::impls::UninitBytes;
+
+#[cfg(all(feature = "avx512", target_arch = "x86_64"))]
+use crate::avx512::try_refill_wide as try_refill_wide_avx512;
 
 pub(crate) const BLOCK: usize = 16;
 pub(crate) const BLOCK64: u64 = BLOCK as u64;
@@ -23,6 +27,11 @@ const BUFBLOCKS: u64 = 1 << LOG2_BUFBLOCKS;
 pub(crate) const BUFSZ64: u64 = BLOCK64 * BUFBLOCKS;
 pub(crate) const BUFSZ: usize = BUFSZ64 as usize;
 
+// Number of blocks computed per iteration of the wide (multi-block) kernel
+pub(crate) const WIDE_BLOCKS: usize = 16;
+const BLOCK_BYTES: usize = BLOCK * 4;
+const WIDE_BYTES: usize = BLOCK_BYTES * WIDE_BLOCKS;
+
 const STREAM_PARAM_NONCE: u32 = 1;
 const STREAM_PARAM_BLOCK: u32 = 0;
 
@@ -33,7 +42,7 @@ pub struct ChaCha {
     pub(crate) d: vec128_storage,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Copy)]
 pub struct State<V> {
     pub(crate) a: V,
     pub(crate) b: V,
@@ -81,6 +90,57 @@ impl ChaCha {
         refill_wide(self, drounds, out)
     }
 
+    /// Produce `out.len() / 64` blocks of output directly as bytes, advancing
+    /// the state. Blocks are computed [`WIDE_BLOCKS`] at a time where possible.
+    ///
+    /// Panics if `out.len()` is not a multiple of the block size.
+    #[inline]
+    pub fn refill_bytes(&mut self, drounds: u32, out: &mut [u8]) {
+        assert_eq!(out.len() % BLOCK_BYTES, 0);
+        let mut chunks = out.chunks_exact_mut(WIDE_BYTES);
+        for chunk in &mut chunks {
+            let chunk: &mut [u8; WIDE_BYTES] = chunk.try_into().unwrap();
+            if !try_refill_wide_avx512(self, drounds, chunk) {
+                refill_wide_bytes(self, drounds, chunk);
+            }
+            self.set_block_pos(self.get_block_pos().wrapping_add(WIDE_BLOCKS as u64));
+        }
+        // The remainder is produced via the narrower 4-block kernel
+        for rem in chunks.into_remainder().chunks_mut(BUFSZ * 4) {
+            let pos = self.get_block_pos();
+            let mut buf = [0u32; BUFSZ];
+            refill_wide(self, drounds, &mut buf);
+            for (word, x) in rem.chunks_exact_mut(4).zip(buf.iter()) {
+                word.copy_from_slice(&x.to_le_bytes());
+            }
+            let blocks = (rem.len() / BLOCK_BYTES) as u64;
+            self.set_block_pos(pos.wrapping_add(blocks));
+        }
+    }
+
+    /// As [`ChaCha::refill_bytes`], but writing `out.remaining() / 64`
+    /// blocks to possibly-uninitialized memory. Whole groups of
+    /// [`WIDE_BLOCKS`] are written directly; only a shorter tail is produced
+    /// via a local buffer.
+    ///
+    /// Panics if `out.remaining()` is not a multiple of the block size.
+    #[inline]
+    pub fn refill_uninit(&mut self, drounds: u32, out: &mut UninitBytes<'_>) {
+        assert_eq!(out.remaining() % BLOCK_BYTES, 0);
+        while out.remaining() >= WIDE_BYTES {
+            if !try_refill_wide_avx512(self, drounds, out) {
+                refill_wide_uninit(self, drounds, out);
+            }
+            self.set_block_pos(self.get_block_pos().wrapping_add(WIDE_BLOCKS as u64));
+        }
+        let len = out.remaining();
+        if len > 0 {
+            let mut buf = [0u8; WIDE_BYTES];
+            self.refill_bytes(drounds, &mut buf[..len]);
+            out.write(&buf[..len]);
+        }
+    }
+
     #[inline(always)]
     pub fn set_block_pos(&mut self, value: u64) {
         set_stream_param(self, STREAM_PARAM_BLOCK, value)
@@ -105,6 +165,43 @@ impl ChaCha {
     pub fn get_seed(&self) -> [u8; 32] {
         get_seed(self)
     }
+
+    /// Construct an XChaCha state, deriving a subkey from `key` and the first
+    /// 16 bytes of `nonce` via HChaCha
+    #[inline(always)]
+    pub fn new_x(key: &[u8; 32], nonce: &[u8; 24], drounds: u32) -> Self {
+        init_chacha_x(key, nonce, drounds)
+    }
+
+    /// Set the 32-bit block counter of the RFC 8439 layout
+    #[inline(always)]
+    pub fn set_ietf_block_pos(&mut self, value: u32) {
+        set_state_word(self, 0, value)
+    }
+
+    /// Get the 32-bit block counter of the RFC 8439 layout
+    #[inline(always)]
+    pub fn get_ietf_block_pos(&self) -> u32 {
+        get_state_word(self, 0)
+    }
+
+    /// Set the 96-bit nonce of the RFC 8439 layout
+    #[inline(always)]
+    pub fn set_ietf_nonce(&mut self, nonce: &[u8; 12]) {
+        for (i, chunk) in nonce.chunks_exact(4).enumerate() {
+            set_state_word(self, i as u32 + 1, read_u32le(chunk));
+        }
+    }
+
+    /// Get the 96-bit nonce of the RFC 8439 layout
+    #[inline(always)]
+    pub fn get_ietf_nonce(&self) -> [u8; 12] {
+        let mut nonce = [0u8; 12];
+        for (i, chunk) in nonce.chunks_exact_mut(4).enumerate() {
+            chunk.copy_from_slice(&get_state_word(self, i as u32 + 1).to_le_bytes());
+        }
+        nonce
+    }
 }
 
 // This implementation is platform-independent.
@@ -188,6 +285,103 @@ dispatch!(m, Mach, {
     }
 });
 
+// Number of 4-block groups in the wide kernel
+const WIDE_GROUPS: usize = WIDE_BLOCKS / 4;
+
+// Destination of the wide kernel, which writes its `WIDE_BLOCKS` blocks in
+// order, one block at a time.
+pub(crate) trait WideOut {
+    // Write block `index` of the output via `f`
+    fn write_block(&mut self, index: usize, f: impl FnOnce(&mut [u8; BLOCK_BYTES]));
+}
+
+impl WideOut for [u8; WIDE_BYTES] {
+    #[inline(always)]
+    fn write_block(&mut self, index: usize, f: impl FnOnce(&mut [u8; BLOCK_BYTES])) {
+        let block = &mut self[index * BLOCK_BYTES..][..BLOCK_BYTES];
+        f(block.try_into().unwrap());
+    }
+}
+
+impl WideOut for UninitBytes<'_> {
+    #[inline(always)]
+    fn write_block(&mut self, _index: usize, f: impl FnOnce(&mut [u8; BLOCK_BYTES])) {
+        // `UninitBytes` only accepts initialized bytes, so each block is
+        // staged in a (zeroed) 64-byte temporary and then copied. This copies
+        // one block at a time, not a whole buffer of `WIDE_BLOCKS` blocks.
+        let mut bytes = [0u8; BLOCK_BYTES];
+        f(&mut bytes);
+        self.write(&bytes);
+    }
+}
+
+// Compute `WIDE_BLOCKS` consecutive blocks from `state` as independent groups
+// of four, each in the same layout as `refill_wide_impl`. Working on several
+// groups at once keeps more vector units busy than a single group can.
+#[allow(clippy::many_single_char_names)]
+#[inline(always)]
+fn refill_wide_bytes_impl<Mach: Machine, O: WideOut>(
+    m: Mach,
+    state: &ChaCha,
+    drounds: u32,
+    out: &mut O,
+) {
+    let k = m.vec([0x6170_7865, 0x3320_646e, 0x7962_2d32, 0x6b20_6574]);
+    let b = m.unpack(state.b);
+    let c = m.unpack(state.c);
+    let d: Mach::u32x4 = m.unpack(state.d);
+    let mut init = [State {
+        a: Mach::u32x4x4::from_lanes([k, k, k, k]),
+        b: Mach::u32x4x4::from_lanes([b, b, b, b]),
+        c: Mach::u32x4x4::from_lanes([c, c, c, c]),
+        d: d0123(m, state.d),
+    }; WIDE_GROUPS];
+    for (i, x) in init.iter_mut().enumerate().skip(1) {
+        x.d = d0123(m, add_pos(m, d, 4 * i as u64).into());
+    }
+
+    let mut x = init;
+    for _ in 0..drounds {
+        for x in x.iter_mut() {
+            *x = round(*x);
+        }
+        for x in x.iter_mut() {
+            *x = undiagonalize(round(diagonalize(*x)));
+        }
+    }
+
+    for (i, (x, init)) in x.iter().zip(init.iter()).enumerate() {
+        let (r0, r1, r2, r3) =
+            Mach::u32x4x4::transpose4(x.a + init.a, x.b + init.b, x.c + init.c, x.d + init.d);
+        out.write_block(4 * i, |o| r0.write_le(o));
+        out.write_block(4 * i + 1, |o| r1.write_le(o));
+        out.write_block(4 * i + 2, |o| r2.write_le(o));
+        out.write_block(4 * i + 3, |o| r3.write_le(o));
+    }
+}
+
+// Dispatch selects the widest vector instruction set available at runtime, up
+// to AVX2 on x86-64. AVX-512 is handled by `try_refill_wide_avx512`.
+dispatch!(m, Mach, {
+    fn refill_wide_bytes(state: &ChaCha, drounds: u32, out: &mut [u8; WIDE_BYTES]) {
+        refill_wide_bytes_impl(m, state, drounds, out);
+    }
+});
+
+// As `refill_wide_bytes`, writing the next `WIDE_BYTES` of `out`
+dispatch!(m, Mach, {
+    fn refill_wide_uninit(state: &ChaCha, drounds: u32, out: &mut UninitBytes<'_>) {
+        refill_wide_bytes_impl(m, state, drounds, out);
+    }
+});
+
+// Without the `avx512` feature, always fall back to the `ppv-lite86` kernels
+#[cfg(not(all(feature = "avx512", target_arch = "x86_64")))]
+#[inline(always)]
+fn try_refill_wide_avx512<O: WideOut>(_state: &ChaCha, _drounds: u32, _out: &mut O) -> bool {
+    false
+}
+
 // Single-block, rounds-only; shared by try_apply_keystream for tails shorter than BUFSZ
 // and XChaCha's setup step.
 dispatch!(m, Mach, {
@@ -229,6 +423,20 @@ dispatch_light128!(m, Mach, {
     }
 });
 
+dispatch_light128!(m, Mach, {
+    fn set_state_word(state: &mut ChaCha, index: u32, value: u32) {
+        let d: Mach::u32x4 = m.unpack(state.d);
+        state.d = d.insert(value, index).into();
+    }
+});
+
+dispatch_light128!(m, Mach, {
+    fn get_state_word(state: &ChaCha, index: u32) -> u32 {
+        let d: Mach::u32x4 = m.unpack(state.d);
+        d.extract(index)
+    }
+});
+
 dispatch_light128!(m, Mach, {
     fn get_seed(state: &ChaCha) -> [u8; 32] {
         let b: Mach::u32x4 = m.unpack(state.b);
@@ -0,0 +1,419 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The ChaCha random number generator with the IETF (RFC 8439) layout.
+
+use crate::chacha::{Array64, BLOCK_WORDS, BUF_BLOCKS};
+use crate::guts::ChaCha;
+use core::fmt;
+use core::mem::MaybeUninit;
+use rand_core::block::{BlockRng, BlockRngCore, CryptoBlockRng};
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+macro_rules! chacha_ietf_impl {
+    ($ChaChaXCore:ident, $ChaChaXRng:ident, $rounds:expr, $doc:expr, $abst:ident,) => {
+        #[doc=$doc]
+        #[derive(Clone, PartialEq, Eq)]
+        pub struct $ChaChaXCore {
+            state: ChaCha,
+        }
+
+        // Custom Debug implementation that does not expose the internal state
+        impl fmt::Debug for $ChaChaXCore {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "ChaChaXIetfCore {{}}")
+            }
+        }
+
+        impl BlockRngCore for $ChaChaXCore {
+            type Item = u32;
+            type Results = Array64<u32>;
+
+            #[inline]
+            fn generate(&mut self, r: &mut Self::Results) {
+                let nonce = self.state.get_ietf_nonce();
+                self.state.refill4($rounds, &mut r.0);
+                let end = self.state.get_ietf_block_pos();
+                if end < u32::from(BUF_BLOCKS) {
+                    // The 32-bit counter wrapped within this buffer, carrying
+                    // into the nonce. Restore the nonce and regenerate the
+                    // blocks following the wrap.
+                    self.state.set_ietf_nonce(&nonce);
+                    if end > 0 {
+                        let wrapped = end as usize * usize::from(BLOCK_WORDS);
+                        let mut buf = Array64::default();
+                        self.state.set_ietf_block_pos(0);
+                        self.state.refill4($rounds, &mut buf.0);
+                        self.state.set_ietf_nonce(&nonce);
+                        self.state.set_ietf_block_pos(end);
+                        r.0[64 - wrapped..].copy_from_slice(&buf.0[..wrapped]);
+                    }
+                }
+            }
+        }
+
+        impl SeedableRng for $ChaChaXCore {
+            type Seed = [u8; 32];
+
+            #[inline]
+            fn from_seed(seed: Self::Seed) -> Self {
+                $ChaChaXCore {
+                    state: ChaCha::new(&seed, &[0u8; 12]),
+                }
+            }
+        }
+
+        impl CryptoBlockRng for $ChaChaXCore {}
+
+        /// A cryptographically secure random number generator that uses the ChaCha algorithm
+        /// with the IETF block layout.
+        ///
+        /// This is the variant of ChaCha specified by RFC 8439[^1], using a 32-bit block
+        /// counter and a 96-bit nonce in place of the 64-bit counter and 64-bit stream of
+        /// [`ChaCha20Rng`](crate::ChaCha20Rng) and friends. Its output is the ChaCha
+        /// keystream for the given key and nonce, and thus may be validated against the RFC
+        /// test vectors or used to reproduce the keystream of other implementations.
+        ///
+        /// The nonce and counter are initialized to zero but may be set via the `set_nonce`
+        /// and `set_word_pos` methods. The 32-bit counter over 64-byte blocks allows 256 GiB
+        /// of output per nonce; beyond that, the counter wraps and output repeats (the nonce
+        /// is unaffected).
+        ///
+        /// The word layout is:
+        ///
+        /// ```text
+        /// constant  constant  constant  constant
+        /// seed      seed      seed      seed
+        /// seed      seed      seed      seed
+        /// counter   nonce     nonce     nonce
+        /// ```
+        ///
+        /// This implementation uses an output buffer of sixteen `u32` words, and uses
+        /// [`BlockRng`] to implement the [`RngCore`] methods.
+        ///
+        /// [^1]: Y. Nir and A. Langley, [*ChaCha20 and Poly1305 for IETF Protocols*](
+        ///       https://www.rfc-editor.org/rfc/rfc8439)
+        #[derive(Clone, Debug)]
+        pub struct $ChaChaXRng {
+            rng: BlockRng<$ChaChaXCore>,
+        }
+
+        impl SeedableRng for $ChaChaXRng {
+            type Seed = [u8; 32];
+
+            #[inline]
+            fn from_seed(seed: Self::Seed) -> Self {
+                let core = $ChaChaXCore::from_seed(seed);
+                Self {
+                    rng: BlockRng::new(core),
+                }
+            }
+        }
+
+        impl RngCore for $ChaChaXRng {
+            #[inline]
+            fn next_u32(&mut self) -> u32 {
+                self.rng.next_u32()
+            }
+
+            #[inline]
+            fn next_u64(&mut self) -> u64 {
+                self.rng.next_u64()
+            }
+
+            #[inline]
+            fn fill_bytes(&mut self, bytes: &mut [u8]) {
+                self.rng.fill_bytes(bytes)
+            }
+
+            #[inline]
+            fn fill_uninit<'a>(&mut self, bytes: &'a mut [MaybeUninit<u8>]) -> &'a mut [u8] {
+                self.rng.fill_uninit(bytes)
+            }
+        }
+
+        impl $ChaChaXRng {
+            /// Get the offset from the start of the stream, in 32-bit words.
+            ///
+            /// Since the generated blocks are 16 words (2<sup>4</sup>) long and the
+            /// counter is 32-bits, the offset is a 36-bit number. Sub-word offsets are
+            /// not supported, hence the result can simply be multiplied by 4 to get a
+            /// byte-offset.
+            #[inline]
+            pub fn get_word_pos(&self) -> u64 {
+                let buf_start_block = {
+                    let buf_end_block = self.rng.core.state.get_ietf_block_pos();
+                    u32::wrapping_sub(buf_end_block, BUF_BLOCKS.into())
+                };
+                let (buf_offset_blocks, block_offset_words) = {
+                    let buf_offset_words = self.rng.index() as u32;
+                    let blocks_part = buf_offset_words / u32::from(BLOCK_WORDS);
+                    let words_part = buf_offset_words % u32::from(BLOCK_WORDS);
+                    (blocks_part, words_part)
+                };
+                let pos_block = u32::wrapping_add(buf_start_block, buf_offset_blocks);
+                let pos_block_words = u64::from(pos_block) * u64::from(BLOCK_WORDS);
+                pos_block_words + u64::from(block_offset_words)
+            }
+
+            /// Set the offset from the start of the stream, in 32-bit words.
+            ///
+            /// As with `get_word_pos`, we use a 36-bit number. Since the generator
+            /// simply cycles at the end of its period (256 GiB), we ignore the upper
+            /// 28 bits.
+            ///
+            /// To start at block counter `n` as in RFC 8439, set the word position to
+            /// `16 * n`.
+            #[inline]
+            pub fn set_word_pos(&mut self, word_offset: u64) {
+                let block = (word_offset / u64::from(BLOCK_WORDS)) as u32;
+                self.rng.core.state.set_ietf_block_pos(block);
+                self.rng
+                    .generate_and_set((word_offset % u64::from(BLOCK_WORDS)) as usize);
+            }
+
+            /// Set the 96-bit nonce.
+            ///
+            /// This is initialized to zero. The word position is unaffected.
+            #[inline]
+            pub fn set_nonce(&mut self, nonce: [u8; 12]) {
+                self.rng.core.state.set_ietf_nonce(&nonce);
+                if self.rng.index() != 64 {
+                    let wp = self.get_word_pos();
+                    self.set_word_pos(wp);
+                }
+            }
+
+            /// Get the 96-bit nonce.
+            #[inline]
+            pub fn get_nonce(&self) -> [u8; 12] {
+                self.rng.core.state.get_ietf_nonce()
+            }
+
+            /// Get the seed.
+            #[inline]
+            pub fn get_seed(&self) -> [u8; 32] {
+                self.rng.core.state.get_seed()
+            }
+        }
+
+        impl CryptoRng for $ChaChaXRng {}
+
+        impl From<$ChaChaXCore> for $ChaChaXRng {
+            fn from(core: $ChaChaXCore) -> Self {
+                $ChaChaXRng {
+                    rng: BlockRng::new(core),
+                }
+            }
+        }
+
+        impl PartialEq<$ChaChaXRng> for $ChaChaXRng {
+            fn eq(&self, rhs: &$ChaChaXRng) -> bool {
+                let a: $abst::$ChaChaXRng = self.into();
+                let b: $abst::$ChaChaXRng = rhs.into();
+                a == b
+            }
+        }
+        impl Eq for $ChaChaXRng {}
+
+        #[cfg(feature = "serde")]
+        impl Serialize for $ChaChaXRng {
+            fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                $abst::$ChaChaXRng::from(self).serialize(s)
+            }
+        }
+        #[cfg(feature = "serde")]
+        impl<'de> Deserialize<'de> for $ChaChaXRng {
+            fn deserialize<D>(d: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                $abst::$ChaChaXRng::deserialize(d).map(|x| Self::from(&x))
+            }
+        }
+
+        mod $abst {
+            #[cfg(feature = "serde")]
+            use serde::{Deserialize, Serialize};
+
+            // The abstract state of a ChaCha stream, independent of implementation choices. The
+            // comparison and serialization of this object is considered a semver-covered part of
+            // the API.
+            #[derive(Debug, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+            pub(crate) struct $ChaChaXRng {
+                seed: [u8; 32],
+                nonce: [u8; 12],
+                word_pos: u64,
+            }
+
+            impl From<&super::$ChaChaXRng> for $ChaChaXRng {
+                // Forget all information about the input except what is necessary to determine the
+                // outputs of any sequence of pub API calls.
+                fn from(r: &super::$ChaChaXRng) -> Self {
+                    Self {
+                        seed: r.get_seed(),
+                        nonce: r.get_nonce(),
+                        word_pos: r.get_word_pos(),
+                    }
+                }
+            }
+
+            impl From<&$ChaChaXRng> for super::$ChaChaXRng {
+                // Construct one of the possible concrete RNGs realizing an abstract state.
+                fn from(a: &$ChaChaXRng) -> Self {
+                    use rand_core::SeedableRng;
+                    let mut r = Self::from_seed(a.seed);
+                    r.set_nonce(a.nonce);
+                    r.set_word_pos(a.word_pos);
+                    r
+                }
+            }
+        }
+    };
+}
+
+chacha_ietf_impl!(
+    ChaCha20IetfCore,
+    ChaCha20IetfRng,
+    10,
+    "ChaCha with 20 rounds and the IETF (RFC 8439) layout",
+    abstract20,
+);
+chacha_ietf_impl!(
+    ChaCha12IetfCore,
+    ChaCha12IetfRng,
+    6,
+    "ChaCha with 12 rounds and the IETF (RFC 8439) layout",
+    abstract12,
+);
+chacha_ietf_impl!(
+    ChaCha8IetfCore,
+    ChaCha8IetfRng,
+    4,
+    "ChaCha with 8 rounds and the IETF (RFC 8439) layout",
+    abstract8,
+);
+
+#[cfg(test)]
+mod test {
+    use super::{ChaCha12IetfRng, ChaCha20IetfRng, ChaCha8IetfRng};
+    use rand_core::{RngCore, SeedableRng};
+
+    const RFC_KEY: [u8; 32] = [
+        0x00, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0a, 0x0b, 0x0c, 0x0d, 0x0e,
+        0x0f, 0x10, 0x11, 0x12, 0x13, 0x14, 0x15, 0x16, 0x17, 0x18, 0x19, 0x1a, 0x1b, 0x1c, 0x1d,
+        0x1e, 0x1f,
+    ];
+
+    #[test]
+    fn test_chacha_ietf_rfc8439_block() {
+        // RFC 8439 section 2.3.2
+        let mut rng = ChaCha20IetfRng::from_seed(RFC_KEY);
+        rng.set_nonce([0, 0, 0, 0x09, 0, 0, 0, 0x4a, 0, 0, 0, 0]);
+        rng.set_word_pos(16);
+
+        let mut results = [0u32; 16];
+        for i in results.iter_mut() {
+            *i = rng.next_u32();
+        }
+        let expected = [
+            0xe4e7f110, 0x15593bd1, 0x1fdd0f50, 0xc47120a3, 0xc7f4d1c7, 0x0368c033, 0x9aaa2204,
+            0x4e6cd4c3, 0x466482d2, 0x09aa9f07, 0x05d7c214, 0xa2028bd9, 0xd19c12b5, 0xb94e16de,
+            0xe883d0cb, 0x4e3c50a2,
+        ];
+        assert_eq!(results, expected);
+        assert_eq!(rng.get_word_pos(), 32);
+    }
+
+    #[test]
+    fn test_chacha_ietf_zero_nonce() {
+        // With a zero nonce and small counter, the IETF and original layouts coincide
+        let seed = [7; 32];
+        let mut rng1 = ChaCha20IetfRng::from_seed(seed);
+        let mut rng2 = crate::ChaCha20Rng::from_seed(seed);
+        for _ in 0..100 {
+            assert_eq!(rng1.next_u64(), rng2.next_u64());
+        }
+    }
+
+    #[test]
+    fn test_chacha_ietf_nonce() {
+        let nonce = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let mut rng1 = ChaCha12IetfRng::from_seed(RFC_KEY);
+        let mut rng2 = ChaCha12IetfRng::from_seed(RFC_KEY);
+        rng1.set_nonce(nonce);
+        assert_eq!(rng1.get_nonce(), nonce);
+        assert_ne!(rng1.next_u32(), rng2.next_u32());
+
+        // setting the nonce preserves the word position
+        rng2.set_nonce(nonce);
+        assert_eq!(rng1.get_word_pos(), 1);
+        assert_eq!(rng2.get_word_pos(), 1);
+        assert_eq!(rng1.next_u32(), rng2.next_u32());
+        assert_eq!(rng1, rng2);
+    }
+
+    #[test]
+    fn test_chacha_ietf_counter_wrap() {
+        let nonce = [0xff; 12];
+        let mut rng = ChaCha8IetfRng::from_seed(RFC_KEY);
+        rng.set_nonce(nonce);
+        let mut start = [0u32; 32];
+        for x in start.iter_mut() {
+            *x = rng.next_u32();
+        }
+
+        // the last block of the counter range, then wrapping to block 0
+        rng.set_word_pos((u64::from(u32::MAX) - 1) * 16);
+        let mut skipped = [0u32; 32];
+        for x in skipped.iter_mut() {
+            *x = rng.next_u32();
+        }
+        assert_eq!(rng.get_word_pos(), 0);
+        assert_eq!(rng.get_nonce(), nonce);
+        let mut wrapped = [0u32; 32];
+        for x in wrapped.iter_mut() {
+            *x = rng.next_u32();
+        }
+        assert_eq!(wrapped, start);
+        assert_eq!(rng.get_word_pos(), 32);
+    }
+
+    #[test]
+    fn test_chacha_ietf_word_pos() {
+        let mut rng1 = ChaCha20IetfRng::from_seed(RFC_KEY);
+        let mut rng2 = ChaCha20IetfRng::from_seed(RFC_KEY);
+        for _ in 0..37 {
+            rng1.next_u32();
+        }
+        rng2.set_word_pos(37);
+        assert_eq!(rng1.get_word_pos(), 37);
+        assert_eq!(rng1, rng2);
+        assert_eq!(rng1.next_u64(), rng2.next_u64());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_chacha_ietf_serde_roundtrip() {
+        let mut rng = ChaCha20IetfRng::from_seed(RFC_KEY);
+        rng.set_nonce([3; 12]);
+        rng.next_u32();
+
+        let encoded = serde_json::to_string(&rng).unwrap();
+        let mut decoded: ChaCha20IetfRng = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(rng, decoded);
+        assert_eq!(rng.next_u32(), decoded.next_u32());
+    }
+}
@@ -20,6 +20,14 @@
 //! These generators are all deterministic and portable (see [Reproducibility]
 //! in the book), with testing against reference vectors.
 //!
+//! Besides the `ChaCha*Rng` generators using Bernstein's original layout
+//! (64-bit counter, 64-bit stream), the following keystream-compatible
+//! variants are provided:
+//!
+//! -   [`ChaCha20IetfRng`] and friends use the RFC 8439 layout with a
+//!     32-bit counter and 96-bit nonce
+//! -   [`XChaCha20Rng`] and friends use a 192-bit nonce via HChaCha
+//!
 //! ## Cryptographic (secure) usage
 //!
 //! Where secure unpredictable generators are required, it is suggested to use
@@ -85,7 +93,8 @@
     html_favicon_url = "https://www.rust-lang.org/favicon.ico",
     html_root_url = "https://rust-random.github.io/rand/"
 )]
-#![forbid(unsafe_code)]
+#![cfg_attr(not(feature = "avx512"), forbid(unsafe_code))]
+#![cfg_attr(feature = "avx512", deny(unsafe_code))]
 #![deny(missing_docs)]
 #![deny(missing_debug_implementations)]
 #![doc(test(attr(allow(unused_variables), deny(warnings))))]
@@ -93,12 +102,24 @@
 
 pub use rand_core;
 
+#[cfg(all(feature = "avx512", target_arch = "x86_64"))]
+#[clippy::msrv = "1.89"]
+mod avx512;
 mod chacha;
 mod guts;
+mod ietf;
+mod xchacha;
 
 pub use crate::chacha::{
     ChaCha12Core, ChaCha12Rng, ChaCha20Core, ChaCha20Rng, ChaCha8Core, ChaCha8Rng,
 };
+pub use crate::ietf::{
+    ChaCha12IetfCore, ChaCha12IetfRng, ChaCha20IetfCore, ChaCha20IetfRng, ChaCha8IetfCore,
+    ChaCha8IetfRng,
+};
+pub use crate::xchacha::{
+    XChaCha12Core, XChaCha12Rng, XChaCha20Core, XChaCha20Rng, XChaCha8Core, XChaCha8Rng,
+};
 
 /// ChaCha with 20 rounds
 pub type ChaChaRng = ChaCha20Rng;
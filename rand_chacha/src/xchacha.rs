@@ -0,0 +1,399 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The XChaCha random number generator, with a 192-bit nonce.
+
+use crate::chacha::{Array64, BLOCK_WORDS, BUF_BLOCKS};
+use crate::guts::ChaCha;
+use core::fmt;
+use core::mem::MaybeUninit;
+use rand_core::block::{BlockRng, BlockRngCore, CryptoBlockRng};
+use rand_core::impls::UninitBytes;
+use rand_core::{CryptoRng, RngCore, SeedableRng};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+macro_rules! xchacha_impl {
+    ($XChaChaXCore:ident, $XChaChaXRng:ident, $rounds:expr, $doc:expr, $abst:ident,) => {
+        #[doc=$doc]
+        #[derive(Clone, PartialEq, Eq)]
+        pub struct $XChaChaXCore {
+            key: [u8; 32],
+            nonce: [u8; 24],
+            state: ChaCha,
+        }
+
+        impl $XChaChaXCore {
+            // Replace the nonce, deriving a new subkey; the block position is kept.
+            fn set_nonce(&mut self, nonce: [u8; 24]) {
+                let pos = self.state.get_block_pos();
+                self.nonce = nonce;
+                self.state = ChaCha::new_x(&self.key, &nonce, $rounds);
+                self.state.set_block_pos(pos);
+            }
+        }
+
+        // Custom Debug implementation that does not expose the internal state
+        impl fmt::Debug for $XChaChaXCore {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "XChaChaXCore {{}}")
+            }
+        }
+
+        impl BlockRngCore for $XChaChaXCore {
+            type Item = u32;
+            type Results = Array64<u32>;
+
+            #[inline]
+            fn generate(&mut self, r: &mut Self::Results) {
+                self.state.refill4($rounds, &mut r.0);
+            }
+
+            #[inline]
+            fn generate_bytes(&mut self, dest: &mut [u8]) -> bool {
+                self.state.refill_bytes($rounds, dest);
+                true
+            }
+
+            #[inline]
+            fn generate_uninit(&mut self, dest: &mut UninitBytes<'_>) {
+                self.state.refill_uninit($rounds, dest);
+            }
+        }
+
+        impl SeedableRng for $XChaChaXCore {
+            type Seed = [u8; 32];
+
+            #[inline]
+            fn from_seed(seed: Self::Seed) -> Self {
+                let nonce = [0u8; 24];
+                $XChaChaXCore {
+                    key: seed,
+                    nonce,
+                    state: ChaCha::new_x(&seed, &nonce, $rounds),
+                }
+            }
+        }
+
+        impl CryptoBlockRng for $XChaChaXCore {}
+
+        /// A cryptographically secure random number generator that uses the XChaCha
+        /// algorithm.
+        ///
+        /// XChaCha extends ChaCha with a 192-bit nonce[^1]. The seed (key) and the first
+        /// 128 bits of the nonce are mixed by HChaCha (with the same number of rounds) to
+        /// derive a subkey; ChaCha is then run with this subkey, a 64-bit block counter and
+        /// the last 64 bits of the nonce. Its output is the XChaCha keystream for the given
+        /// key and nonce. A nonce this long may safely be chosen at random.
+        ///
+        /// The nonce and counter are initialized to zero but may be set via the `set_nonce`
+        /// and `set_word_pos` methods. Setting the nonce requires re-deriving the subkey,
+        /// which costs about one block of output.
+        ///
+        /// The word layout of the inner ChaCha state is:
+        ///
+        /// ```text
+        /// constant  constant  constant  constant
+        /// subkey    subkey    subkey    subkey
+        /// subkey    subkey    subkey    subkey
+        /// counter   counter   nonce     nonce
+        /// ```
+        ///
+        /// This implementation uses an output buffer of sixteen `u32` words, and uses
+        /// [`BlockRng`] to implement the [`RngCore`] methods.
+        ///
+        /// [^1]: S. Arciszewski, [*XChaCha: eXtended-nonce ChaCha and AEAD_XChaCha20_Poly1305*](
+        ///       https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-xchacha)
+        #[derive(Clone, Debug)]
+        pub struct $XChaChaXRng {
+            rng: BlockRng<$XChaChaXCore>,
+        }
+
+        impl SeedableRng for $XChaChaXRng {
+            type Seed = [u8; 32];
+
+            #[inline]
+            fn from_seed(seed: Self::Seed) -> Self {
+                let core = $XChaChaXCore::from_seed(seed);
+                Self {
+                    rng: BlockRng::new(core),
+                }
+            }
+        }
+
+        impl RngCore for $XChaChaXRng {
+            #[inline]
+            fn next_u32(&mut self) -> u32 {
+                self.rng.next_u32()
+            }
+
+            #[inline]
+            fn next_u64(&mut self) -> u64 {
+                self.rng.next_u64()
+            }
+
+            #[inline]
+            fn fill_bytes(&mut self, bytes: &mut [u8]) {
+                self.rng.fill_bytes(bytes)
+            }
+
+            #[inline]
+            fn fill_uninit<'a>(&mut self, bytes: &'a mut [MaybeUninit<u8>]) -> &'a mut [u8] {
+                self.rng.fill_uninit(bytes)
+            }
+        }
+
+        impl $XChaChaXRng {
+            /// Get the offset from the start of the stream, in 32-bit words.
+            ///
+            /// Since the generated blocks are 16 words (2<sup>4</sup>) long and the
+            /// counter is 64-bits, the offset is a 68-bit number. Sub-word offsets are
+            /// not supported, hence the result can simply be multiplied by 4 to get a
+            /// byte-offset.
+            #[inline]
+            pub fn get_word_pos(&self) -> u128 {
+                let buf_start_block = {
+                    let buf_end_block = self.rng.core.state.get_block_pos();
+                    u64::wrapping_sub(buf_end_block, BUF_BLOCKS.into())
+                };
+                let (buf_offset_blocks, block_offset_words) = {
+                    let buf_offset_words = self.rng.index() as u64;
+                    let blocks_part = buf_offset_words / u64::from(BLOCK_WORDS);
+                    let words_part = buf_offset_words % u64::from(BLOCK_WORDS);
+                    (blocks_part, words_part)
+                };
+                let pos_block = u64::wrapping_add(buf_start_block, buf_offset_blocks);
+                let pos_block_words = u128::from(pos_block) * u128::from(BLOCK_WORDS);
+                pos_block_words + u128::from(block_offset_words)
+            }
+
+            /// Set the offset from the start of the stream, in 32-bit words.
+            ///
+            /// As with `get_word_pos`, we use a 68-bit number. Since the generator
+            /// simply cycles at the end of its period (1 ZiB), we ignore the upper
+            /// 60 bits.
+            #[inline]
+            pub fn set_word_pos(&mut self, word_offset: u128) {
+                let block = (word_offset / u128::from(BLOCK_WORDS)) as u64;
+                self.rng.core.state.set_block_pos(block);
+                self.rng
+                    .generate_and_set((word_offset % u128::from(BLOCK_WORDS)) as usize);
+            }
+
+            /// Set the 192-bit nonce.
+            ///
+            /// This is initialized to zero. The word position is unaffected.
+            #[inline]
+            pub fn set_nonce(&mut self, nonce: [u8; 24]) {
+                let wp = self.get_word_pos();
+                self.rng.core.set_nonce(nonce);
+                if self.rng.index() != 64 {
+                    self.set_word_pos(wp);
+                }
+            }
+
+            /// Get the 192-bit nonce.
+            #[inline]
+            pub fn get_nonce(&self) -> [u8; 24] {
+                self.rng.core.nonce
+            }
+
+            /// Get the seed.
+            #[inline]
+            pub fn get_seed(&self) -> [u8; 32] {
+                self.rng.core.key
+            }
+        }
+
+        impl CryptoRng for $XChaChaXRng {}
+
+        impl From<$XChaChaXCore> for $XChaChaXRng {
+            fn from(core: $XChaChaXCore) -> Self {
+                $XChaChaXRng {
+                    rng: BlockRng::new(core),
+                }
+            }
+        }
+
+        impl PartialEq<$XChaChaXRng> for $XChaChaXRng {
+            fn eq(&self, rhs: &$XChaChaXRng) -> bool {
+                let a: $abst::$XChaChaXRng = self.into();
+                let b: $abst::$XChaChaXRng = rhs.into();
+                a == b
+            }
+        }
+        impl Eq for $XChaChaXRng {}
+
+        #[cfg(feature = "serde")]
+        impl Serialize for $XChaChaXRng {
+            fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                $abst::$XChaChaXRng::from(self).serialize(s)
+            }
+        }
+        #[cfg(feature = "serde")]
+        impl<'de> Deserialize<'de> for $XChaChaXRng {
+            fn deserialize<D>(d: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                $abst::$XChaChaXRng::deserialize(d).map(|x| Self::from(&x))
+            }
+        }
+
+        mod $abst {
+            #[cfg(feature = "serde")]
+            use serde::{Deserialize, Serialize};
+
+            // The abstract state of an XChaCha stream, independent of implementation choices.
+            // The comparison and serialization of this object is considered a semver-covered
+            // part of the API.
+            #[derive(Debug, PartialEq, Eq)]
+            #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+            pub(crate) struct $XChaChaXRng {
+                seed: [u8; 32],
+                nonce: [u8; 24],
+                word_pos: u128,
+            }
+
+            impl From<&super::$XChaChaXRng> for $XChaChaXRng {
+                // Forget all information about the input except what is necessary to determine the
+                // outputs of any sequence of pub API calls.
+                fn from(r: &super::$XChaChaXRng) -> Self {
+                    Self {
+                        seed: r.get_seed(),
+                        nonce: r.get_nonce(),
+                        word_pos: r.get_word_pos(),
+                    }
+                }
+            }
+
+            impl From<&$XChaChaXRng> for super::$XChaChaXRng {
+                // Construct one of the possible concrete RNGs realizing an abstract state.
+                fn from(a: &$XChaChaXRng) -> Self {
+                    use rand_core::SeedableRng;
+                    let mut r = Self::from_seed(a.seed);
+                    r.set_nonce(a.nonce);
+                    r.set_word_pos(a.word_pos);
+                    r
+                }
+            }
+        }
+    };
+}
+
+xchacha_impl!(
+    XChaCha20Core,
+    XChaCha20Rng,
+    10,
+    "XChaCha with 20 rounds",
+    abstract20,
+);
+xchacha_impl!(
+    XChaCha12Core,
+    XChaCha12Rng,
+    6,
+    "XChaCha with 12 rounds",
+    abstract12,
+);
+xchacha_impl!(
+    XChaCha8Core,
+    XChaCha8Rng,
+    4,
+    "XChaCha with 8 rounds",
+    abstract8,
+);
+
+#[cfg(test)]
+mod test {
+    use super::{XChaCha12Rng, XChaCha20Rng};
+    use rand_core::{RngCore, SeedableRng};
+
+    fn test_key() -> [u8; 32] {
+        let mut key = [0u8; 32];
+        for (i, k) in key.iter_mut().enumerate() {
+            *k = 0x80 + i as u8;
+        }
+        key
+    }
+
+    fn test_nonce() -> [u8; 24] {
+        let mut nonce = [0u8; 24];
+        for (i, n) in nonce.iter_mut().enumerate() {
+            *n = 0x40 + i as u8;
+        }
+        nonce[23] = 0x58;
+        nonce
+    }
+
+    #[test]
+    fn test_xchacha_vector() {
+        // draft-irtf-cfrg-xchacha, section A.3.2 (keystream from counter 1)
+        let mut rng = XChaCha20Rng::from_seed(test_key());
+        rng.set_nonce(test_nonce());
+        rng.set_word_pos(16);
+        let mut results = [0u8; 32];
+        rng.fill_bytes(&mut results);
+        let expected = [
+            0x29, 0x62, 0x4b, 0x4b, 0x1b, 0x14, 0x0a, 0xce, 0x53, 0x74, 0x0e, 0x40, 0x5b, 0x21,
+            0x68, 0x54, 0x0f, 0xd7, 0xd6, 0x30, 0xc1, 0xf5, 0x36, 0xfe, 0xcd, 0x72, 0x2f, 0xc3,
+            0xcd, 0xdb, 0xa7, 0xf4,
+        ];
+        assert_eq!(results, expected);
+
+        // the block at counter 0
+        rng.set_word_pos(0);
+        rng.fill_bytes(&mut results);
+        let expected = [
+            0x11, 0x31, 0xce, 0x9a, 0x2a, 0x20, 0xae, 0x0d, 0x67, 0xc8, 0x93, 0x5c, 0x77, 0x89,
+            0xfa, 0x10, 0x25, 0xc9, 0xe5, 0xbb, 0x72, 0x0f, 0xb9, 0x6f, 0x11, 0x35, 0x4f, 0xb9,
+            0x7a, 0xf0, 0xbd, 0x9a,
+        ];
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    fn test_xchacha_nonce() {
+        let mut rng1 = XChaCha12Rng::from_seed(test_key());
+        let mut rng2 = XChaCha12Rng::from_seed(test_key());
+        assert_eq!(rng1.get_seed(), test_key());
+        assert_eq!(rng1.get_nonce(), [0; 24]);
+
+        rng1.set_nonce(test_nonce());
+        assert_eq!(rng1.get_nonce(), test_nonce());
+        assert_ne!(rng1.next_u32(), rng2.next_u32());
+
+        // setting the nonce preserves the word position
+        rng2.set_nonce(test_nonce());
+        assert_eq!(rng2.get_word_pos(), 1);
+        assert_eq!(rng1.next_u64(), rng2.next_u64());
+        assert_eq!(rng1, rng2);
+
+        // changing only the first 16 bytes changes the subkey
+        let mut nonce = test_nonce();
+        nonce[0] ^= 1;
+        rng2.set_nonce(nonce);
+        assert_ne!(rng1.next_u64(), rng2.next_u64());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_xchacha_serde_roundtrip() {
+        let mut rng = XChaCha20Rng::from_seed(test_key());
+        rng.set_nonce(test_nonce());
+        rng.next_u32();
+
+        let encoded = serde_json::to_string(&rng).unwrap();
+        let mut decoded: XChaCha20Rng = serde_json::from_str(&encoded).unwrap();
+        assert_eq!(rng, decoded);
+        assert_eq!(rng.next_u32(), decoded.next_u32());
+    }
+}
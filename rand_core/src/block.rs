@@ -19,6 +19,9 @@
 //! `ReseedingRng` (see [`rand`](https://docs.rs/rand) crate) perform periodic
 //! reseeding with very low overhead.
 //!
+//! For block sources which may fail, [`TryBlockRngCore`] and [`TryBlockRng`]
+//! provide the same for [`TryRngCore`].
+//!
 //! # Example
 //!
 //! ```no_run
@@ -53,9 +56,13 @@
 //! [`BlockRngCore`]: crate::block::BlockRngCore
 //! [`fill_bytes`]: RngCore::fill_bytes
 
-use crate::impls::{fill_via_u32_chunks, fill_via_u64_chunks};
-use crate::{CryptoRng, RngCore, SeedableRng, TryRngCore};
+use crate::impls::{
+    fill_uninit_via_u32_chunks, fill_uninit_via_u64_chunks, fill_via_u32_chunks,
+    fill_via_u64_chunks, UninitBytes,
+};
+use crate::{CryptoRng, RngCore, SeedableRng, TryCryptoRng, TryRngCore};
 use core::fmt;
+use core::mem::{size_of_val, MaybeUninit};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -74,6 +81,51 @@ pub trait BlockRngCore {
 
     /// Generate a new block of results.
     fn generate(&mut self, results: &mut Self::Results);
+
+    /// Generate whole blocks of results directly into `dest`, if supported.
+    ///
+    /// The length of `dest` is a multiple of the size of `Results` in bytes.
+    /// Implementations supporting this write the same output as that of the
+    /// next `n` calls to [`generate`](Self::generate) (where `n` is the
+    /// number of blocks fitting in `dest`), with each result in little-endian
+    /// byte order, and return `true`.
+    ///
+    /// The default implementation generates nothing and returns `false`, in
+    /// which case [`BlockRng`] and [`BlockRng64`] generate via their results
+    /// buffer instead.
+    ///
+    /// Overriding this allows large [`fill_bytes`] requests to skip copying
+    /// through the results buffer, and perhaps to generate several blocks at
+    /// once. See [`generate_uninit`](Self::generate_uninit) for the
+    /// equivalent used by [`fill_uninit`].
+    ///
+    /// [`fill_bytes`]: RngCore::fill_bytes
+    /// [`fill_uninit`]: RngCore::fill_uninit
+    #[inline]
+    fn generate_bytes(&mut self, dest: &mut [u8]) -> bool {
+        let _ = dest;
+        false
+    }
+
+    /// Generate whole blocks of results directly into `dest`, if supported.
+    ///
+    /// This is the equivalent of [`generate_bytes`](Self::generate_bytes)
+    /// for possibly-uninitialized memory. `dest.remaining()` is a multiple of
+    /// the size of `Results` in bytes. Implementations supporting this write
+    /// whole blocks of output (in the same format as `generate_bytes`) with
+    /// [`UninitBytes::write`]; any bytes not written are generated via the
+    /// results buffer instead.
+    ///
+    /// The default implementation writes nothing.
+    ///
+    /// Overriding this allows large [`fill_uninit`] requests to skip copying
+    /// through the results buffer, without first initializing `dest`.
+    ///
+    /// [`fill_uninit`]: RngCore::fill_uninit
+    #[inline]
+    fn generate_uninit(&mut self, dest: &mut UninitBytes<'_>) {
+        let _ = dest;
+    }
 }
 
 /// A marker trait used to indicate that an [`RngCore`] implementation is
@@ -82,6 +134,38 @@ pub trait BlockRngCore {
 /// See [`CryptoRng`] docs for more information.
 pub trait CryptoBlockRng: BlockRngCore {}
 
+/// A fallible version of [`BlockRngCore`].
+///
+/// This is intended for block sources which may fail, for example those
+/// backed by hardware or by a file. Such a source can be wrapped in
+/// [`TryBlockRng`] to implement [`TryRngCore`].
+///
+/// A type may implement both this trait and [`BlockRngCore`].
+pub trait TryBlockRngCore {
+    /// Results element type, e.g. `u32`.
+    type Item;
+
+    /// Results type. This is the 'block' an RNG implementing
+    /// `TryBlockRngCore` generates, which will usually be an array like
+    /// `[u32; 16]`.
+    type Results: AsRef<[Self::Item]> + AsMut<[Self::Item]> + Default;
+
+    /// The type returned in the event of an RNG error.
+    type Error: fmt::Debug + fmt::Display;
+
+    /// Try to generate a new block of results.
+    ///
+    /// On error, the contents of `results` are unspecified; they will not be
+    /// used.
+    fn try_generate(&mut self, results: &mut Self::Results) -> Result<(), Self::Error>;
+}
+
+/// A marker trait used to indicate that a [`TryRngCore`] implementation is
+/// supposed to be cryptographically secure.
+///
+/// See [`CryptoRng`] docs for more information.
+pub trait TryCryptoBlockRng: TryBlockRngCore {}
+
 /// A wrapper type implementing [`RngCore`] for some type implementing
 /// [`BlockRngCore`] with `u32` array buffer; i.e. this can be used to implement
 /// a full RNG from just a `generate` function.
@@ -96,8 +180,9 @@ pub trait CryptoBlockRng: BlockRngCore {}
 ///
 /// `BlockRng` has heavily optimized implementations of the [`RngCore`] methods
 /// reading values from the results buffer, as well as
-/// calling [`BlockRngCore::generate`] directly on the output array when
-/// [`fill_bytes`] is called on a large array. These methods also handle
+/// calling [`BlockRngCore::generate_bytes`] (or
+/// [`BlockRngCore::generate_uninit`]) directly on the output array when
+/// [`fill_bytes`] (or [`RngCore::fill_uninit`]) is called on a large array. These methods also handle
 /// the bookkeeping of when to generate a new batch of values.
 ///
 /// No whole generated `u32` values are thrown away and all values are consumed
@@ -220,9 +305,19 @@ impl<R: BlockRngCore<Item = u32>> RngCore for BlockRng<R> {
 
     #[inline]
     fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let block_len = size_of_val(self.results.as_ref());
         let mut read_len = 0;
         while read_len < dest.len() {
             if self.index >= self.results.as_ref().len() {
+                let direct_len = (dest.len() - read_len) / block_len * block_len;
+                if direct_len > 0
+                    && self
+                        .core
+                        .generate_bytes(&mut dest[read_len..read_len + direct_len])
+                {
+                    read_len += direct_len;
+                    continue;
+                }
                 self.generate_and_set(0);
             }
             let (consumed_u32, filled_u8) = fill_via_u32_chunks(
@@ -234,6 +329,30 @@ impl<R: BlockRngCore<Item = u32>> RngCore for BlockRng<R> {
             read_len += filled_u8;
         }
     }
+
+    #[inline]
+    fn fill_uninit<'a>(&mut self, dest: &'a mut [MaybeUninit<u8>]) -> &'a mut [u8] {
+        let block_len = size_of_val(self.results.as_ref());
+        let mut dest = UninitBytes::new(dest);
+        while dest.remaining() > 0 {
+            if self.index >= self.results.as_ref().len() {
+                let direct_len = dest.remaining() / block_len * block_len;
+                if direct_len > 0 {
+                    let filled = dest.filled();
+                    dest.limit(direct_len, |chunk| self.core.generate_uninit(chunk));
+                    if dest.filled() > filled {
+                        continue;
+                    }
+                }
+                self.generate_and_set(0);
+            }
+            let (consumed_u32, _) =
+                fill_uninit_via_u32_chunks(&mut self.results.as_mut()[self.index..], &mut dest);
+
+            self.index += consumed_u32;
+        }
+        dest.into_filled()
+    }
 }
 
 impl<R: BlockRngCore + SeedableRng> SeedableRng for BlockRng<R> {
@@ -262,6 +381,184 @@ impl<R: BlockRngCore + SeedableRng> SeedableRng for BlockRng<R> {
 
 impl<R: CryptoBlockRng + BlockRngCore<Item = u32>> CryptoRng for BlockRng<R> {}
 
+/// A wrapper type implementing [`TryRngCore`] for some type implementing
+/// [`TryBlockRngCore`] with `u32` array buffer.
+///
+/// This is the fallible counterpart of [`BlockRng`] and consumes values in the
+/// same order. Errors from [`TryBlockRngCore::try_generate`] are returned to
+/// the caller without losing or repeating output: after an error, the next
+/// value produced is the one following the last value returned (or written to
+/// the destination of [`try_fill_bytes`]) before the error. In particular, if
+/// [`try_next_u64`] fails after the first half of its result was taken from
+/// the buffer, that half remains available for the next call.
+///
+/// Note that [`try_fill_bytes`] may have consumed values for a part of its
+/// destination when an error is returned.
+///
+/// [`try_next_u64`]: TryRngCore::try_next_u64
+/// [`try_fill_bytes`]: TryRngCore::try_fill_bytes
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        bound = "for<'x> R: Serialize + Deserialize<'x>, for<'x> R::Results: Serialize + Deserialize<'x>"
+    )
+)]
+pub struct TryBlockRng<R: TryBlockRngCore> {
+    results: R::Results,
+    index: usize,
+    /// The *core* part of the RNG, implementing the `try_generate` function.
+    pub core: R,
+}
+
+// Custom Debug implementation that does not expose the contents of `results`.
+impl<R: TryBlockRngCore + fmt::Debug> fmt::Debug for TryBlockRng<R> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        fmt.debug_struct("TryBlockRng")
+            .field("core", &self.core)
+            .field("result_len", &self.results.as_ref().len())
+            .field("index", &self.index)
+            .finish()
+    }
+}
+
+impl<R: TryBlockRngCore> TryBlockRng<R> {
+    /// Create a new `TryBlockRng` from an existing RNG implementing
+    /// `TryBlockRngCore`. Results will be generated on first use.
+    #[inline]
+    pub fn new(core: R) -> TryBlockRng<R> {
+        let results_empty = R::Results::default();
+        TryBlockRng {
+            core,
+            index: results_empty.as_ref().len(),
+            results: results_empty,
+        }
+    }
+
+    /// Get the index into the result buffer.
+    ///
+    /// If this is equal to or larger than the size of the result buffer then
+    /// the buffer is "empty" and `try_generate()` must be called to produce
+    /// new results.
+    #[inline(always)]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Reset the number of available results.
+    /// This will force a new set of results to be generated on next use.
+    #[inline]
+    pub fn reset(&mut self) {
+        self.index = self.results.as_ref().len();
+    }
+
+    /// Try to generate a new set of results immediately, setting the index to
+    /// the given value.
+    ///
+    /// On error, the buffer is left empty.
+    #[inline]
+    pub fn try_generate_and_set(&mut self, index: usize) -> Result<(), R::Error> {
+        let len = self.results.as_ref().len();
+        assert!(index < len);
+        if let Err(e) = self.core.try_generate(&mut self.results) {
+            self.index = len;
+            return Err(e);
+        }
+        self.index = index;
+        Ok(())
+    }
+}
+
+impl<R: TryBlockRngCore<Item = u32>> TryRngCore for TryBlockRng<R> {
+    type Error = R::Error;
+
+    #[inline]
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        if self.index >= self.results.as_ref().len() {
+            self.try_generate_and_set(0)?;
+        }
+
+        let value = self.results.as_ref()[self.index];
+        self.index += 1;
+        Ok(value)
+    }
+
+    #[inline]
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        let read_u64 = |results: &[u32], index| {
+            let data = &results[index..=index + 1];
+            (u64::from(data[1]) << 32) | u64::from(data[0])
+        };
+
+        let len = self.results.as_ref().len();
+
+        let index = self.index;
+        if index < len - 1 {
+            self.index += 2;
+            // Read an u64 from the current index
+            Ok(read_u64(self.results.as_ref(), index))
+        } else if index >= len {
+            self.try_generate_and_set(2)?;
+            Ok(read_u64(self.results.as_ref(), 0))
+        } else {
+            let x = self.results.as_ref()[len - 1];
+            if let Err(e) = self.try_generate_and_set(1) {
+                // Put back the unused half so that it is not lost
+                self.results.as_mut()[len - 1] = x;
+                self.index = len - 1;
+                return Err(e);
+            }
+            let y = u64::from(self.results.as_ref()[0]);
+            Ok((y << 32) | u64::from(x))
+        }
+    }
+
+    #[inline]
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Self::Error> {
+        let mut read_len = 0;
+        while read_len < dest.len() {
+            if self.index >= self.results.as_ref().len() {
+                self.try_generate_and_set(0)?;
+            }
+            let (consumed_u32, filled_u8) = fill_via_u32_chunks(
+                &mut self.results.as_mut()[self.index..],
+                &mut dest[read_len..],
+            );
+
+            self.index += consumed_u32;
+            read_len += filled_u8;
+        }
+        Ok(())
+    }
+}
+
+impl<R: TryBlockRngCore + SeedableRng> SeedableRng for TryBlockRng<R> {
+    type Seed = R::Seed;
+
+    #[inline(always)]
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self::new(R::from_seed(seed))
+    }
+
+    #[inline(always)]
+    fn seed_from_u64(seed: u64) -> Self {
+        Self::new(R::seed_from_u64(seed))
+    }
+
+    #[inline(always)]
+    fn from_rng(rng: &mut impl RngCore) -> Self {
+        Self::new(R::from_rng(rng))
+    }
+
+    #[inline(always)]
+    fn try_from_rng<S: TryRngCore>(rng: &mut S) -> Result<Self, S::Error> {
+        R::try_from_rng(rng).map(Self::new)
+    }
+}
+
+impl<R: TryCryptoBlockRng + TryBlockRngCore<Item = u32>> TryCryptoRng for TryBlockRng<R> {}
+
 /// A wrapper type implementing [`RngCore`] for some type implementing
 /// [`BlockRngCore`] with `u64` array buffer; i.e. this can be used to implement
 /// a full RNG from just a `generate` function.
@@ -328,6 +625,18 @@ impl<R: BlockRngCore> BlockRng64<R> {
         self.index
     }
 
+    /// Whether only the lower half of the result before [`Self::index`]
+    /// has been used
+    ///
+    /// If `true`, the next call to [`next_u32`] returns the upper half of that
+    /// result.
+    ///
+    /// [`next_u32`]: RngCore::next_u32
+    #[inline(always)]
+    pub fn half_used(&self) -> bool {
+        self.half_used
+    }
+
     /// Reset the number of available results.
     /// This will force a new set of results to be generated on next use.
     #[inline]
@@ -382,10 +691,20 @@ impl<R: BlockRngCore<Item = u64>> RngCore for BlockRng64<R> {
 
     #[inline]
     fn fill_bytes(&mut self, dest: &mut [u8]) {
+        let block_len = size_of_val(self.results.as_ref());
         let mut read_len = 0;
         self.half_used = false;
         while read_len < dest.len() {
             if self.index >= self.results.as_ref().len() {
+                let direct_len = (dest.len() - read_len) / block_len * block_len;
+                if direct_len > 0
+                    && self
+                        .core
+                        .generate_bytes(&mut dest[read_len..read_len + direct_len])
+                {
+                    read_len += direct_len;
+                    continue;
+                }
                 self.core.generate(&mut self.results);
                 self.index = 0;
             }
@@ -399,6 +718,33 @@ impl<R: BlockRngCore<Item = u64>> RngCore for BlockRng64<R> {
             read_len += filled_u8;
         }
     }
+
+    #[inline]
+    fn fill_uninit<'a>(&mut self, dest: &'a mut [MaybeUninit<u8>]) -> &'a mut [u8] {
+        let block_len = size_of_val(self.results.as_ref());
+        let mut dest = UninitBytes::new(dest);
+        self.half_used = false;
+        while dest.remaining() > 0 {
+            if self.index >= self.results.as_ref().len() {
+                let direct_len = dest.remaining() / block_len * block_len;
+                if direct_len > 0 {
+                    let filled = dest.filled();
+                    dest.limit(direct_len, |chunk| self.core.generate_uninit(chunk));
+                    if dest.filled() > filled {
+                        continue;
+                    }
+                }
+                self.core.generate(&mut self.results);
+                self.index = 0;
+            }
+
+            let (consumed_u64, _) =
+                fill_uninit_via_u64_chunks(&mut self.results.as_mut()[self.index..], &mut dest);
+
+            self.index += consumed_u64;
+        }
+        dest.into_filled()
+    }
 }
 
 impl<R: BlockRngCore + SeedableRng> SeedableRng for BlockRng64<R> {
@@ -429,8 +775,9 @@ impl<R: CryptoBlockRng + BlockRngCore<Item = u64>> CryptoRng for BlockRng64<R> {
 
 #[cfg(test)]
 mod test {
-    use crate::block::{BlockRng, BlockRng64, BlockRngCore};
-    use crate::{RngCore, SeedableRng};
+    use crate::block::{BlockRng, BlockRng64, BlockRngCore, TryBlockRng, TryBlockRngCore};
+    use crate::impls::UninitBytes;
+    use crate::{RngCore, SeedableRng, TryRngCore};
 
     #[derive(Debug, Clone)]
     struct DummyRng {
@@ -483,6 +830,73 @@ mod test {
         assert_eq!(a, c);
     }
 
+    // As `DummyRng`, but generating directly into byte slices
+    #[derive(Debug, Clone)]
+    struct DirectDummyRng(DummyRng);
+
+    impl BlockRngCore for DirectDummyRng {
+        type Item = u32;
+        type Results = [u32; 16];
+
+        fn generate(&mut self, results: &mut Self::Results) {
+            self.0.generate(results)
+        }
+
+        fn generate_bytes(&mut self, dest: &mut [u8]) -> bool {
+            for chunk in dest.chunks_exact_mut(4) {
+                chunk.copy_from_slice(&self.0.counter.to_le_bytes());
+                self.0.counter = self.0.counter.wrapping_add(3511615421);
+            }
+            true
+        }
+
+        fn generate_uninit(&mut self, dest: &mut UninitBytes<'_>) {
+            // Write only one block, to exercise the fallback for the rest
+            for _ in 0..(64 / 4).min(dest.remaining() / 4) {
+                dest.write(&self.0.counter.to_le_bytes());
+                self.0.counter = self.0.counter.wrapping_add(3511615421);
+            }
+        }
+    }
+
+    #[test]
+    fn blockrng_fill_bytes_direct() {
+        let core = DummyRng::from_seed([1, 2, 3, 4]);
+        let mut rng1 = BlockRng::new(core.clone());
+        let mut rng2 = BlockRng::new(DirectDummyRng(core));
+
+        for &len in [3, 64, 65, 200, 7, 128, 1000].iter() {
+            let mut a = [0u8; 1000];
+            let mut b = [0u8; 1000];
+            rng1.fill_bytes(&mut a[..len]);
+            rng2.fill_bytes(&mut b[..len]);
+            assert_eq!(a, b);
+            assert_eq!(rng1.index(), rng2.index());
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+    }
+
+    #[test]
+    fn blockrng_fill_uninit() {
+        use core::mem::MaybeUninit;
+
+        fn check<R: BlockRngCore<Item = u32> + Clone>(core: R) {
+            let mut rng1 = BlockRng::new(core.clone());
+            let mut rng2 = BlockRng::new(core);
+            for &len in [3, 64, 65, 200, 7, 128, 1000].iter() {
+                let mut a = [0u8; 1000];
+                let mut b = [MaybeUninit::uninit(); 1000];
+                rng1.fill_bytes(&mut a[..len]);
+                assert_eq!(rng2.fill_uninit(&mut b[..len]), &a[..len]);
+                assert_eq!(rng1.next_u32(), rng2.next_u32());
+            }
+        }
+
+        let core = DummyRng::from_seed([1, 2, 3, 4]);
+        check(core.clone());
+        check(DirectDummyRng(core));
+    }
+
     #[derive(Debug, Clone)]
     struct DummyRng64 {
         counter: u64,
@@ -535,4 +949,142 @@ mod test {
         c[12..].copy_from_slice(&rng3.next_u32().to_le_bytes());
         assert_eq!(b, c);
     }
+
+    // As `DummyRng64`, but generating directly into byte slices
+    #[derive(Debug, Clone)]
+    struct DirectDummyRng64(DummyRng64);
+
+    impl BlockRngCore for DirectDummyRng64 {
+        type Item = u64;
+        type Results = [u64; 8];
+
+        fn generate(&mut self, results: &mut Self::Results) {
+            self.0.generate(results)
+        }
+
+        fn generate_bytes(&mut self, dest: &mut [u8]) -> bool {
+            for chunk in dest.chunks_exact_mut(8) {
+                chunk.copy_from_slice(&self.0.counter.to_le_bytes());
+                self.0.counter = self.0.counter.wrapping_add(2781463553396133981);
+            }
+            true
+        }
+
+        fn generate_uninit(&mut self, dest: &mut UninitBytes<'_>) {
+            // Write only one block, to exercise the fallback for the rest
+            for _ in 0..(64 / 8).min(dest.remaining() / 8) {
+                dest.write(&self.0.counter.to_le_bytes());
+                self.0.counter = self.0.counter.wrapping_add(2781463553396133981);
+            }
+        }
+    }
+
+    #[test]
+    fn blockrng64_fill_bytes_direct() {
+        let core = DummyRng64::from_seed([1, 2, 3, 4, 5, 6, 7, 8]);
+        let mut rng1 = BlockRng64::new(core.clone());
+        let mut rng2 = BlockRng64::new(DirectDummyRng64(core));
+
+        for &len in [3, 64, 65, 200, 7, 128, 1000].iter() {
+            let mut a = [0u8; 1000];
+            let mut b = [0u8; 1000];
+            rng1.next_u32();
+            rng2.next_u32();
+            rng1.fill_bytes(&mut a[..len]);
+            rng2.fill_bytes(&mut b[..len]);
+            assert_eq!(a, b);
+            assert_eq!(rng1.index(), rng2.index());
+            assert_eq!(rng1.next_u32(), rng2.next_u32());
+        }
+    }
+
+    #[test]
+    fn blockrng64_fill_uninit() {
+        use core::mem::MaybeUninit;
+
+        fn check<R: BlockRngCore<Item = u64> + Clone>(core: R) {
+            let mut rng1 = BlockRng64::new(core.clone());
+            let mut rng2 = BlockRng64::new(core);
+            for &len in [3, 64, 65, 200, 7, 128, 1000].iter() {
+                let mut a = [0u8; 1000];
+                let mut b = [MaybeUninit::uninit(); 1000];
+                rng1.next_u32();
+                rng2.next_u32();
+                rng1.fill_bytes(&mut a[..len]);
+                assert_eq!(rng2.fill_uninit(&mut b[..len]), &a[..len]);
+                assert_eq!(rng1.next_u32(), rng2.next_u32());
+            }
+        }
+
+        let core = DummyRng64::from_seed([1, 2, 3, 4, 5, 6, 7, 8]);
+        check(core.clone());
+        check(DirectDummyRng64(core));
+    }
+
+    // As `DummyRng`, but failing on request, leaving garbage in the results
+    #[derive(Debug, Clone)]
+    struct FailingDummyRng {
+        inner: DummyRng,
+        fail: bool,
+    }
+
+    #[derive(Debug)]
+    struct DummyError;
+
+    impl core::fmt::Display for DummyError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str("dummy error")
+        }
+    }
+
+    impl TryBlockRngCore for FailingDummyRng {
+        type Item = u32;
+        type Results = [u32; 16];
+        type Error = DummyError;
+
+        fn try_generate(&mut self, results: &mut Self::Results) -> Result<(), DummyError> {
+            if self.fail {
+                results[..8].fill(0xdead_beef);
+                return Err(DummyError);
+            }
+            self.inner.generate(results);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn tryblockrng_errors() {
+        let core = DummyRng::from_seed([1, 2, 3, 4]);
+        let mut rng1 = BlockRng::new(core.clone());
+        let mut rng2 = TryBlockRng::new(FailingDummyRng {
+            inner: core,
+            fail: true,
+        });
+
+        assert!(rng2.try_next_u32().is_err());
+        rng2.core.fail = false;
+        for _ in 0..15 {
+            assert_eq!(rng1.next_u32(), rng2.try_next_u32().unwrap());
+        }
+
+        // u64 straddling the block boundary keeps its first half on error
+        rng2.core.fail = true;
+        assert!(rng2.try_next_u64().is_err());
+        assert!(rng2.try_next_u64().is_err());
+        rng2.core.fail = false;
+        assert_eq!(rng1.next_u64(), rng2.try_next_u64().unwrap());
+
+        // bytes written before an error are consumed
+        let mut a = [0u8; 64];
+        let mut b = [0u8; 64];
+        rng1.fill_bytes(&mut a[..60]);
+        rng2.core.fail = true;
+        assert!(rng2.try_fill_bytes(&mut b).is_err());
+        assert_eq!(a[..60], b[..60]);
+        assert!(rng2.try_fill_bytes(&mut b[..4]).is_err());
+        rng2.core.fail = false;
+        rng2.try_fill_bytes(&mut b).unwrap();
+        rng1.fill_bytes(&mut a);
+        assert_eq!(a, b);
+    }
 }
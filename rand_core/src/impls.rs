@@ -19,6 +19,7 @@
 
 use crate::RngCore;
 use core::cmp::min;
+use core::mem::MaybeUninit;
 use zerocopy::{Immutable, IntoBytes};
 
 /// Implement `next_u64` via `next_u32`, little-endian order.
@@ -146,6 +147,148 @@ pub fn fill_via_u64_chunks(src: &mut [u64], dest: &mut [u8]) -> (usize, usize) {
     fill_via_chunks(src, dest)
 }
 
+/// A buffer of possibly-uninitialized bytes, written in order
+///
+/// Bytes are appended with [`write`](Self::write). The buffer keeps count of
+/// the bytes written so far, which may then be retrieved as an initialized
+/// slice with [`into_filled`](Self::into_filled).
+///
+/// This is the destination of [`BlockRngCore::generate_uninit`].
+///
+/// [`BlockRngCore::generate_uninit`]: crate::block::BlockRngCore::generate_uninit
+#[derive(Debug)]
+pub struct UninitBytes<'a> {
+    // Invariant: `filled <= buf.len()` and `buf[..filled]` is initialized.
+    buf: &'a mut [MaybeUninit<u8>],
+    filled: usize,
+}
+
+impl<'a> UninitBytes<'a> {
+    /// Wrap `buf`, with no bytes written
+    #[inline]
+    pub fn new(buf: &'a mut [MaybeUninit<u8>]) -> Self {
+        UninitBytes { buf, filled: 0 }
+    }
+
+    /// The number of bytes written
+    #[inline]
+    pub fn filled(&self) -> usize {
+        self.filled
+    }
+
+    /// The number of bytes which may still be written
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.buf.len() - self.filled
+    }
+
+    /// Write `bytes` after those already written
+    ///
+    /// # Panics
+    ///
+    /// If `bytes.len() > self.remaining()`.
+    #[inline]
+    pub fn write(&mut self, bytes: &[u8]) {
+        let dest = &mut self.buf[self.filled..][..bytes.len()];
+        for (d, s) in dest.iter_mut().zip(bytes.iter()) {
+            d.write(*s);
+        }
+        self.filled += bytes.len();
+    }
+
+    /// Call `f` on a buffer over the next `len` unwritten bytes
+    ///
+    /// Bytes written by `f` count as written to `self`.
+    ///
+    /// # Panics
+    ///
+    /// If `len > self.remaining()` or if `f` replaces the buffer it is given.
+    pub fn limit<T>(&mut self, len: usize, f: impl FnOnce(&mut UninitBytes<'_>) -> T) -> T {
+        let buf = &mut self.buf[self.filled..][..len];
+        let ptr = buf.as_ptr();
+        let mut inner = UninitBytes::new(buf);
+        let result = f(&mut inner);
+        // Only `inner` can point to this memory, so this check ensures that
+        // the bytes counted by `inner.filled` are ours.
+        assert!(
+            core::ptr::eq(inner.buf.as_ptr(), ptr) && inner.buf.len() == len,
+            "UninitBytes::limit: buffer was replaced"
+        );
+        self.filled += inner.filled;
+        result
+    }
+
+    /// Get the written bytes as an initialized slice
+    #[inline]
+    pub fn into_filled(self) -> &'a mut [u8] {
+        let filled = &mut self.buf[..self.filled];
+        // SAFETY: `MaybeUninit<u8>` has the same layout as `u8`, and the
+        // first `self.filled` bytes have been initialized by `write`.
+        unsafe { &mut *(filled as *mut [MaybeUninit<u8>] as *mut [u8]) }
+    }
+}
+
+/// Fill uninitialized dest from src
+///
+/// As `fill_via_chunks`, but writing to possibly-uninitialized memory.
+fn fill_uninit_via_chunks<T: Observable>(
+    src: &mut [T],
+    dest: &mut UninitBytes<'_>,
+) -> (usize, usize) {
+    let size = core::mem::size_of::<T>();
+    let byte_len = min(core::mem::size_of_val(src), dest.remaining());
+    let num_chunks = (byte_len + size - 1) / size;
+
+    if cfg!(target_endian = "big") {
+        for x in &mut src[..num_chunks] {
+            *x = x.to_le();
+        }
+    }
+
+    dest.write(&<[T]>::as_bytes(&src[..num_chunks])[..byte_len]);
+
+    (num_chunks, byte_len)
+}
+
+/// Implement `fill_uninit` by reading chunks from the output buffer of a
+/// block based RNG.
+///
+/// This is equivalent to [`fill_via_u32_chunks`], except that the `filled_u8`
+/// bytes are written to `dest` after any bytes already written.
+pub fn fill_uninit_via_u32_chunks(src: &mut [u32], dest: &mut UninitBytes<'_>) -> (usize, usize) {
+    fill_uninit_via_chunks(src, dest)
+}
+
+/// Implement `fill_uninit` by reading chunks from the output buffer of a
+/// block based RNG.
+///
+/// This is equivalent to [`fill_via_u64_chunks`], except that the `filled_u8`
+/// bytes are written to `dest` after any bytes already written.
+pub fn fill_uninit_via_u64_chunks(src: &mut [u64], dest: &mut UninitBytes<'_>) -> (usize, usize) {
+    fill_uninit_via_chunks(src, dest)
+}
+
+/// Initialize `dest` with zeros, returning it as an initialized slice.
+pub fn init_zeroed(dest: &mut [MaybeUninit<u8>]) -> &mut [u8] {
+    let mut dest = UninitBytes::new(dest);
+    while dest.remaining() > 0 {
+        let n = min(dest.remaining(), 64);
+        dest.write(&[0; 64][..n]);
+    }
+    dest.into_filled()
+}
+
+/// Implement `fill_uninit` via `fill_bytes`, by first zero-initializing
+/// `dest`.
+pub fn fill_uninit_via_fill_bytes<'a, R: RngCore + ?Sized>(
+    rng: &mut R,
+    dest: &'a mut [MaybeUninit<u8>],
+) -> &'a mut [u8] {
+    let dest = init_zeroed(dest);
+    rng.fill_bytes(dest);
+    dest
+}
+
 /// Implement `next_u32` via `fill_bytes`, little-endian order.
 pub fn next_u32_via_fill<R: RngCore + ?Sized>(rng: &mut R) -> u32 {
     let mut buf = [0; 4];
@@ -203,4 +346,78 @@ mod test {
         assert_eq!(fill_via_u64_chunks(&mut src, &mut dst), (1, 5));
         assert_eq!(dst, [1, 0, 0, 0, 0]);
     }
+
+    #[test]
+    fn test_uninit_bytes() {
+        let mut buf = [MaybeUninit::uninit(); 10];
+        let mut dest = UninitBytes::new(&mut buf);
+        assert_eq!((dest.filled(), dest.remaining()), (0, 10));
+        dest.write(&[1, 2, 3]);
+        dest.write(&[]);
+        assert_eq!((dest.filled(), dest.remaining()), (3, 7));
+        let r = dest.limit(4, |d| {
+            assert_eq!((d.filled(), d.remaining()), (0, 4));
+            d.write(&[4, 5]);
+            7
+        });
+        assert_eq!(r, 7);
+        assert_eq!((dest.filled(), dest.remaining()), (5, 5));
+        assert_eq!(dest.into_filled(), [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_uninit_bytes_overflow() {
+        let mut buf = [MaybeUninit::uninit(); 4];
+        let mut dest = UninitBytes::new(&mut buf);
+        dest.write(&[1, 2]);
+        dest.write(&[3, 4, 5]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_uninit_bytes_limit_overflow() {
+        let mut buf = [MaybeUninit::uninit(); 4];
+        let mut dest = UninitBytes::new(&mut buf);
+        dest.write(&[1, 2]);
+        dest.limit(3, |_| ());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_uninit_bytes_limit_replaced() {
+        let mut buf = [MaybeUninit::uninit(); 4];
+        let mut dest = UninitBytes::new(&mut buf);
+        dest.limit(4, |d| *d = UninitBytes::new(&mut []));
+    }
+
+    #[test]
+    fn test_fill_uninit_via_u32_chunks() {
+        let mut src = [1, 2, 3];
+        let mut buf = [MaybeUninit::uninit(); 11];
+        let mut dest = UninitBytes::new(&mut buf);
+        dest.write(&[9]);
+        assert_eq!(fill_uninit_via_u32_chunks(&mut src, &mut dest), (3, 10));
+        assert_eq!(dest.into_filled(), [9, 1, 0, 0, 0, 2, 0, 0, 0, 3, 0]);
+    }
+
+    #[test]
+    fn test_fill_uninit_via_u64_chunks() {
+        let mut src = [1, 2];
+        let mut buf = [MaybeUninit::uninit(); 20];
+        let mut dest = UninitBytes::new(&mut buf);
+        assert_eq!(fill_uninit_via_u64_chunks(&mut src, &mut dest), (2, 16));
+        assert_eq!(dest.remaining(), 4);
+        assert_eq!(
+            dest.into_filled(),
+            [1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_init_zeroed() {
+        let mut buf = [MaybeUninit::uninit(); 100];
+        assert_eq!(init_zeroed(&mut buf), [0; 100]);
+        assert_eq!(init_zeroed(&mut []), []);
+    }
 }
@@ -38,7 +38,7 @@
 #[cfg(feature = "std")]
 extern crate std;
 
-use core::{fmt, ops::DerefMut};
+use core::{fmt, mem::MaybeUninit, ops::DerefMut};
 
 pub mod block;
 pub mod impls;
@@ -153,6 +153,22 @@ pub trait RngCore {
     /// (e.g. reading past the end of a file that is being used as the
     /// source of randomness).
     fn fill_bytes(&mut self, dst: &mut [u8]);
+
+    /// Fill `dst` with random data, returning it as an initialized slice.
+    ///
+    /// This is equivalent to [`fill_bytes`](Self::fill_bytes) (with the same
+    /// output and effect on the generator's state) except that `dst` need
+    /// not be initialized beforehand. This is useful for filling fresh
+    /// buffers, e.g. the spare capacity of a `Vec<u8>`, without zeroing them
+    /// first.
+    ///
+    /// The default implementation zero-initializes `dst` then calls
+    /// `fill_bytes`; see [`impls::fill_uninit_via_fill_bytes`]. Generators
+    /// able to write to uninitialized memory directly should override this.
+    #[inline]
+    fn fill_uninit<'a>(&mut self, dst: &'a mut [MaybeUninit<u8>]) -> &'a mut [u8] {
+        impls::fill_uninit_via_fill_bytes(self, dst)
+    }
 }
 
 impl<T: DerefMut> RngCore for T
@@ -173,6 +189,11 @@ where
     fn fill_bytes(&mut self, dst: &mut [u8]) {
         self.deref_mut().fill_bytes(dst);
     }
+
+    #[inline]
+    fn fill_uninit<'a>(&mut self, dst: &'a mut [MaybeUninit<u8>]) -> &'a mut [u8] {
+        self.deref_mut().fill_uninit(dst)
+    }
 }
 
 /// A marker trait over [`RngCore`] for securely unpredictable RNGs
@@ -228,6 +249,22 @@ pub trait TryRngCore {
     /// Fill `dest` entirely with random data.
     fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error>;
 
+    /// Fill `dst` entirely with random data, returning it as an initialized
+    /// slice.
+    ///
+    /// This is equivalent to [`try_fill_bytes`](Self::try_fill_bytes) except
+    /// that `dst` need not be initialized beforehand. The default
+    /// implementation zero-initializes `dst` then calls `try_fill_bytes`.
+    #[inline]
+    fn try_fill_uninit<'a>(
+        &mut self,
+        dst: &'a mut [MaybeUninit<u8>],
+    ) -> Result<&'a mut [u8], Self::Error> {
+        let dst = impls::init_zeroed(dst);
+        self.try_fill_bytes(dst)?;
+        Ok(dst)
+    }
+
     /// Wrap RNG with the [`UnwrapErr`] wrapper.
     fn unwrap_err(self) -> UnwrapErr<Self>
     where
@@ -272,6 +309,14 @@ impl<R: RngCore + ?Sized> TryRngCore for R {
         self.fill_bytes(dst);
         Ok(())
     }
+
+    #[inline]
+    fn try_fill_uninit<'a>(
+        &mut self,
+        dst: &'a mut [MaybeUninit<u8>],
+    ) -> Result<&'a mut [u8], Self::Error> {
+        Ok(self.fill_uninit(dst))
+    }
 }
 
 /// A marker trait over [`TryRngCore`] for securely unpredictable RNGs
@@ -312,6 +357,11 @@ impl<R: TryRngCore> RngCore for UnwrapErr<R> {
     fn fill_bytes(&mut self, dst: &mut [u8]) {
         self.0.try_fill_bytes(dst).unwrap()
     }
+
+    #[inline]
+    fn fill_uninit<'a>(&mut self, dst: &'a mut [MaybeUninit<u8>]) -> &'a mut [u8] {
+        self.0.try_fill_uninit(dst).unwrap()
+    }
 }
 
 impl<R: TryCryptoRng> CryptoRng for UnwrapErr<R> {}
@@ -351,6 +401,11 @@ impl<R: TryRngCore + ?Sized> RngCore for UnwrapMut<'_, R> {
     fn fill_bytes(&mut self, dst: &mut [u8]) {
         self.0.try_fill_bytes(dst).unwrap()
     }
+
+    #[inline]
+    fn fill_uninit<'a>(&mut self, dst: &'a mut [MaybeUninit<u8>]) -> &'a mut [u8] {
+        self.0.try_fill_uninit(dst).unwrap()
+    }
 }
 
 impl<R: TryCryptoRng + ?Sized> CryptoRng for UnwrapMut<'_, R> {}
@@ -768,4 +823,32 @@ mod test {
         drop(rng2);
         assert_eq!(rng.next_u32(), 4);
     }
+
+    #[test]
+    fn fill_uninit_default() {
+        struct CountingRng(u8);
+
+        impl RngCore for CountingRng {
+            fn next_u32(&mut self) -> u32 {
+                impls::next_u32_via_fill(self)
+            }
+            fn next_u64(&mut self) -> u64 {
+                impls::next_u64_via_fill(self)
+            }
+            fn fill_bytes(&mut self, dst: &mut [u8]) {
+                for x in dst {
+                    self.0 = self.0.wrapping_add(1);
+                    *x = self.0;
+                }
+            }
+        }
+
+        let mut buf = [MaybeUninit::uninit(); 4];
+        assert_eq!(CountingRng(0).fill_uninit(&mut buf), &[1, 2, 3, 4]);
+
+        let mut rng = CountingRng(4);
+        assert_eq!(rng.try_fill_uninit(&mut buf).unwrap(), &[5, 6, 7, 8]);
+        let mut rng = UnwrapErr(CountingRng(8));
+        assert_eq!(rng.fill_uninit(&mut buf), &[9, 10, 11, 12]);
+    }
 }
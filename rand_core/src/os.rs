@@ -9,6 +9,7 @@
 //! Interface to the random number generator of the operating system.
 
 use crate::{TryCryptoRng, TryRngCore};
+use core::mem::MaybeUninit;
 
 /// An interface over the operating-system's random data source
 ///
@@ -97,6 +98,14 @@ impl TryRngCore for OsRng {
     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Self::Error> {
         getrandom::fill(dest).map_err(OsError)
     }
+
+    #[inline]
+    fn try_fill_uninit<'a>(
+        &mut self,
+        dest: &'a mut [MaybeUninit<u8>],
+    ) -> Result<&'a mut [u8], Self::Error> {
+        getrandom::fill_uninit(dest).map_err(OsError)
+    }
 }
 
 impl TryCryptoRng for OsRng {}
@@ -109,6 +118,14 @@ fn test_os_rng() {
     assert!(x != y);
 }
 
+#[test]
+fn test_os_rng_uninit() {
+    let mut buf = [MaybeUninit::uninit(); 64];
+    let bytes = OsRng.try_fill_uninit(&mut buf).unwrap();
+    assert_eq!(bytes.len(), 64);
+    assert!(bytes.iter().any(|&x| x != 0));
+}
+
 #[test]
 fn test_construction() {
     assert!(OsRng.try_next_u64().unwrap() != 0);
@@ -0,0 +1,61 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Java-compatible random number generators.
+//!
+//! This crate provides native Rust implementations of the generators in the
+//! Java standard library, reproducing their output bit for bit. The primary
+//! use is compatibility with existing Java code, for example comparing with
+//! test fixtures produced by a Java service.
+//!
+//! ## Generators
+//!
+//! This crate provides:
+//!
+//! -   [`JavaRandom`], compatible with `java.util.Random`. This is a 48-bit
+//!     LCG of poor statistical quality; it should not be used for new code.
+//! -   [`JavaSplittableRandom`], compatible with `java.util.SplittableRandom`.
+//!     This is the SplitMix64 generator, supporting `split()`.
+//!
+//! ## Generation
+//!
+//! Generators implement [`RngCore`], mapping `next_u32` to Java's `nextInt()`,
+//! `next_u64` to `nextLong()` and `fill_bytes` to `nextBytes`. Java's other
+//! methods are provided as inherent methods such as
+//! [`JavaRandom::next_int_bounded`] and [`JavaRandom::next_double`]; note that
+//! these are not equivalent to sampling from [`rand::distr`] distributions.
+//!
+//! ```
+//! use rand_java::JavaRandom;
+//!
+//! // Equivalent to `new java.util.Random(42)`:
+//! let mut rng = JavaRandom::new(42);
+//! assert_eq!(rng.next_int(), -1170105035);
+//! assert_eq!(rng.next_int_bounded(10), 3);
+//! ```
+//!
+//! [`RngCore`]: rand_core::RngCore
+//! [`rand::distr`]: https://docs.rs/rand/latest/rand/distr/index.html
+
+#![doc(
+    html_logo_url = "https://www.rust-lang.org/logos/rust-logo-128x128-blk.png",
+    html_favicon_url = "https://www.rust-lang.org/favicon.ico",
+    html_root_url = "https://rust-random.github.io/rand/"
+)]
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+#![deny(missing_debug_implementations)]
+#![no_std]
+
+mod random;
+mod splittable;
+
+pub use rand_core;
+
+pub use self::random::JavaRandom;
+pub use self::splittable::JavaSplittableRandom;
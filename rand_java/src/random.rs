@@ -0,0 +1,159 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Java `java.util.Random` generator
+
+const MULTIPLIER: u64 = 0x5_DEEC_E66D;
+const ADDEND: u64 = 0xB;
+const MASK: u64 = (1 << 48) - 1;
+
+use core::fmt;
+use rand_core::{le, RngCore, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A random number generator compatible with `java.util.Random`.
+///
+/// This is a Linear Congruential Generator with 48-bit state, as specified
+/// by the Java API documentation. All methods reproduce the output of the
+/// corresponding Java methods exactly.
+///
+/// The statistical quality of this generator is poor (the low bits of its
+/// state have short periods); it is provided only for compatibility.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JavaRandom {
+    state: u64,
+}
+
+impl JavaRandom {
+    /// Construct an instance equivalent to `new java.util.Random(seed)`.
+    pub fn new(seed: i64) -> Self {
+        let mut rng = JavaRandom { state: 0 };
+        rng.set_seed(seed);
+        rng
+    }
+
+    /// Reseed, equivalent to Java's `setSeed(seed)`.
+    pub fn set_seed(&mut self, seed: i64) {
+        self.state = (seed as u64 ^ MULTIPLIER) & MASK;
+    }
+
+    /// Generate up to 32 random bits, equivalent to Java's `next(bits)`.
+    ///
+    /// The result holds `bits` random bits, sign-extended from bit 31.
+    #[inline]
+    fn next(&mut self, bits: u32) -> i32 {
+        debug_assert!((1..=32).contains(&bits));
+        self.state = self.state.wrapping_mul(MULTIPLIER).wrapping_add(ADDEND) & MASK;
+        (self.state >> (48 - bits)) as i32
+    }
+
+    /// Equivalent to Java's `nextInt()`.
+    #[inline]
+    pub fn next_int(&mut self) -> i32 {
+        self.next(32)
+    }
+
+    /// Equivalent to Java's `nextInt(bound)`: a value in `0..bound`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound <= 0`.
+    pub fn next_int_bounded(&mut self, bound: i32) -> i32 {
+        assert!(
+            bound > 0,
+            "JavaRandom::next_int_bounded: bound must be positive"
+        );
+        let mut r = self.next(31);
+        let m = bound - 1;
+        if bound & m == 0 {
+            // bound is a power of 2: use the high bits
+            ((i64::from(bound) * i64::from(r)) >> 31) as i32
+        } else {
+            // reject over-represented candidates; overflow is intended here
+            let mut u = r;
+            loop {
+                r = u % bound;
+                if u.wrapping_sub(r).wrapping_add(m) >= 0 {
+                    return r;
+                }
+                u = self.next(31);
+            }
+        }
+    }
+
+    /// Equivalent to Java's `nextLong()`.
+    #[inline]
+    pub fn next_long(&mut self) -> i64 {
+        let hi = i64::from(self.next(32));
+        let lo = i64::from(self.next(32));
+        (hi << 32).wrapping_add(lo)
+    }
+
+    /// Equivalent to Java's `nextBoolean()`.
+    #[inline]
+    pub fn next_boolean(&mut self) -> bool {
+        self.next(1) != 0
+    }
+
+    /// Equivalent to Java's `nextFloat()`: a value in `[0, 1)`.
+    #[inline]
+    pub fn next_float(&mut self) -> f32 {
+        self.next(24) as f32 / (1 << 24) as f32
+    }
+
+    /// Equivalent to Java's `nextDouble()`: a value in `[0, 1)`.
+    #[inline]
+    pub fn next_double(&mut self) -> f64 {
+        let hi = i64::from(self.next(26));
+        let lo = i64::from(self.next(27));
+        ((hi << 27) + lo) as f64 / (1u64 << 53) as f64
+    }
+}
+
+// Custom Debug implementation that does not expose the internal state
+impl fmt::Debug for JavaRandom {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "JavaRandom {{}}")
+    }
+}
+
+impl SeedableRng for JavaRandom {
+    type Seed = [u8; 8];
+
+    /// The seed is read as a little-endian `i64` and used as by
+    /// [`JavaRandom::new`]. The highest 16 bits of the seed are ignored.
+    fn from_seed(seed: Self::Seed) -> Self {
+        let mut seed_u64 = [0u64; 1];
+        le::read_u64_into(&seed, &mut seed_u64);
+        JavaRandom::new(seed_u64[0] as i64)
+    }
+}
+
+impl RngCore for JavaRandom {
+    /// Equivalent to Java's `nextInt()`.
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.next_int() as u32
+    }
+
+    /// Equivalent to Java's `nextLong()`.
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.next_long() as u64
+    }
+
+    /// Equivalent to Java's `nextBytes(dest)`.
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(4) {
+            let bytes = self.next_u32().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Java `java.util.SplittableRandom` generator
+
+// The default gamma: the odd integer closest to 2^64 / phi.
+const GOLDEN_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+use core::fmt;
+use rand_core::{le, RngCore, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A random number generator compatible with `java.util.SplittableRandom`.
+///
+/// This is the SplitMix64 algorithm of Steele, Lea and Flood, "Fast
+/// Splittable Pseudorandom Number Generators" (OOPSLA 2014), with a 64-bit
+/// state and a per-instance odd increment (gamma). New, independent
+/// instances may be created with [`JavaSplittableRandom::split`].
+///
+/// All methods reproduce the output of the corresponding Java methods
+/// exactly.
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct JavaSplittableRandom {
+    seed: u64,
+    gamma: u64,
+}
+
+impl JavaSplittableRandom {
+    /// Construct an instance equivalent to
+    /// `new java.util.SplittableRandom(seed)`.
+    pub fn new(seed: i64) -> Self {
+        JavaSplittableRandom {
+            seed: seed as u64,
+            gamma: GOLDEN_GAMMA,
+        }
+    }
+
+    /// Construct a new, independent instance, equivalent to Java's `split()`.
+    ///
+    /// This advances the state of `self`.
+    pub fn split(&mut self) -> Self {
+        let seed = self.next_long() as u64;
+        let gamma = mix_gamma(self.next_seed());
+        JavaSplittableRandom { seed, gamma }
+    }
+
+    #[inline]
+    fn next_seed(&mut self) -> u64 {
+        self.seed = self.seed.wrapping_add(self.gamma);
+        self.seed
+    }
+
+    /// Equivalent to Java's `nextInt()`.
+    #[inline]
+    pub fn next_int(&mut self) -> i32 {
+        mix32(self.next_seed())
+    }
+
+    /// Equivalent to Java's `nextInt(bound)`: a value in `0..bound`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound <= 0`.
+    pub fn next_int_bounded(&mut self, bound: i32) -> i32 {
+        assert!(
+            bound > 0,
+            "JavaSplittableRandom::next_int_bounded: bound must be positive"
+        );
+        let mut r = self.next_int();
+        let m = bound - 1;
+        if bound & m == 0 {
+            // bound is a power of 2
+            r & m
+        } else {
+            // reject over-represented candidates; overflow is intended here
+            let mut u = ((r as u32) >> 1) as i32;
+            loop {
+                r = u % bound;
+                if u.wrapping_add(m).wrapping_sub(r) >= 0 {
+                    return r;
+                }
+                u = ((self.next_int() as u32) >> 1) as i32;
+            }
+        }
+    }
+
+    /// Equivalent to Java's `nextLong()`.
+    #[inline]
+    pub fn next_long(&mut self) -> i64 {
+        mix64(self.next_seed()) as i64
+    }
+
+    /// Equivalent to Java's `nextBoolean()`.
+    #[inline]
+    pub fn next_boolean(&mut self) -> bool {
+        self.next_int() < 0
+    }
+
+    /// Equivalent to Java's `nextDouble()`: a value in `[0, 1)`.
+    #[inline]
+    pub fn next_double(&mut self) -> f64 {
+        ((self.next_long() as u64) >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
+
+// Custom Debug implementation that does not expose the internal state
+impl fmt::Debug for JavaSplittableRandom {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "JavaSplittableRandom {{}}")
+    }
+}
+
+impl SeedableRng for JavaSplittableRandom {
+    type Seed = [u8; 8];
+
+    /// The seed is read as a little-endian `i64` and used as by
+    /// [`JavaSplittableRandom::new`].
+    fn from_seed(seed: Self::Seed) -> Self {
+        let mut seed_u64 = [0u64; 1];
+        le::read_u64_into(&seed, &mut seed_u64);
+        JavaSplittableRandom::new(seed_u64[0] as i64)
+    }
+}
+
+impl RngCore for JavaSplittableRandom {
+    /// Equivalent to Java's `nextInt()`.
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.next_int() as u32
+    }
+
+    /// Equivalent to Java's `nextLong()`.
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.next_long() as u64
+    }
+
+    /// Equivalent to Java's `nextBytes(dest)`.
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+}
+
+#[inline]
+fn mix64(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+#[inline]
+fn mix32(mut z: u64) -> i32 {
+    z = (z ^ (z >> 33)).wrapping_mul(0x62a9_d9ed_7997_05f5);
+    ((z ^ (z >> 28)).wrapping_mul(0xcb24_d0a5_c88c_35b3) >> 32) as i32
+}
+
+#[inline]
+fn mix_gamma(mut z: u64) -> u64 {
+    z = (z ^ (z >> 33)).wrapping_mul(0xff51_afd7_ed55_8ccd);
+    z = (z ^ (z >> 33)).wrapping_mul(0xc4ce_b9fe_1a85_ec53);
+    z = (z ^ (z >> 33)) | 1;
+    // force high bit-flip density to avoid weak gammas
+    let n = (z ^ (z >> 1)).count_ones();
+    if n < 24 {
+        z ^ 0xaaaa_aaaa_aaaa_aaaa
+    } else {
+        z
+    }
+}
@@ -0,0 +1,109 @@
+use rand_core::{RngCore, SeedableRng};
+use rand_java::JavaRandom;
+
+#[test]
+fn test_java_random_construction() {
+    let mut rng1 = JavaRandom::new(42);
+    assert_eq!(rng1.next_int(), -1170105035);
+
+    let mut rng2 = JavaRandom::from_seed(42i64.to_le_bytes());
+    assert_eq!(rng2.next_int(), -1170105035);
+
+    rng1.set_seed(0);
+    assert_eq!(rng1.next_int(), -1155484576);
+}
+
+#[test]
+fn test_java_random_reference() {
+    // Numbers determined using `java.util.Random`.
+    let mut rng = JavaRandom::new(42);
+    let mut results = [0i32; 6];
+    for i in results.iter_mut() {
+        *i = rng.next_int();
+    }
+    let expected = [
+        -1170105035,
+        234785527,
+        -1360544799,
+        205897768,
+        1325939940,
+        -248792245,
+    ];
+    assert_eq!(results, expected);
+
+    let mut rng = JavaRandom::new(12345);
+    let mut results = [0i64; 3];
+    for i in results.iter_mut() {
+        *i = rng.next_long();
+    }
+    let expected = [
+        6674089274190705457,
+        -1236052134575208584,
+        -3078921119283744887,
+    ];
+    assert_eq!(results, expected);
+
+    let mut rng = JavaRandom::new(12345);
+    assert_eq!(rng.next_double(), 0.3618031071604718);
+    assert_eq!(rng.next_double(), 0.932993485288541);
+    assert_eq!(rng.next_double(), 0.8330913489710237);
+    assert_eq!(rng.next_float(), 0.32647574);
+    assert_eq!(rng.next_float(), 0.12466812);
+    let bools: [bool; 4] = core::array::from_fn(|_| rng.next_boolean());
+    assert_eq!(bools, [false, false, false, true]);
+}
+
+#[test]
+fn test_java_random_bounded() {
+    // Numbers determined using `java.util.Random::nextInt(bound)`.
+    let mut rng = JavaRandom::new(42);
+    let results: [i32; 8] = core::array::from_fn(|_| rng.next_int_bounded(10));
+    assert_eq!(results, [0, 3, 8, 4, 0, 5, 5, 8]);
+
+    // power of 2
+    let mut rng = JavaRandom::new(42);
+    let results: [i32; 4] = core::array::from_fn(|_| rng.next_int_bounded(16));
+    assert_eq!(results, [11, 0, 10, 0]);
+
+    // large bound, exercising rejection
+    let mut rng = JavaRandom::new(-7);
+    let results: [i32; 4] = core::array::from_fn(|_| rng.next_int_bounded(1431655764));
+    assert_eq!(results, [577934662, 1119890478, 314447087, 677675436]);
+}
+
+#[test]
+#[should_panic]
+fn test_java_random_bounded_zero() {
+    JavaRandom::new(0).next_int_bounded(0);
+}
+
+#[test]
+fn test_java_random_bytes() {
+    // Bytes determined using `java.util.Random::nextBytes`.
+    let mut rng = JavaRandom::new(1);
+    let mut results = [0u8; 7];
+    rng.fill_bytes(&mut results);
+    assert_eq!(results, [115, 213, 26, 187, 216, 156, 184]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_java_random_serde() {
+    use bincode;
+    use std::io::{BufReader, BufWriter};
+
+    let mut rng = JavaRandom::new(0);
+
+    let buf: Vec<u8> = Vec::new();
+    let mut buf = BufWriter::new(buf);
+    bincode::serialize_into(&mut buf, &rng).expect("Could not serialize");
+
+    let buf = buf.into_inner().unwrap();
+    let mut read = BufReader::new(&buf[..]);
+    let mut deserialized: JavaRandom =
+        bincode::deserialize_from(&mut read).expect("Could not deserialize");
+
+    for _ in 0..16 {
+        assert_eq!(rng.next_u64(), deserialized.next_u64());
+    }
+}
@@ -0,0 +1,84 @@
+use rand_core::{RngCore, SeedableRng};
+use rand_java::JavaSplittableRandom;
+
+#[test]
+fn test_java_splittable_construction() {
+    // The first output for seed 0 is the well-known SplitMix64 output.
+    let mut rng1 = JavaSplittableRandom::new(0);
+    assert_eq!(rng1.next_u64(), 0xe220a8397b1dcdaf);
+
+    let mut rng2 = JavaSplittableRandom::from_seed(0i64.to_le_bytes());
+    assert_eq!(rng2.next_u64(), 0xe220a8397b1dcdaf);
+}
+
+#[test]
+fn test_java_splittable_reference() {
+    // Numbers determined using `java.util.SplittableRandom`.
+    let mut rng = JavaSplittableRandom::new(42);
+    let results: [i64; 3] = core::array::from_fn(|_| rng.next_long());
+    let expected = [
+        -4767286540954276203,
+        2949826092126892291,
+        5139283748462763858,
+    ];
+    assert_eq!(results, expected);
+    let results: [i32; 3] = core::array::from_fn(|_| rng.next_int());
+    assert_eq!(results, [-1024560952, -1540993733, -1746239153]);
+
+    let mut rng = JavaSplittableRandom::new(42);
+    let results: [i32; 6] = core::array::from_fn(|_| rng.next_int_bounded(10));
+    assert_eq!(results, [1, 6, 1, 2, 1, 1]);
+    assert_eq!(rng.next_int_bounded(1 << 20), 76880);
+    assert_eq!(rng.next_int_bounded(1 << 20), 865070);
+    assert_eq!(rng.next_double(), 0.3399310389170206);
+    assert_eq!(rng.next_double(), 0.6184820663561348);
+}
+
+#[test]
+fn test_java_splittable_split() {
+    // Numbers determined using `java.util.SplittableRandom::split`.
+    let mut rng = JavaSplittableRandom::new(42);
+    let mut child = rng.split();
+    let results: [i64; 3] = core::array::from_fn(|_| child.next_long());
+    let expected = [
+        -7511033593127921611,
+        5410762927873577580,
+        1172002037136309321,
+    ];
+    assert_eq!(results, expected);
+
+    // split consumes two outputs of the parent
+    let results: [i64; 2] = core::array::from_fn(|_| rng.next_long());
+    assert_eq!(results, [5139283748462763858, 6349198060258255764]);
+}
+
+#[test]
+fn test_java_splittable_bytes() {
+    // Bytes determined using `java.util.SplittableRandom::nextBytes`.
+    let mut rng = JavaSplittableRandom::new(1);
+    let mut results = [0u8; 11];
+    rng.fill_bytes(&mut results);
+    assert_eq!(results, [193, 92, 2, 137, 236, 45, 10, 145, 103, 236, 142]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_java_splittable_serde() {
+    use bincode;
+    use std::io::{BufReader, BufWriter};
+
+    let mut rng = JavaSplittableRandom::new(0);
+
+    let buf: Vec<u8> = Vec::new();
+    let mut buf = BufWriter::new(buf);
+    bincode::serialize_into(&mut buf, &rng).expect("Could not serialize");
+
+    let buf = buf.into_inner().unwrap();
+    let mut read = BufReader::new(&buf[..]);
+    let mut deserialized: JavaSplittableRandom =
+        bincode::deserialize_from(&mut read).expect("Could not deserialize");
+
+    for _ in 0..16 {
+        assert_eq!(rng.next_u64(), deserialized.next_u64());
+    }
+}
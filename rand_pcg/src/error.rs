@@ -0,0 +1,28 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Error types
+
+use core::fmt;
+
+/// Error type returned when constructing a PCG generator from an invalid
+/// LCG increment.
+///
+/// The increment of an LCG-based PCG generator must be odd for the generator
+/// to have full period.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IncrementError;
+
+impl fmt::Display for IncrementError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("PCG increment must be odd")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for IncrementError {}
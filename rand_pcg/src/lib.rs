@@ -50,8 +50,40 @@
 //!     # let _: Pcg64Mcg = rng;
 //!     ```
 //!
+//! 3.  To reproduce **NumPy's seeding** of `PCG64` or `PCG64DXSM`, use
+//!     [`SeedSequence`] with `from_seed_sequence`. This matches the output of
+//!     `numpy.random.default_rng(seed)` for the same integer `seed`:
+//!     ```
+//!     # use rand_core::RngCore;
+//!     use rand_pcg::{Pcg64, SeedSequence};
+//!     let mut rng = Pcg64::from_seed_sequence(&SeedSequence::from_u128(0));
+//!     // numpy.random.default_rng(0).bit_generator.random_raw()
+//!     assert_eq!(rng.next_u64(), 11749869230777074271);
+//!     ```
+//!
 //! See also [Seeding RNGs] in the book.
 //!
+//! ## Streams and positions
+//!
+//! The LCG-based generators support multiple *streams*, selected by the LCG
+//! increment: use the `stream` parameter of `new` or derive a family of
+//! generators from one seed with `with_stream`. All generators support
+//! jumping forwards or backwards in O(log n) time with `advance` and
+//! `retreat`, and computing the number of steps between two generators on
+//! the same stream with `distance`:
+//! ```
+//! # use rand_core::{SeedableRng, RngCore};
+//! use rand_pcg::Pcg64;
+//! let checkpoint = Pcg64::seed_from_u64(1).with_stream(7);
+//! let mut rng = checkpoint.clone();
+//! for _ in 0..100 {
+//!     rng.next_u64();
+//! }
+//! assert_eq!(checkpoint.distance(&rng), Some(100));
+//! rng.retreat(100);
+//! assert_eq!(rng, checkpoint);
+//! ```
+//!
 //! ## Generation
 //!
 //! Generators implement [`RngCore`], whose methods may be used directly to
@@ -87,14 +119,18 @@
 #![forbid(unsafe_code)]
 #![deny(missing_docs)]
 #![deny(missing_debug_implementations)]
-#![no_std]
+#![cfg_attr(not(feature = "std"), no_std)]
 
+mod error;
 mod pcg128;
 mod pcg128cm;
 mod pcg64;
+mod seed_seq;
 
 pub use rand_core;
 
+pub use self::error::IncrementError;
 pub use self::pcg128::{Lcg128Xsl64, Mcg128Xsl64, Pcg64, Pcg64Mcg};
 pub use self::pcg128cm::{Lcg128CmDxsm64, Pcg64Dxsm};
 pub use self::pcg64::{Lcg64Xsh32, Pcg32};
+pub use self::seed_seq::SeedSequence;
@@ -13,6 +13,8 @@
 // This is the default multiplier used by PCG for 128-bit state.
 const MULTIPLIER: u128 = 0x2360_ED05_1FC6_5DA4_4385_DF64_9FCC_F645;
 
+use crate::IncrementError;
+use crate::SeedSequence;
 use core::fmt;
 use rand_core::{impls, le, RngCore, SeedableRng};
 #[cfg(feature = "serde")]
@@ -35,11 +37,30 @@ use serde::{Deserialize, Serialize};
 /// correlated.
 #[derive(Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Lcg128Xsl64Fields"))]
 pub struct Lcg128Xsl64 {
     state: u128,
     increment: u128,
 }
 
+/// The serialized fields of [`Lcg128Xsl64`], validated on deserialization.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "Lcg128Xsl64")]
+struct Lcg128Xsl64Fields {
+    state: u128,
+    increment: u128,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Lcg128Xsl64Fields> for Lcg128Xsl64 {
+    type Error = IncrementError;
+
+    fn try_from(fields: Lcg128Xsl64Fields) -> Result<Self, IncrementError> {
+        Self::from_state_increment(fields.state, fields.increment)
+    }
+}
+
 /// [`Lcg128Xsl64`] is also officially known as `pcg64`.
 pub type Pcg64 = Lcg128Xsl64;
 
@@ -76,6 +97,46 @@ impl Lcg128Xsl64 {
         self.state = acc_mult.wrapping_mul(self.state).wrapping_add(acc_plus);
     }
 
+    /// Multi-step retreat function (jump-back)
+    ///
+    /// Using this function is equivalent to undoing `delta` calls to
+    /// `next_u64()`, i.e. `advance(delta)` followed by `retreat(delta)` restores
+    /// the original state.
+    #[inline]
+    pub fn retreat(&mut self, delta: u128) {
+        self.advance(delta.wrapping_neg());
+    }
+
+    /// Compute the number of steps from `self` to `other`
+    ///
+    /// Returns the `delta` such that `self.advance(delta)` yields a generator
+    /// equal to `other`, or `None` if `other` is on a different stream
+    /// (i.e. has a different increment).
+    ///
+    /// The method used is that of `pcg_extras::distance` from pcg-cpp,
+    /// determining one bit of the distance at a time.
+    pub fn distance(&self, other: &Self) -> Option<u128> {
+        if self.increment != other.increment {
+            return None;
+        }
+        let mut cur_state = self.state;
+        let mut cur_mult = MULTIPLIER;
+        let mut cur_plus = self.increment;
+        let mut the_bit: u128 = 1;
+        let mut distance: u128 = 0;
+        while cur_state != other.state {
+            if (cur_state & the_bit) != (other.state & the_bit) {
+                cur_state = cur_state.wrapping_mul(cur_mult).wrapping_add(cur_plus);
+                distance |= the_bit;
+            }
+            debug_assert_eq!(cur_state & the_bit, other.state & the_bit);
+            the_bit <<= 1;
+            cur_plus = cur_mult.wrapping_add(1).wrapping_mul(cur_plus);
+            cur_mult = cur_mult.wrapping_mul(cur_mult);
+        }
+        Some(distance)
+    }
+
     /// Construct an instance compatible with PCG seed and stream.
     ///
     /// Note that the highest bit of the `stream` parameter is discarded
@@ -94,6 +155,63 @@ impl Lcg128Xsl64 {
         Lcg128Xsl64::from_state_incr(state, increment)
     }
 
+    /// Construct an instance from the raw LCG state and increment.
+    ///
+    /// Unlike [`Self::new`], this sets the state directly, without the
+    /// initial step taken by PCG's seeding procedure. The increment must be
+    /// odd; otherwise an error is returned.
+    pub fn from_state_increment(state: u128, increment: u128) -> Result<Self, IncrementError> {
+        if increment & 1 == 0 {
+            return Err(IncrementError);
+        }
+        Ok(Lcg128Xsl64 { state, increment })
+    }
+
+    /// Get the stream selector.
+    ///
+    /// This is the `stream` parameter of [`Self::new`] (without its
+    /// highest bit).
+    #[inline]
+    pub fn stream(&self) -> u128 {
+        self.increment >> 1
+    }
+
+    /// Construct a generator with the same state on stream `stream`.
+    ///
+    /// This allows deriving a family of generators from a single seed, for
+    /// example `rng.with_stream(i)` for each worker `i`. As with
+    /// [`Self::new`], the highest bit of `stream` is discarded.
+    ///
+    /// Note that generators with the same state but different streams may be
+    /// correlated; see the type-level documentation.
+    #[inline]
+    pub fn with_stream(&self, stream: u128) -> Self {
+        Lcg128Xsl64 {
+            state: self.state,
+            increment: (stream << 1) | 1,
+        }
+    }
+
+    /// Construct an instance seeded as by NumPy's `PCG64` bit generator.
+    ///
+    /// NumPy generates the state and stream parameters from a
+    /// [`SeedSequence`]; using the same seed sequence yields the same output
+    /// as NumPy's `PCG64`. In particular, the output of
+    /// `numpy.random.default_rng(seed)` may be reproduced with
+    /// `Lcg128Xsl64::from_seed_sequence(&SeedSequence::from_u128(seed))`.
+    ///
+    /// ```
+    /// use rand_core::RngCore;
+    /// use rand_pcg::{Lcg128Xsl64, SeedSequence};
+    ///
+    /// let mut rng = Lcg128Xsl64::from_seed_sequence(&SeedSequence::from_u128(0xdeadbeaf));
+    /// assert_eq!(rng.next_u64(), 0x60d24054e17a0698);
+    /// ```
+    pub fn from_seed_sequence(seq: &SeedSequence) -> Self {
+        let (state, stream) = seq.pcg128_state_stream();
+        Self::new(state, stream)
+    }
+
     #[inline]
     fn from_state_incr(state: u128, increment: u128) -> Self {
         let mut pcg = Lcg128Xsl64 { state, increment };
@@ -167,10 +285,34 @@ impl RngCore for Lcg128Xsl64 {
 /// good performance on statistical tests.
 #[derive(Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Mcg128Xsl64Fields"))]
 pub struct Mcg128Xsl64 {
     state: u128,
 }
 
+/// The serialized fields of [`Mcg128Xsl64`], validated on deserialization.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "Mcg128Xsl64")]
+struct Mcg128Xsl64Fields {
+    state: u128,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Mcg128Xsl64Fields> for Mcg128Xsl64 {
+    type Error = &'static str;
+
+    fn try_from(fields: Mcg128Xsl64Fields) -> Result<Self, &'static str> {
+        // The MCG state is always odd; see `new`
+        if fields.state & 1 == 0 {
+            return Err("Mcg128Xsl64 state must be odd");
+        }
+        Ok(Mcg128Xsl64 {
+            state: fields.state,
+        })
+    }
+}
+
 /// A friendly name for [`Mcg128Xsl64`] (also known as `pcg64_fast`).
 pub type Pcg64Mcg = Mcg128Xsl64;
 
@@ -207,6 +349,46 @@ impl Mcg128Xsl64 {
         self.state = acc_mult.wrapping_mul(self.state).wrapping_add(acc_plus);
     }
 
+    /// Multi-step retreat function (jump-back)
+    ///
+    /// Using this function is equivalent to undoing `delta` calls to
+    /// `next_u64()`, i.e. `advance(delta)` followed by `retreat(delta)`
+    /// restores the original state.
+    #[inline]
+    pub fn retreat(&mut self, delta: u128) {
+        // The period of the MCG, 2^126, divides 2^128
+        self.advance(delta.wrapping_neg());
+    }
+
+    /// Compute the number of steps from `self` to `other`
+    ///
+    /// Returns the `delta` (less than 2<sup>126</sup>) such that
+    /// `self.advance(delta)` yields a generator equal to `other`, or `None` if
+    /// `other` is not reachable from `self`. The MCG state space consists of
+    /// two cycles distinguished by the second-lowest bit of the state.
+    ///
+    /// The method used is that of `pcg_extras::distance` from pcg-cpp,
+    /// determining one bit of the distance at a time.
+    pub fn distance(&self, other: &Self) -> Option<u128> {
+        if self.state & 3 != other.state & 3 {
+            return None;
+        }
+        let mut cur_state = self.state;
+        let mut cur_mult = MULTIPLIER;
+        let mut the_bit: u128 = 4;
+        let mut distance: u128 = 0;
+        while cur_state != other.state {
+            if (cur_state & the_bit) != (other.state & the_bit) {
+                cur_state = cur_state.wrapping_mul(cur_mult);
+                distance |= the_bit;
+            }
+            debug_assert_eq!(cur_state & the_bit, other.state & the_bit);
+            the_bit <<= 1;
+            cur_mult = cur_mult.wrapping_mul(cur_mult);
+        }
+        Some(distance >> 2)
+    }
+
     /// Construct an instance compatible with PCG seed.
     ///
     /// Note that PCG specifies a default value for the parameter:
@@ -13,6 +13,8 @@
 // This is the cheap multiplier used by PCG for 128-bit state.
 const MULTIPLIER: u64 = 15750249268501108917;
 
+use crate::IncrementError;
+use crate::SeedSequence;
 use core::fmt;
 use rand_core::{impls, le, RngCore, SeedableRng};
 #[cfg(feature = "serde")]
@@ -38,11 +40,30 @@ use serde::{Deserialize, Serialize};
 /// [upgrading-pcg64]: https://numpy.org/doc/stable/reference/random/upgrading-pcg64.html
 #[derive(Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Lcg128CmDxsm64Fields"))]
 pub struct Lcg128CmDxsm64 {
     state: u128,
     increment: u128,
 }
 
+/// The serialized fields of [`Lcg128CmDxsm64`], validated on deserialization.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "Lcg128CmDxsm64")]
+struct Lcg128CmDxsm64Fields {
+    state: u128,
+    increment: u128,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Lcg128CmDxsm64Fields> for Lcg128CmDxsm64 {
+    type Error = IncrementError;
+
+    fn try_from(fields: Lcg128CmDxsm64Fields) -> Result<Self, IncrementError> {
+        Self::from_state_increment(fields.state, fields.increment)
+    }
+}
+
 /// [`Lcg128CmDxsm64`] is also known as `PCG64DXSM`.
 pub type Pcg64Dxsm = Lcg128CmDxsm64;
 
@@ -79,6 +100,46 @@ impl Lcg128CmDxsm64 {
         self.state = acc_mult.wrapping_mul(self.state).wrapping_add(acc_plus);
     }
 
+    /// Multi-step retreat function (jump-back)
+    ///
+    /// Using this function is equivalent to undoing `delta` calls to
+    /// `next_u64()`, i.e. `advance(delta)` followed by `retreat(delta)` restores
+    /// the original state.
+    #[inline]
+    pub fn retreat(&mut self, delta: u128) {
+        self.advance(delta.wrapping_neg());
+    }
+
+    /// Compute the number of steps from `self` to `other`
+    ///
+    /// Returns the `delta` such that `self.advance(delta)` yields a generator
+    /// equal to `other`, or `None` if `other` is on a different stream
+    /// (i.e. has a different increment).
+    ///
+    /// The method used is that of `pcg_extras::distance` from pcg-cpp,
+    /// determining one bit of the distance at a time.
+    pub fn distance(&self, other: &Self) -> Option<u128> {
+        if self.increment != other.increment {
+            return None;
+        }
+        let mut cur_state = self.state;
+        let mut cur_mult = MULTIPLIER as u128;
+        let mut cur_plus = self.increment;
+        let mut the_bit: u128 = 1;
+        let mut distance: u128 = 0;
+        while cur_state != other.state {
+            if (cur_state & the_bit) != (other.state & the_bit) {
+                cur_state = cur_state.wrapping_mul(cur_mult).wrapping_add(cur_plus);
+                distance |= the_bit;
+            }
+            debug_assert_eq!(cur_state & the_bit, other.state & the_bit);
+            the_bit <<= 1;
+            cur_plus = cur_mult.wrapping_add(1).wrapping_mul(cur_plus);
+            cur_mult = cur_mult.wrapping_mul(cur_mult);
+        }
+        Some(distance)
+    }
+
     /// Construct an instance compatible with PCG seed and stream.
     ///
     /// Note that the highest bit of the `stream` parameter is discarded
@@ -99,6 +160,63 @@ impl Lcg128CmDxsm64 {
         Self::from_state_incr(state, increment)
     }
 
+    /// Construct an instance from the raw LCG state and increment.
+    ///
+    /// Unlike [`Self::new`], this sets the state directly, without the
+    /// initial step taken by PCG's seeding procedure. The increment must be
+    /// odd; otherwise an error is returned.
+    pub fn from_state_increment(state: u128, increment: u128) -> Result<Self, IncrementError> {
+        if increment & 1 == 0 {
+            return Err(IncrementError);
+        }
+        Ok(Lcg128CmDxsm64 { state, increment })
+    }
+
+    /// Get the stream selector.
+    ///
+    /// This is the `stream` parameter of [`Self::new`] (without its
+    /// highest bit).
+    #[inline]
+    pub fn stream(&self) -> u128 {
+        self.increment >> 1
+    }
+
+    /// Construct a generator with the same state on stream `stream`.
+    ///
+    /// This allows deriving a family of generators from a single seed, for
+    /// example `rng.with_stream(i)` for each worker `i`. As with
+    /// [`Self::new`], the highest bit of `stream` is discarded.
+    ///
+    /// Note that generators with the same state but different streams may be
+    /// correlated; see the type-level documentation.
+    #[inline]
+    pub fn with_stream(&self, stream: u128) -> Self {
+        Lcg128CmDxsm64 {
+            state: self.state,
+            increment: (stream << 1) | 1,
+        }
+    }
+
+    /// Construct an instance seeded as by NumPy's `PCG64DXSM` bit generator.
+    ///
+    /// NumPy generates the state and stream parameters from a
+    /// [`SeedSequence`]; using the same seed sequence yields the same output
+    /// as NumPy's `PCG64DXSM`. In particular, NumPy's `PCG64DXSM(seed)` for an
+    /// integer `seed` is reproduced by
+    /// `Lcg128CmDxsm64::from_seed_sequence(&SeedSequence::from_u128(seed))`.
+    ///
+    /// ```
+    /// use rand_core::RngCore;
+    /// use rand_pcg::{Pcg64Dxsm, SeedSequence};
+    ///
+    /// let mut rng = Pcg64Dxsm::from_seed_sequence(&SeedSequence::from_u128(0xdeadbeaf));
+    /// assert_eq!(rng.next_u64(), 0x353db1139412b43b);
+    /// ```
+    pub fn from_seed_sequence(seq: &SeedSequence) -> Self {
+        let (state, stream) = seq.pcg128_state_stream();
+        Self::new(state, stream)
+    }
+
     #[inline]
     fn from_state_incr(state: u128, increment: u128) -> Self {
         let mut pcg = Self { state, increment };
@@ -10,6 +10,7 @@
 
 //! PCG random number generators
 
+use crate::IncrementError;
 use core::fmt;
 use rand_core::{impls, le, RngCore, SeedableRng};
 #[cfg(feature = "serde")]
@@ -35,11 +36,30 @@ const MULTIPLIER: u64 = 6364136223846793005;
 /// correlated.
 #[derive(Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Lcg64Xsh32Fields"))]
 pub struct Lcg64Xsh32 {
     state: u64,
     increment: u64,
 }
 
+/// The serialized fields of [`Lcg64Xsh32`], validated on deserialization.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "Lcg64Xsh32")]
+struct Lcg64Xsh32Fields {
+    state: u64,
+    increment: u64,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Lcg64Xsh32Fields> for Lcg64Xsh32 {
+    type Error = IncrementError;
+
+    fn try_from(fields: Lcg64Xsh32Fields) -> Result<Self, IncrementError> {
+        Self::from_state_increment(fields.state, fields.increment)
+    }
+}
+
 /// [`Lcg64Xsh32`] is also officially known as `pcg32`.
 pub type Pcg32 = Lcg64Xsh32;
 
@@ -76,6 +96,46 @@ impl Lcg64Xsh32 {
         self.state = acc_mult.wrapping_mul(self.state).wrapping_add(acc_plus);
     }
 
+    /// Multi-step retreat function (jump-back)
+    ///
+    /// Using this function is equivalent to undoing `delta` calls to
+    /// `next_u32()`, i.e. `advance(delta)` followed by `retreat(delta)` restores
+    /// the original state.
+    #[inline]
+    pub fn retreat(&mut self, delta: u64) {
+        self.advance(delta.wrapping_neg());
+    }
+
+    /// Compute the number of steps from `self` to `other`
+    ///
+    /// Returns the `delta` such that `self.advance(delta)` yields a generator
+    /// equal to `other`, or `None` if `other` is on a different stream
+    /// (i.e. has a different increment).
+    ///
+    /// The method used is that of `pcg_extras::distance` from pcg-cpp,
+    /// determining one bit of the distance at a time.
+    pub fn distance(&self, other: &Self) -> Option<u64> {
+        if self.increment != other.increment {
+            return None;
+        }
+        let mut cur_state = self.state;
+        let mut cur_mult = MULTIPLIER;
+        let mut cur_plus = self.increment;
+        let mut the_bit: u64 = 1;
+        let mut distance: u64 = 0;
+        while cur_state != other.state {
+            if (cur_state & the_bit) != (other.state & the_bit) {
+                cur_state = cur_state.wrapping_mul(cur_mult).wrapping_add(cur_plus);
+                distance |= the_bit;
+            }
+            debug_assert_eq!(cur_state & the_bit, other.state & the_bit);
+            the_bit <<= 1;
+            cur_plus = cur_mult.wrapping_add(1).wrapping_mul(cur_plus);
+            cur_mult = cur_mult.wrapping_mul(cur_mult);
+        }
+        Some(distance)
+    }
+
     /// Construct an instance compatible with PCG seed and stream.
     ///
     /// Note that the highest bit of the `stream` parameter is discarded
@@ -95,6 +155,43 @@ impl Lcg64Xsh32 {
         Lcg64Xsh32::from_state_incr(state, increment)
     }
 
+    /// Construct an instance from the raw LCG state and increment.
+    ///
+    /// Unlike [`Self::new`], this sets the state directly, without the
+    /// initial step taken by PCG's seeding procedure. The increment must be
+    /// odd; otherwise an error is returned.
+    pub fn from_state_increment(state: u64, increment: u64) -> Result<Self, IncrementError> {
+        if increment & 1 == 0 {
+            return Err(IncrementError);
+        }
+        Ok(Lcg64Xsh32 { state, increment })
+    }
+
+    /// Get the stream selector.
+    ///
+    /// This is the `stream` parameter of [`Self::new`] (without its
+    /// highest bit).
+    #[inline]
+    pub fn stream(&self) -> u64 {
+        self.increment >> 1
+    }
+
+    /// Construct a generator with the same state on stream `stream`.
+    ///
+    /// This allows deriving a family of generators from a single seed, for
+    /// example `rng.with_stream(i)` for each worker `i`. As with
+    /// [`Self::new`], the highest bit of `stream` is discarded.
+    ///
+    /// Note that generators with the same state but different streams may be
+    /// correlated; see the type-level documentation.
+    #[inline]
+    pub fn with_stream(&self, stream: u64) -> Self {
+        Lcg64Xsh32 {
+            state: self.state,
+            increment: (stream << 1) | 1,
+        }
+    }
+
     #[inline]
     fn from_state_incr(state: u64, increment: u64) -> Self {
         let mut pcg = Lcg64Xsh32 { state, increment };
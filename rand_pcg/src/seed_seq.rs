@@ -0,0 +1,191 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! NumPy-compatible seed sequence
+
+use core::fmt;
+
+const POOL_SIZE: usize = 4;
+const INIT_A: u32 = 0x43b0_d7e5;
+const MULT_A: u32 = 0x931e_8875;
+const INIT_B: u32 = 0x8b51_f9dd;
+const MULT_B: u32 = 0x58f3_8ded;
+const MIX_MULT_L: u32 = 0xca01_f9dd;
+const MIX_MULT_R: u32 = 0x4973_f715;
+const XSHIFT: u32 = 16;
+
+/// A seed sequence compatible with NumPy's `SeedSequence`.
+///
+/// This mixes an arbitrary amount of input entropy into a pool of 128 bits,
+/// from which any number of well-distributed output words may be generated.
+/// The algorithm is that of NumPy's [`SeedSequence`] (with the default pool
+/// size), itself derived from Melissa O'Neill's `seed_seq_fe128`.
+///
+/// The main purpose of this type is to reproduce NumPy's seeding of
+/// [`Pcg64`] and [`Pcg64Dxsm`]; see [`Lcg128Xsl64::from_seed_sequence`] and
+/// [`Lcg128CmDxsm64::from_seed_sequence`].
+///
+/// NumPy also supports seeding with an entropy pool gathered from the OS;
+/// this is not supported here. Use [`SeedableRng`] methods for that instead.
+///
+/// [`SeedSequence`]: https://numpy.org/doc/stable/reference/random/bit_generators/generated/numpy.random.SeedSequence.html
+/// [`Pcg64`]: crate::Pcg64
+/// [`Pcg64Dxsm`]: crate::Pcg64Dxsm
+/// [`Lcg128Xsl64::from_seed_sequence`]: crate::Lcg128Xsl64::from_seed_sequence
+/// [`Lcg128CmDxsm64::from_seed_sequence`]: crate::Lcg128CmDxsm64::from_seed_sequence
+/// [`SeedableRng`]: rand_core::SeedableRng
+#[derive(Clone, PartialEq, Eq)]
+pub struct SeedSequence {
+    pool: [u32; POOL_SIZE],
+}
+
+impl SeedSequence {
+    /// Construct from entropy given as a sequence of 32-bit words.
+    ///
+    /// This is equivalent to NumPy's `SeedSequence(entropy)` where `entropy`
+    /// is an array of `uint32` values.
+    pub fn new(entropy: &[u32]) -> Self {
+        Self::with_spawn_key(entropy, &[])
+    }
+
+    /// Construct from an integer seed.
+    ///
+    /// This is equivalent to NumPy's `SeedSequence(seed)` for a non-negative
+    /// Python integer `seed`, and thus also to `numpy.random.default_rng(seed)`.
+    pub fn from_u128(seed: u128) -> Self {
+        let mut words = [0u32; 4];
+        let mut len = 0;
+        let mut x = seed;
+        // NumPy uses the least number of words needed, but at least one.
+        loop {
+            words[len] = x as u32;
+            len += 1;
+            x >>= 32;
+            if x == 0 {
+                break;
+            }
+        }
+        Self::new(&words[..len])
+    }
+
+    /// Construct from entropy and a spawn key.
+    ///
+    /// This is equivalent to NumPy's
+    /// `SeedSequence(entropy, spawn_key=spawn_key)`. Children created by
+    /// NumPy's `SeedSequence.spawn` use the parent's spawn key extended by
+    /// the child's index; for example the `i`-th child of
+    /// `SeedSequence(entropy)` has `spawn_key = [i]`.
+    pub fn with_spawn_key(entropy: &[u32], spawn_key: &[u32]) -> Self {
+        // When a spawn key is used, run entropy is padded with zeros up to
+        // the pool size to avoid conflicts with the spawn key.
+        let padding = if !spawn_key.is_empty() && entropy.len() < POOL_SIZE {
+            POOL_SIZE - entropy.len()
+        } else {
+            0
+        };
+        let assembled = || {
+            entropy
+                .iter()
+                .copied()
+                .chain(core::iter::repeat(0).take(padding))
+                .chain(spawn_key.iter().copied())
+        };
+
+        let mut hash_const = INIT_A;
+        let mut hashmix = |value: u32| {
+            let mut value = value ^ hash_const;
+            hash_const = hash_const.wrapping_mul(MULT_A);
+            value = value.wrapping_mul(hash_const);
+            value ^ (value >> XSHIFT)
+        };
+
+        // Add in the entropy up to the pool size, then keep running the hash
+        // if the entropy is shorter than the pool.
+        let mut pool = [0u32; POOL_SIZE];
+        let mut source = assembled().chain(core::iter::repeat(0));
+        for word in pool.iter_mut() {
+            *word = hashmix(source.next().unwrap());
+        }
+
+        // Mix all bits together so late bits can affect earlier bits.
+        for i_src in 0..POOL_SIZE {
+            for i_dst in 0..POOL_SIZE {
+                if i_src != i_dst {
+                    pool[i_dst] = mix(pool[i_dst], hashmix(pool[i_src]));
+                }
+            }
+        }
+
+        // Add any remaining entropy, mixing each new word with each pool word.
+        for value in assembled().skip(POOL_SIZE) {
+            for word in pool.iter_mut() {
+                *word = mix(*word, hashmix(value));
+            }
+        }
+
+        SeedSequence { pool }
+    }
+
+    /// Fill `dest` with 32-bit words of generated state.
+    ///
+    /// This is equivalent to NumPy's `generate_state(len, np.uint32)`.
+    pub fn generate_state(&self, dest: &mut [u32]) {
+        for (dest, value) in dest.iter_mut().zip(self.output()) {
+            *dest = value;
+        }
+    }
+
+    /// Fill `dest` with 64-bit words of generated state.
+    ///
+    /// This is equivalent to NumPy's `generate_state(len, np.uint64)`: pairs
+    /// of 32-bit words are combined in little-endian order.
+    pub fn generate_state_u64(&self, dest: &mut [u64]) {
+        let mut source = self.output();
+        for dest in dest.iter_mut() {
+            let lo = u64::from(source.next().unwrap());
+            let hi = u64::from(source.next().unwrap());
+            *dest = lo | (hi << 32);
+        }
+    }
+
+    /// An endless stream of output words
+    fn output(&self) -> impl Iterator<Item = u32> + '_ {
+        let mut hash_const = INIT_B;
+        self.pool.iter().cycle().map(move |value| {
+            let mut value = value ^ hash_const;
+            hash_const = hash_const.wrapping_mul(MULT_B);
+            value = value.wrapping_mul(hash_const);
+            value ^ (value >> XSHIFT)
+        })
+    }
+
+    /// Generate 128-bit state and stream values for a PCG generator, as used
+    /// by NumPy's `PCG64` and `PCG64DXSM` bit generators.
+    pub(crate) fn pcg128_state_stream(&self) -> (u128, u128) {
+        let mut words = [0u64; 4];
+        self.generate_state_u64(&mut words);
+        let state = (u128::from(words[0]) << 64) | u128::from(words[1]);
+        let stream = (u128::from(words[2]) << 64) | u128::from(words[3]);
+        (state, stream)
+    }
+}
+
+// Custom Debug implementation that does not expose the internal state
+impl fmt::Debug for SeedSequence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SeedSequence {{}}")
+    }
+}
+
+#[inline]
+fn mix(x: u32, y: u32) -> u32 {
+    let result = MIX_MULT_L
+        .wrapping_mul(x)
+        .wrapping_sub(MIX_MULT_R.wrapping_mul(y));
+    result ^ (result >> XSHIFT)
+}
@@ -1,5 +1,5 @@
 use rand_core::{RngCore, SeedableRng};
-use rand_pcg::{Lcg128CmDxsm64, Pcg64Dxsm};
+use rand_pcg::{Lcg128CmDxsm64, Pcg64Dxsm, SeedSequence};
 
 #[test]
 fn test_lcg128cmdxsm64_advancing() {
@@ -14,6 +14,44 @@ fn test_lcg128cmdxsm64_advancing() {
     }
 }
 
+#[test]
+fn test_lcg128cmdxsm64_retreat_distance() {
+    for seed in 0..20 {
+        let rng1 = Lcg128CmDxsm64::seed_from_u64(seed);
+        let mut rng2 = rng1.clone();
+        for _ in 0..20 {
+            rng2.next_u64();
+        }
+        assert_eq!(rng1.distance(&rng2), Some(20));
+        assert_eq!(rng2.distance(&rng1), Some(20u128.wrapping_neg()));
+
+        let mut rng3 = rng1.clone();
+        rng3.advance(1 << 40 | 12345);
+        assert_eq!(rng1.distance(&rng3), Some(1 << 40 | 12345));
+
+        rng2.retreat(20);
+        assert_eq!(rng1, rng2);
+        assert_eq!(rng1.distance(&rng2), Some(0));
+    }
+}
+
+#[test]
+fn test_lcg128cmdxsm64_streams() {
+    let rng = Lcg128CmDxsm64::new(42, 54);
+    assert_eq!(rng.stream(), 54);
+
+    let rng1 = rng.with_stream(1);
+    let rng2 = rng.with_stream(2);
+    assert_eq!(rng1.stream(), 1);
+    assert_eq!(rng2.stream(), 2);
+    assert_ne!(rng1, rng2);
+    assert_eq!(rng1.distance(&rng2), None);
+
+    assert!(Lcg128CmDxsm64::from_state_increment(1, 2).is_err());
+    let rng3 = Lcg128CmDxsm64::from_state_increment(1, 3).unwrap();
+    assert_eq!(rng3.stream(), 1);
+}
+
 #[test]
 fn test_lcg128cmdxsm64_construction() {
     // Test that various construction techniques produce a working RNG.
@@ -54,6 +92,24 @@ fn test_lcg128cmdxsm64_reference() {
     assert_eq!(results, expected);
 }
 
+#[test]
+fn test_lcg128cmdxsm64_numpy() {
+    // Numbers from `numpy.random.PCG64DXSM(seed).random_raw()`.
+    let mut rng = Lcg128CmDxsm64::from_seed_sequence(&SeedSequence::from_u128(42));
+
+    let mut results = [0u64; 4];
+    for i in results.iter_mut() {
+        *i = rng.next_u64();
+    }
+    let expected: [u64; 4] = [
+        16989333694122108153,
+        16037878181427258172,
+        6568298358479024712,
+        10943698277350149421,
+    ];
+    assert_eq!(results, expected);
+}
+
 #[cfg(feature = "serde")]
 #[test]
 fn test_lcg128cmdxsm64_serde() {
@@ -75,3 +131,14 @@ fn test_lcg128cmdxsm64_serde() {
         assert_eq!(rng.next_u64(), deserialized.next_u64());
     }
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_lcg128cmdxsm64_serde_even_increment() {
+    let buf = bincode::serialize(&(7u128, 2u128)).unwrap();
+    assert!(bincode::deserialize::<Lcg128CmDxsm64>(&buf).is_err());
+
+    let buf = bincode::serialize(&(7u128, 3u128)).unwrap();
+    let rng: Lcg128CmDxsm64 = bincode::deserialize(&buf).unwrap();
+    assert_eq!(rng, Lcg128CmDxsm64::from_state_increment(7, 3).unwrap());
+}
@@ -1,5 +1,5 @@
 use rand_core::{RngCore, SeedableRng};
-use rand_pcg::{Lcg128Xsl64, Pcg64};
+use rand_pcg::{Lcg128Xsl64, Pcg64, SeedSequence};
 
 #[test]
 fn test_lcg128xsl64_advancing() {
@@ -14,6 +14,44 @@ fn test_lcg128xsl64_advancing() {
     }
 }
 
+#[test]
+fn test_lcg128xsl64_retreat_distance() {
+    for seed in 0..20 {
+        let rng1 = Lcg128Xsl64::seed_from_u64(seed);
+        let mut rng2 = rng1.clone();
+        for _ in 0..20 {
+            rng2.next_u64();
+        }
+        assert_eq!(rng1.distance(&rng2), Some(20));
+        assert_eq!(rng2.distance(&rng1), Some(20u128.wrapping_neg()));
+
+        let mut rng3 = rng1.clone();
+        rng3.advance(1 << 40 | 12345);
+        assert_eq!(rng1.distance(&rng3), Some(1 << 40 | 12345));
+
+        rng2.retreat(20);
+        assert_eq!(rng1, rng2);
+        assert_eq!(rng1.distance(&rng2), Some(0));
+    }
+}
+
+#[test]
+fn test_lcg128xsl64_streams() {
+    let rng = Lcg128Xsl64::new(42, 54);
+    assert_eq!(rng.stream(), 54);
+
+    let rng1 = rng.with_stream(1);
+    let rng2 = rng.with_stream(2);
+    assert_eq!(rng1.stream(), 1);
+    assert_eq!(rng2.stream(), 2);
+    assert_ne!(rng1, rng2);
+    assert_eq!(rng1.distance(&rng2), None);
+
+    assert!(Lcg128Xsl64::from_state_increment(1, 2).is_err());
+    let rng3 = Lcg128Xsl64::from_state_increment(1, 3).unwrap();
+    assert_eq!(rng3.stream(), 1);
+}
+
 #[test]
 fn test_lcg128xsl64_construction() {
     // Test that various construction techniques produce a working RNG.
@@ -54,6 +92,36 @@ fn test_lcg128xsl64_reference() {
     assert_eq!(results, expected);
 }
 
+#[test]
+fn test_lcg128xsl64_numpy() {
+    // Numbers from `numpy.random.PCG64(seed).random_raw()`.
+    let mut results = [0u64; 4];
+
+    let mut rng = Lcg128Xsl64::from_seed_sequence(&SeedSequence::from_u128(0));
+    for i in results.iter_mut() {
+        *i = rng.next_u64();
+    }
+    let expected: [u64; 4] = [
+        11749869230777074271,
+        4976686463289251617,
+        755828109848996024,
+        304881062738325533,
+    ];
+    assert_eq!(results, expected);
+
+    let mut rng = Lcg128Xsl64::from_seed_sequence(&SeedSequence::from_u128(0xdeadbeaf));
+    for i in results.iter_mut() {
+        *i = rng.next_u64();
+    }
+    let expected: [u64; 4] = [
+        0x60d24054e17a0698,
+        0xd5e79d89856e4f12,
+        0xd254972fe64bd782,
+        0xf1e3072a53c72571,
+    ];
+    assert_eq!(results, expected);
+}
+
 #[cfg(feature = "serde")]
 #[test]
 fn test_lcg128xsl64_serde() {
@@ -75,3 +143,14 @@ fn test_lcg128xsl64_serde() {
         assert_eq!(rng.next_u64(), deserialized.next_u64());
     }
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_lcg128xsl64_serde_even_increment() {
+    let buf = bincode::serialize(&(7u128, 2u128)).unwrap();
+    assert!(bincode::deserialize::<Lcg128Xsl64>(&buf).is_err());
+
+    let buf = bincode::serialize(&(7u128, 3u128)).unwrap();
+    let rng: Lcg128Xsl64 = bincode::deserialize(&buf).unwrap();
+    assert_eq!(rng, Lcg128Xsl64::from_state_increment(7, 3).unwrap());
+}
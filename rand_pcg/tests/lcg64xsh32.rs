@@ -14,6 +14,44 @@ fn test_lcg64xsh32_advancing() {
     }
 }
 
+#[test]
+fn test_lcg64xsh32_retreat_distance() {
+    for seed in 0..20 {
+        let rng1 = Lcg64Xsh32::seed_from_u64(seed);
+        let mut rng2 = rng1.clone();
+        for _ in 0..20 {
+            rng2.next_u32();
+        }
+        assert_eq!(rng1.distance(&rng2), Some(20));
+        assert_eq!(rng2.distance(&rng1), Some(20u64.wrapping_neg()));
+
+        let mut rng3 = rng1.clone();
+        rng3.advance(1 << 40 | 12345);
+        assert_eq!(rng1.distance(&rng3), Some(1 << 40 | 12345));
+
+        rng2.retreat(20);
+        assert_eq!(rng1, rng2);
+        assert_eq!(rng1.distance(&rng2), Some(0));
+    }
+}
+
+#[test]
+fn test_lcg64xsh32_streams() {
+    let rng = Lcg64Xsh32::new(42, 54);
+    assert_eq!(rng.stream(), 54);
+
+    let rng1 = rng.with_stream(1);
+    let rng2 = rng.with_stream(2);
+    assert_eq!(rng1.stream(), 1);
+    assert_eq!(rng2.stream(), 2);
+    assert_ne!(rng1, rng2);
+    assert_eq!(rng1.distance(&rng2), None);
+
+    assert!(Lcg64Xsh32::from_state_increment(1, 2).is_err());
+    let rng3 = Lcg64Xsh32::from_state_increment(1, 3).unwrap();
+    assert_eq!(rng3.stream(), 1);
+}
+
 #[test]
 fn test_lcg64xsh32_construction() {
     // Test that various construction techniques produce a working RNG.
@@ -68,3 +106,14 @@ fn test_lcg64xsh32_serde() {
         assert_eq!(rng.next_u64(), deserialized.next_u64());
     }
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_lcg64xsh32_serde_even_increment() {
+    let buf = bincode::serialize(&(7u64, 2u64)).unwrap();
+    assert!(bincode::deserialize::<Lcg64Xsh32>(&buf).is_err());
+
+    let buf = bincode::serialize(&(7u64, 3u64)).unwrap();
+    let rng: Lcg64Xsh32 = bincode::deserialize(&buf).unwrap();
+    assert_eq!(rng, Lcg64Xsh32::from_state_increment(7, 3).unwrap());
+}
@@ -14,6 +14,30 @@ fn test_mcg128xsl64_advancing() {
     }
 }
 
+#[test]
+fn test_mcg128xsl64_retreat_distance() {
+    for seed in 0..20 {
+        let rng1 = Mcg128Xsl64::seed_from_u64(seed);
+        let mut rng2 = rng1.clone();
+        for _ in 0..20 {
+            rng2.next_u64();
+        }
+        assert_eq!(rng1.distance(&rng2), Some(20));
+        assert_eq!(rng2.distance(&rng1), Some((1 << 126) - 20));
+
+        let mut rng3 = rng1.clone();
+        rng3.advance(1 << 40 | 12345);
+        assert_eq!(rng1.distance(&rng3), Some(1 << 40 | 12345));
+
+        rng2.retreat(20);
+        assert_eq!(rng1, rng2);
+        assert_eq!(rng1.distance(&rng2), Some(0));
+    }
+
+    // States on different cycles
+    assert_eq!(Mcg128Xsl64::new(1).distance(&Mcg128Xsl64::new(3)), None);
+}
+
 #[test]
 fn test_mcg128xsl64_construction() {
     // Test that various construction techniques produce a working RNG.
@@ -73,3 +97,13 @@ fn test_mcg128xsl64_serde() {
         assert_eq!(rng.next_u64(), deserialized.next_u64());
     }
 }
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_mcg128xsl64_serde_even_state() {
+    let buf = bincode::serialize(&4u128).unwrap();
+    assert!(bincode::deserialize::<Mcg128Xsl64>(&buf).is_err());
+    let buf = bincode::serialize(&5u128).unwrap();
+    let rng: Mcg128Xsl64 = bincode::deserialize(&buf).unwrap();
+    assert_eq!(rng, Mcg128Xsl64::new(5));
+}
@@ -0,0 +1,44 @@
+use rand_pcg::SeedSequence;
+
+#[test]
+fn test_seed_seq_reference() {
+    // Reference data from the C++ `seed_seq_fe128` implementation, as also
+    // used by NumPy's test suite.
+    let seq = SeedSequence::new(&[3735928559, 195939070, 229505742, 305419896]);
+    let mut results = [0u32; 4];
+    seq.generate_state(&mut results);
+    assert_eq!(results, [3914649087, 576849849, 3593928901, 2229911004]);
+}
+
+#[test]
+fn test_seed_seq_numpy() {
+    // Numbers determined using `numpy.random.SeedSequence`.
+    let entropy: [u32; 10] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+    let mut results = [0u32; 6];
+    SeedSequence::new(&entropy).generate_state(&mut results);
+    let expected = [
+        3816053309, 868430205, 1561401649, 2832469222, 1622583007, 989210168,
+    ];
+    assert_eq!(results, expected);
+
+    let mut results = [0u64; 2];
+    SeedSequence::from_u128((1 << 100) + 7).generate_state_u64(&mut results);
+    assert_eq!(results, [33175750552016506, 12324894697821659760]);
+
+    let mut results = [0u32; 4];
+    SeedSequence::with_spawn_key(&[12345], &[0]).generate_state(&mut results);
+    assert_eq!(results, [959183449, 3196577012, 2719720162, 1792540688]);
+    SeedSequence::with_spawn_key(&[12345], &[3, 1]).generate_state(&mut results);
+    assert_eq!(results, [1508687055, 286920059, 3852731631, 1324126445]);
+}
+
+#[test]
+fn test_seed_seq_from_u128() {
+    // Integer seeds are split into the least number of 32-bit words.
+    assert_eq!(SeedSequence::from_u128(0), SeedSequence::new(&[0]));
+    assert_eq!(SeedSequence::from_u128(12345), SeedSequence::new(&[12345]));
+    assert_eq!(
+        SeedSequence::from_u128(0x1_0000_0002),
+        SeedSequence::new(&[2, 1])
+    );
+}
@@ -0,0 +1,91 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Counter-based random number generators.
+//!
+//! This is a native Rust implementation of a selection of the counter-based
+//! generators of the [Random123] library, described in Salmon et al.,
+//! ["Parallel Random Numbers: As Easy as 1, 2, 3"][paper] (SC 2011).
+//!
+//! A counter-based generator computes each block of output as a pure
+//! function of a *key* and a *counter*. Any position in the output stream can
+//! thus be reached in constant time (see `set_counter`), and distinct keys or
+//! counter ranges may be assigned to parallel workers for reproducible
+//! results independent of scheduling.
+//!
+//! ## Generators
+//!
+//! This crate provides:
+//!
+//! -   [`Philox4x32Rng`], officially known as `philox4x32_10`. This uses
+//!     32-bit multiplications and is a good choice on 32-bit CPUs and GPUs.
+//!     It is the default generator of cuRAND's Philox.
+//! -   [`Philox4x64Rng`], officially known as `philox4x64_10`, as used by
+//!     NumPy's `Philox` bit generator.
+//! -   [`Threefry4x64Rng`], officially known as `threefry4x64_20`. This uses
+//!     only additions, rotations and XORs.
+//!
+//! These generators are all deterministic and portable (see [Reproducibility]
+//! in the book), with testing against the Random123 known-answer vectors.
+//! They are not suitable for cryptographic uses.
+//!
+//! ## Seeding (construction)
+//!
+//! Generators implement the [`SeedableRng`] trait, where the seed is the key.
+//! The counter starts at zero. Alternatively, a key may be given directly:
+//! ```
+//! use rand_core::RngCore;
+//! use rand_random123::Philox4x32Rng;
+//!
+//! let mut rng = Philox4x32Rng::new([0, 0]);
+//! assert_eq!(rng.next_u32(), 0x6627e8d5);
+//!
+//! // Jump to block 1000; any position is reached in O(1).
+//! rng.set_counter([1000, 0, 0, 0]);
+//! # let _ = rng.next_u32();
+//! ```
+//!
+//! See also [Seeding RNGs] in the book.
+//!
+//! ## Generation
+//!
+//! Generators implement [`RngCore`], whose methods may be used directly to
+//! generate unbounded integer or byte values. The block function itself is
+//! available as `block` on the core types, e.g. [`Philox4x32Core::block`].
+//!
+//! It is often more convenient to use the [`rand::Rng`] trait, which provides
+//! further functionality. See also the [Random Values] chapter in the book.
+//!
+//! [Random123]: https://github.com/DEShawResearch/random123
+//! [paper]: https://doi.org/10.1145/2063384.2063405
+//! [Reproducibility]: https://rust-random.github.io/book/crate-reprod.html
+//! [Seeding RNGs]: https://rust-random.github.io/book/guide-seeding.html
+//! [Random Values]: https://rust-random.github.io/book/guide-values.html
+//! [`RngCore`]: rand_core::RngCore
+//! [`SeedableRng`]: rand_core::SeedableRng
+//! [`rand::Rng`]: https://docs.rs/rand/latest/rand/trait.Rng.html
+
+#![doc(
+    html_logo_url = "https://www.rust-lang.org/logos/rust-logo-128x128-blk.png",
+    html_favicon_url = "https://www.rust-lang.org/favicon.ico",
+    html_root_url = "https://rust-random.github.io/rand/"
+)]
+#![forbid(unsafe_code)]
+#![deny(missing_docs)]
+#![deny(missing_debug_implementations)]
+#![no_std]
+
+#[macro_use]
+mod macros;
+mod philox;
+mod threefry;
+
+pub use rand_core;
+
+pub use self::philox::{Philox4x32Core, Philox4x32Rng, Philox4x64Core, Philox4x64Rng};
+pub use self::threefry::{Threefry4x64Core, Threefry4x64Rng};
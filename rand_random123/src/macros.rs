@@ -0,0 +1,267 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Shared implementation of the counter-based generators
+
+use rand_core::block::{BlockRng, BlockRng64, BlockRngCore};
+
+/// Read position within the buffer of a block wrapper
+pub(crate) trait BufferPosition {
+    /// Index of the buffered word the next output is taken from
+    fn word_index(&self) -> usize;
+
+    /// Whether the lower half of that word has already been returned by
+    /// `next_u32`
+    fn half_used(&self) -> bool;
+}
+
+impl<R: BlockRngCore> BufferPosition for BlockRng<R> {
+    #[inline]
+    fn word_index(&self) -> usize {
+        self.index()
+    }
+
+    #[inline]
+    fn half_used(&self) -> bool {
+        false
+    }
+}
+
+impl<R: BlockRngCore> BufferPosition for BlockRng64<R> {
+    #[inline]
+    fn word_index(&self) -> usize {
+        // `index` has already moved past a half-used word
+        self.index() - BlockRng64::half_used(self) as usize
+    }
+
+    #[inline]
+    fn half_used(&self) -> bool {
+        BlockRng64::half_used(self)
+    }
+}
+
+/// Implement a core and an RNG wrapper type for a counter-based generator
+/// with a four-word counter and output block.
+///
+/// `$block` must be a function `fn([$W; $K], [$W; 4]) -> [$W; 4]` mapping
+/// key and counter to an output block.
+macro_rules! counter_rng_impl {
+    (
+        $(#[$core_meta:meta])*
+        core $Core:ident,
+        $(#[$rng_meta:meta])*
+        rng $Rng:ident,
+        word $W:ident,
+        key_words $K:expr,
+        buffer $BlockRng:ident,
+        read_into $read_into:path,
+        block $block:path,
+    ) => {
+        $(#[$core_meta])*
+        #[derive(Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        pub struct $Core {
+            key: [$W; $K],
+            counter: [$W; 4],
+        }
+
+        impl $Core {
+            /// Construct with the given key, starting at counter zero.
+            #[inline]
+            pub fn new(key: [$W; $K]) -> Self {
+                $Core {
+                    key,
+                    counter: [0; 4],
+                }
+            }
+
+            /// Compute the output block for `counter`.
+            ///
+            /// This is a pure function of the key and `counter`; the
+            /// generator's own counter is neither used nor modified.
+            /// Counter words are ordered least significant first.
+            #[inline]
+            pub fn block(&self, counter: [$W; 4]) -> [$W; 4] {
+                $block(self.key, counter)
+            }
+        }
+
+        // Custom Debug implementation that does not expose the internal state
+        impl fmt::Debug for $Core {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, concat!(stringify!($Core), " {{}}"))
+            }
+        }
+
+        impl BlockRngCore for $Core {
+            type Item = $W;
+            type Results = [$W; 4];
+
+            #[inline]
+            fn generate(&mut self, results: &mut Self::Results) {
+                *results = self.block(self.counter);
+                // increment the multi-word counter, wrapping at the end
+                for word in self.counter.iter_mut() {
+                    *word = word.wrapping_add(1);
+                    if *word != 0 {
+                        break;
+                    }
+                }
+            }
+        }
+
+        impl SeedableRng for $Core {
+            type Seed = [u8; $K * core::mem::size_of::<$W>()];
+
+            /// The seed is used as the key (little-endian words); the counter
+            /// starts at zero.
+            #[inline]
+            fn from_seed(seed: Self::Seed) -> Self {
+                let mut key = [0; $K];
+                $read_into(&seed, &mut key);
+                $Core::new(key)
+            }
+        }
+
+        $(#[$rng_meta])*
+        #[derive(Clone, Debug)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        pub struct $Rng {
+            rng: $BlockRng<$Core>,
+        }
+
+        impl $Rng {
+            /// Construct with the given key, starting at counter zero.
+            #[inline]
+            pub fn new(key: [$W; $K]) -> Self {
+                $Core::new(key).into()
+            }
+
+            /// Get the key.
+            #[inline]
+            pub fn get_key(&self) -> [$W; $K] {
+                self.rng.core.key
+            }
+
+            /// Get the counter of the block the next output word is taken
+            /// from.
+            ///
+            /// Counter words are ordered least significant first.
+            #[inline]
+            pub fn get_counter(&self) -> [$W; 4] {
+                let mut counter = self.rng.core.counter;
+                if $crate::macros::BufferPosition::word_index(&self.rng) < 4 {
+                    // the core has already advanced past the buffered block
+                    for word in counter.iter_mut() {
+                        let old = *word;
+                        *word = old.wrapping_sub(1);
+                        if old != 0 {
+                            break;
+                        }
+                    }
+                }
+                counter
+            }
+
+            /// Set the counter, such that the next output word is the first
+            /// word of the block for `counter`.
+            ///
+            /// Counter words are ordered least significant first. This allows
+            /// seeking to any position in O(1) time.
+            #[inline]
+            pub fn set_counter(&mut self, counter: [$W; 4]) {
+                self.rng.core.counter = counter;
+                self.rng.reset();
+            }
+
+            /// Get the offset of the next output word within its block
+            /// (see [`Self::get_counter`]), in the range `0..4`.
+            ///
+            /// With 64-bit words, a call to `next_u32` returns only the lower
+            /// half of a word. The offset then remains that of the partially
+            /// read word until a further `next_u32` returns its upper half (see
+            /// [`Self::is_half_word_used`]).
+            #[inline]
+            pub fn get_word_offset(&self) -> usize {
+                $crate::macros::BufferPosition::word_index(&self.rng) % 4
+            }
+
+            /// Whether the lower half of the next output word has already been
+            /// returned by `next_u32`
+            ///
+            /// If so, the next call to `next_u32` returns the upper half of
+            /// the word. This is always `false` for generators with 32-bit
+            /// words.
+            #[inline]
+            pub fn is_half_word_used(&self) -> bool {
+                $crate::macros::BufferPosition::half_used(&self.rng)
+            }
+
+            /// Set the offset of the next output word within the current
+            /// block (see [`Self::get_counter`]).
+            ///
+            /// The next output starts at the beginning of that word.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `offset >= 4`.
+            #[inline]
+            pub fn set_word_offset(&mut self, offset: usize) {
+                assert!(offset < 4, "word offset out of range");
+                self.rng.core.counter = self.get_counter();
+                self.rng.generate_and_set(offset);
+            }
+        }
+
+        impl SeedableRng for $Rng {
+            type Seed = <$Core as SeedableRng>::Seed;
+
+            /// The seed is used as the key (little-endian words); the counter
+            /// starts at zero.
+            #[inline]
+            fn from_seed(seed: Self::Seed) -> Self {
+                $Core::from_seed(seed).into()
+            }
+        }
+
+        impl RngCore for $Rng {
+            #[inline]
+            fn next_u32(&mut self) -> u32 {
+                self.rng.next_u32()
+            }
+
+            #[inline]
+            fn next_u64(&mut self) -> u64 {
+                self.rng.next_u64()
+            }
+
+            #[inline]
+            fn fill_bytes(&mut self, bytes: &mut [u8]) {
+                self.rng.fill_bytes(bytes)
+            }
+        }
+
+        impl From<$Core> for $Rng {
+            fn from(core: $Core) -> Self {
+                $Rng {
+                    rng: $BlockRng::new(core),
+                }
+            }
+        }
+
+        impl PartialEq for $Rng {
+            fn eq(&self, rhs: &$Rng) -> bool {
+                self.get_key() == rhs.get_key()
+                    && self.get_counter() == rhs.get_counter()
+                    && self.get_word_offset() == rhs.get_word_offset()
+                    && self.is_half_word_used() == rhs.is_half_word_used()
+            }
+        }
+        impl Eq for $Rng {}
+    };
+}
@@ -0,0 +1,113 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Philox random number generators
+
+use core::fmt;
+use rand_core::block::{BlockRng, BlockRng64, BlockRngCore};
+use rand_core::{le, RngCore, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// Number of rounds of the standard Philox variants
+const ROUNDS: usize = 10;
+
+// Multipliers and Weyl sequence increments ("key bumps") from Random123
+const M4X32: [u32; 2] = [0xD251_1F53, 0xCD9E_8D57];
+const W32: [u32; 2] = [0x9E37_79B9, 0xBB67_AE85];
+const M4X64: [u64; 2] = [0xD2E7_470E_E14C_6C93, 0xCA5A_8263_9512_1157];
+const W64: [u64; 2] = [0x9E37_79B9_7F4A_7C15, 0xBB67_AE85_84CA_A73B];
+
+#[inline]
+fn mulhilo32(a: u32, b: u32) -> (u32, u32) {
+    let product = u64::from(a) * u64::from(b);
+    ((product >> 32) as u32, product as u32)
+}
+
+#[inline]
+fn mulhilo64(a: u64, b: u64) -> (u64, u64) {
+    let product = u128::from(a) * u128::from(b);
+    ((product >> 64) as u64, product as u64)
+}
+
+fn philox4x32(mut key: [u32; 2], mut ctr: [u32; 4]) -> [u32; 4] {
+    for round in 0..ROUNDS {
+        if round > 0 {
+            key[0] = key[0].wrapping_add(W32[0]);
+            key[1] = key[1].wrapping_add(W32[1]);
+        }
+        let (hi0, lo0) = mulhilo32(M4X32[0], ctr[0]);
+        let (hi1, lo1) = mulhilo32(M4X32[1], ctr[2]);
+        ctr = [hi1 ^ ctr[1] ^ key[0], lo1, hi0 ^ ctr[3] ^ key[1], lo0];
+    }
+    ctr
+}
+
+fn philox4x64(mut key: [u64; 2], mut ctr: [u64; 4]) -> [u64; 4] {
+    for round in 0..ROUNDS {
+        if round > 0 {
+            key[0] = key[0].wrapping_add(W64[0]);
+            key[1] = key[1].wrapping_add(W64[1]);
+        }
+        let (hi0, lo0) = mulhilo64(M4X64[0], ctr[0]);
+        let (hi1, lo1) = mulhilo64(M4X64[1], ctr[2]);
+        ctr = [hi1 ^ ctr[1] ^ key[0], lo1, hi0 ^ ctr[3] ^ key[1], lo0];
+    }
+    ctr
+}
+
+counter_rng_impl! {
+    /// The core of [`Philox4x32Rng`], computing one block of four `u32`
+    /// words per counter value.
+    core Philox4x32Core,
+    /// A Philox random number generator (4x32, 10 rounds).
+    ///
+    /// Philox is a counter-based generator using a keyed bijection built from
+    /// 32-bit multiplications, by Salmon et al. This is `philox4x32_10` from
+    /// Random123, and also the generator of cuRAND's
+    /// `CURAND_RNG_PSEUDO_PHILOX4_32_10`.
+    ///
+    /// The key is 64 bits and the counter 128 bits; each counter value
+    /// yields a block of four `u32` words, used in order. The counter is
+    /// initialized to zero but may be set via [`Philox4x32Rng::set_counter`].
+    ///
+    /// This implementation uses an output buffer of a single block and uses
+    /// [`BlockRng`] to implement the [`RngCore`] methods.
+    rng Philox4x32Rng,
+    word u32,
+    key_words 2,
+    buffer BlockRng,
+    read_into le::read_u32_into,
+    block philox4x32,
+}
+
+counter_rng_impl! {
+    /// The core of [`Philox4x64Rng`], computing one block of four `u64`
+    /// words per counter value.
+    core Philox4x64Core,
+    /// A Philox random number generator (4x64, 10 rounds).
+    ///
+    /// Philox is a counter-based generator using a keyed bijection built from
+    /// 64-bit multiplications, by Salmon et al. This is `philox4x64_10` from
+    /// Random123, as used by NumPy's `Philox` bit generator. (NumPy increments
+    /// the counter before generating each block, thus its first block uses
+    /// counter `[1, 0, 0, 0]`.)
+    ///
+    /// The key is 128 bits and the counter 256 bits; each counter value
+    /// yields a block of four `u64` words, used in order. The counter is
+    /// initialized to zero but may be set via [`Philox4x64Rng::set_counter`].
+    ///
+    /// This implementation uses an output buffer of a single block and uses
+    /// [`BlockRng64`] to implement the [`RngCore`] methods.
+    rng Philox4x64Rng,
+    word u64,
+    key_words 2,
+    buffer BlockRng64,
+    read_into le::read_u64_into,
+    block philox4x64,
+}
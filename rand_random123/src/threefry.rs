@@ -0,0 +1,99 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Threefry random number generator
+
+use core::fmt;
+use rand_core::block::{BlockRng64, BlockRngCore};
+use rand_core::{le, RngCore, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// Number of rounds of the standard Threefry variant
+const ROUNDS: usize = 20;
+
+// Key schedule parity constant from Skein
+const KS_PARITY: u64 = 0x1BD1_1BDA_A9FC_1A22;
+
+// Rotation constants, indexed by round modulo 8
+const ROTATIONS: [[u32; 2]; 8] = [
+    [14, 16],
+    [52, 57],
+    [23, 40],
+    [5, 37],
+    [25, 33],
+    [46, 12],
+    [58, 22],
+    [32, 32],
+];
+
+fn threefry4x64(key: [u64; 4], ctr: [u64; 4]) -> [u64; 4] {
+    let ks = [
+        key[0],
+        key[1],
+        key[2],
+        key[3],
+        KS_PARITY ^ key[0] ^ key[1] ^ key[2] ^ key[3],
+    ];
+    let mut x = [
+        ctr[0].wrapping_add(ks[0]),
+        ctr[1].wrapping_add(ks[1]),
+        ctr[2].wrapping_add(ks[2]),
+        ctr[3].wrapping_add(ks[3]),
+    ];
+
+    for round in 0..ROUNDS {
+        let [r0, r1] = ROTATIONS[round % 8];
+        if round % 2 == 0 {
+            x[0] = x[0].wrapping_add(x[1]);
+            x[1] = x[1].rotate_left(r0) ^ x[0];
+            x[2] = x[2].wrapping_add(x[3]);
+            x[3] = x[3].rotate_left(r1) ^ x[2];
+        } else {
+            x[0] = x[0].wrapping_add(x[3]);
+            x[3] = x[3].rotate_left(r0) ^ x[0];
+            x[2] = x[2].wrapping_add(x[1]);
+            x[1] = x[1].rotate_left(r1) ^ x[2];
+        }
+
+        // inject the key schedule every four rounds
+        if round % 4 == 3 {
+            let s = (round + 1) / 4;
+            for (i, x) in x.iter_mut().enumerate() {
+                *x = x.wrapping_add(ks[(s + i) % 5]);
+            }
+            x[3] = x[3].wrapping_add(s as u64);
+        }
+    }
+    x
+}
+
+counter_rng_impl! {
+    /// The core of [`Threefry4x64Rng`], computing one block of four `u64`
+    /// words per counter value.
+    core Threefry4x64Core,
+    /// A Threefry random number generator (4x64, 20 rounds).
+    ///
+    /// Threefry is a counter-based generator by Salmon et al., derived from
+    /// the Threefish block cipher used in the Skein hash function. It uses
+    /// only additions, rotations and XORs. This is `threefry4x64_20` from
+    /// Random123.
+    ///
+    /// The key and counter are both 256 bits; each counter value yields a
+    /// block of four `u64` words, used in order. The counter is initialized to
+    /// zero but may be set via [`Threefry4x64Rng::set_counter`].
+    ///
+    /// This implementation uses an output buffer of a single block and uses
+    /// [`BlockRng64`] to implement the [`RngCore`] methods.
+    rng Threefry4x64Rng,
+    word u64,
+    key_words 4,
+    buffer BlockRng64,
+    read_into le::read_u64_into,
+    block threefry4x64,
+}
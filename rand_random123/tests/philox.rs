@@ -0,0 +1,196 @@
+use rand_core::{RngCore, SeedableRng};
+use rand_random123::{Philox4x32Core, Philox4x32Rng, Philox4x64Core, Philox4x64Rng};
+
+#[test]
+fn test_philox4x32_kat() {
+    // Known-answer vectors from Random123 (kat_vectors).
+    let core = Philox4x32Core::new([0, 0]);
+    assert_eq!(
+        core.block([0, 0, 0, 0]),
+        [0x6627e8d5, 0xe169c58d, 0xbc57ac4c, 0x9b00dbd8]
+    );
+
+    let core = Philox4x32Core::new([u32::MAX, u32::MAX]);
+    assert_eq!(
+        core.block([u32::MAX; 4]),
+        [0x408f276d, 0x41c83b0e, 0xa20bc7c6, 0x6d5451fd]
+    );
+
+    let core = Philox4x32Core::new([0xa4093822, 0x299f31d0]);
+    assert_eq!(
+        core.block([0x243f6a88, 0x85a308d3, 0x13198a2e, 0x03707344]),
+        [0xd16cfe09, 0x94fdcceb, 0x5001e420, 0x24126ea1]
+    );
+}
+
+#[test]
+fn test_philox4x64_kat() {
+    // Known-answer vectors from Random123 (kat_vectors).
+    let core = Philox4x64Core::new([0, 0]);
+    assert_eq!(
+        core.block([0, 0, 0, 0]),
+        [
+            0x16554d9eca36314c,
+            0xdb20fe9d672d0fdc,
+            0xd7e772cee186176b,
+            0x7e68b68aec7ba23b
+        ]
+    );
+
+    let core = Philox4x64Core::new([u64::MAX, u64::MAX]);
+    assert_eq!(
+        core.block([u64::MAX; 4]),
+        [
+            0x87b092c3013fe90b,
+            0x438c3c67be8d0224,
+            0x9cc7d7c69cd777b6,
+            0xa09caebf594f0ba0
+        ]
+    );
+
+    let core = Philox4x64Core::new([0x452821e638d01377, 0xbe5466cf34e90c6c]);
+    let ctr = [
+        0x243f6a8885a308d3,
+        0x13198a2e03707344,
+        0xa4093822299f31d0,
+        0x082efa98ec4e6c89,
+    ];
+    assert_eq!(
+        core.block(ctr),
+        [
+            0xa528f45403e61d95,
+            0x38c72dbd566e9788,
+            0xa5a1610e72fd18b5,
+            0x57bd43b5e52b7fe6
+        ]
+    );
+}
+
+#[test]
+fn test_philox4x32_stream() {
+    let mut rng = Philox4x32Rng::new([0, 0]);
+    let mut results = [0u32; 8];
+    for i in results.iter_mut() {
+        *i = rng.next_u32();
+    }
+    let expected = [
+        0x6627e8d5, 0xe169c58d, 0xbc57ac4c, 0x9b00dbd8, // counter 0
+        0xf8e4cca4, 0x5cb200db, 0xb1a574eb, 0x097eff67, // counter 1
+    ];
+    assert_eq!(results, expected);
+
+    // The seed is the key
+    let mut rng = Philox4x32Rng::from_seed([0; 8]);
+    assert_eq!(rng.next_u64(), 0xe169c58d_6627e8d5);
+}
+
+#[test]
+fn test_philox4x32_counter() {
+    let mut rng = Philox4x32Rng::new([0, 0]);
+    assert_eq!(rng.get_counter(), [0, 0, 0, 0]);
+    assert_eq!(rng.get_word_offset(), 0);
+
+    rng.next_u32();
+    assert_eq!(rng.get_counter(), [0, 0, 0, 0]);
+    assert_eq!(rng.get_word_offset(), 1);
+
+    for _ in 0..3 {
+        rng.next_u32();
+    }
+    assert_eq!(rng.get_counter(), [1, 0, 0, 0]);
+    assert_eq!(rng.get_word_offset(), 0);
+    assert_eq!(rng.next_u32(), 0xf8e4cca4);
+
+    // Seeking
+    rng.set_counter([1, 0, 0, 0]);
+    rng.set_word_offset(3);
+    assert_eq!(rng.get_counter(), [1, 0, 0, 0]);
+    assert_eq!(rng.get_word_offset(), 3);
+    assert_eq!(rng.next_u32(), 0x097eff67);
+
+    // Carry into the next counter word
+    rng.set_counter([u32::MAX, 0, 0, 0]);
+    for _ in 0..4 {
+        rng.next_u32();
+    }
+    assert_eq!(rng.get_counter(), [0, 1, 0, 0]);
+    assert_eq!(rng.next_u32(), 0x6ad0c5ec);
+
+    // Equality depends only on key and position
+    let mut rng1 = Philox4x32Rng::new([1, 2]);
+    let mut rng2 = rng1.clone();
+    rng1.next_u32();
+    assert_ne!(rng1, rng2);
+    rng2.set_word_offset(1);
+    assert_eq!(rng1, rng2);
+    assert_eq!(rng1.next_u64(), rng2.next_u64());
+}
+
+#[test]
+fn test_philox4x64_stream() {
+    let mut rng = Philox4x64Rng::new([1, 2]);
+    rng.set_counter([1, 0, 0, 0]);
+    let mut results = [0u64; 4];
+    for i in results.iter_mut() {
+        *i = rng.next_u64();
+    }
+    let expected = [
+        0x4f2f4313b5536b09,
+        0x5b617be3219ff32a,
+        0x097293476f9275cb,
+        0xf63f3bf4962c3942,
+    ];
+    assert_eq!(results, expected);
+    assert_eq!(rng.get_counter(), [2, 0, 0, 0]);
+    assert_eq!(rng.get_key(), [1, 2]);
+}
+
+#[test]
+fn test_philox4x64_half_word() {
+    let mut rng = Philox4x64Rng::new([1, 2]);
+    rng.set_counter([1, 0, 0, 0]);
+    rng.set_word_offset(3);
+    assert!(!rng.is_half_word_used());
+
+    // The lower half of the last word in the block
+    assert_eq!(rng.next_u32(), 0x962c3942);
+    assert_eq!(rng.get_counter(), [1, 0, 0, 0]);
+    assert_eq!(rng.get_word_offset(), 3);
+    assert!(rng.is_half_word_used());
+
+    // Equality includes the half-word state
+    let mut rng2 = rng.clone();
+    rng2.set_word_offset(3);
+    assert_ne!(rng, rng2);
+    rng2.next_u32();
+    assert_eq!(rng, rng2);
+
+    assert_eq!(rng.next_u32(), 0xf63f3bf4);
+    assert_eq!(rng.get_counter(), [2, 0, 0, 0]);
+    assert_eq!(rng.get_word_offset(), 0);
+    assert!(!rng.is_half_word_used());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_philox4x32_serde() {
+    use bincode;
+    use std::io::{BufReader, BufWriter};
+
+    let mut rng = Philox4x32Rng::seed_from_u64(0);
+    rng.next_u32();
+
+    let buf: Vec<u8> = Vec::new();
+    let mut buf = BufWriter::new(buf);
+    bincode::serialize_into(&mut buf, &rng).expect("Could not serialize");
+
+    let buf = buf.into_inner().unwrap();
+    let mut read = BufReader::new(&buf[..]);
+    let mut deserialized: Philox4x32Rng =
+        bincode::deserialize_from(&mut read).expect("Could not deserialize");
+
+    assert_eq!(rng, deserialized);
+    for _ in 0..16 {
+        assert_eq!(rng.next_u64(), deserialized.next_u64());
+    }
+}
@@ -0,0 +1,97 @@
+use rand_core::{RngCore, SeedableRng};
+use rand_random123::{Threefry4x64Core, Threefry4x64Rng};
+
+#[test]
+fn test_threefry4x64_kat() {
+    // Known-answer vectors from Random123 (kat_vectors).
+    let core = Threefry4x64Core::new([0; 4]);
+    assert_eq!(
+        core.block([0; 4]),
+        [
+            0x09218ebde6c85537,
+            0x55941f5266d86105,
+            0x4bd25e16282434dc,
+            0xee29ec846bd2e40b
+        ]
+    );
+
+    let core = Threefry4x64Core::new([u64::MAX; 4]);
+    assert_eq!(
+        core.block([u64::MAX; 4]),
+        [
+            0x29c24097942bba1b,
+            0x0371bbfb0f6f4e11,
+            0x3c231ffa33f83a1c,
+            0xcd29113fde32d168
+        ]
+    );
+
+    let core = Threefry4x64Core::new([
+        0x452821e638d01377,
+        0xbe5466cf34e90c6c,
+        0xbe5466cf34e90c6c,
+        0xc0ac29b7c97c50dd,
+    ]);
+    let ctr = [
+        0x243f6a8885a308d3,
+        0x13198a2e03707344,
+        0xa4093822299f31d0,
+        0x082efa98ec4e6c89,
+    ];
+    assert_eq!(
+        core.block(ctr),
+        [
+            0xa7e8fde591651bd9,
+            0xbaafd0c30138319b,
+            0x84a5c1a729e685b9,
+            0x901d406ccebc1ba4
+        ]
+    );
+}
+
+#[test]
+fn test_threefry4x64_stream() {
+    let mut rng = Threefry4x64Rng::from_seed([0; 32]);
+    let mut results = [0u64; 6];
+    for i in results.iter_mut() {
+        *i = rng.next_u64();
+    }
+    let expected = [
+        0x09218ebde6c85537,
+        0x55941f5266d86105,
+        0x4bd25e16282434dc,
+        0xee29ec846bd2e40b,
+        0xaffbae48c21f4d17,
+        0x69d9911959a2be5d,
+    ];
+    assert_eq!(results, expected);
+    assert_eq!(rng.get_counter(), [1, 0, 0, 0]);
+    assert_eq!(rng.get_word_offset(), 2);
+
+    rng.set_counter([1, 0, 0, 0]);
+    assert_eq!(rng.next_u64(), 0xaffbae48c21f4d17);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_threefry4x64_serde() {
+    use bincode;
+    use std::io::{BufReader, BufWriter};
+
+    let mut rng = Threefry4x64Rng::seed_from_u64(0);
+    rng.next_u64();
+
+    let buf: Vec<u8> = Vec::new();
+    let mut buf = BufWriter::new(buf);
+    bincode::serialize_into(&mut buf, &rng).expect("Could not serialize");
+
+    let buf = buf.into_inner().unwrap();
+    let mut read = BufReader::new(&buf[..]);
+    let mut deserialized: Threefry4x64Rng =
+        bincode::deserialize_from(&mut read).expect("Could not deserialize");
+
+    assert_eq!(rng, deserialized);
+    for _ in 0..16 {
+        assert_eq!(rng.next_u64(), deserialized.next_u64());
+    }
+}
@@ -0,0 +1,515 @@
+// Copyright 2018 Developers of the Rand project.
+// Copyright 2013 The Rust Project Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The dirichlet distribution `Dirichlet(α₁, α₂, ..., αₙ)`.
+
+use crate::distr::{Beta, Distribution, Exp1, Gamma, Open01, StandardNormal};
+use crate::Rng;
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::fmt;
+use num_traits::{Float, NumCast};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+enum DirichletRepr<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    /// Normalise samples of `Gamma(αᵢ, 1)`
+    FromGamma(Box<[Gamma<F>]>),
+
+    /// Stick-breaking with samples of `Beta(αᵢ, αᵢ₊₁ + ... + αₖ)`; there is
+    /// one sampler less than the number of dimensions.
+    FromBeta(Box<[Beta<F>]>),
+}
+
+/// The [Dirichlet distribution](https://en.wikipedia.org/wiki/Dirichlet_distribution) `Dirichlet(α₁, α₂, ..., αₖ)`
+/// with dimension `k` chosen at run-time.
+///
+/// The Dirichlet distribution is a family of continuous multivariate
+/// probability distributions parameterized by a vector of positive
+/// real numbers `α₁, α₂, ..., αₖ`, where `k` is the number of dimensions
+/// of the distribution. The distribution is supported on the `k-1`-dimensional
+/// simplex, which is the set of points `x = [x₁, x₂, ..., xₖ]` such that
+/// `0 ≤ xᵢ ≤ 1` and `∑ xᵢ = 1`.
+/// It is a multivariate generalization of the [`Beta`] distribution.
+/// The distribution is symmetric when all `αᵢ` are equal.
+///
+/// See [`Dirichlet`] for a version where `k` is a compile-time constant.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{DirichletDyn, Distribution};
+///
+/// let alpha = vec![1.0, 2.0, 3.0];
+/// let dirichlet = DirichletDyn::new(&alpha).unwrap();
+/// let samples: Vec<f64> = dirichlet.sample(&mut rand::rng());
+/// println!("{:?} is from a Dirichlet({:?}) distribution", samples, alpha);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "DirichletDynFields<F>"))]
+pub struct DirichletDyn<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    len: usize,
+    repr: DirichletRepr<F>,
+}
+
+/// The serialized fields of [`DirichletDyn`], validated on deserialization.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "DirichletDyn")]
+struct DirichletDynFields<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    len: usize,
+    repr: DirichletRepr<F>,
+}
+
+#[cfg(feature = "serde")]
+impl<F> TryFrom<DirichletDynFields<F>> for DirichletDyn<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    type Error = &'static str;
+
+    fn try_from(fields: DirichletDynFields<F>) -> Result<Self, &'static str> {
+        let samplers = match &fields.repr {
+            DirichletRepr::FromGamma(samplers) => samplers.len(),
+            DirichletRepr::FromBeta(samplers) => samplers.len() + 1,
+        };
+        if fields.len < 2 || samplers != fields.len {
+            return Err("number of samplers does not match dimension in Dirichlet distribution");
+        }
+        Ok(DirichletDyn {
+            len: fields.len,
+            repr: fields.repr,
+        })
+    }
+}
+
+/// The [Dirichlet distribution](https://en.wikipedia.org/wiki/Dirichlet_distribution) `Dirichlet(α₁, α₂, ..., αₖ)`
+/// with dimension `k = N` fixed at compile-time.
+///
+/// This is the same distribution as [`DirichletDyn`], but samples arrays
+/// `[F; N]` (as well as `Vec<F>`).
+///
+/// # Plot
+///
+/// The following plot illustrates the 2-dimensional simplices for various
+/// 3-dimensional Dirichlet distributions.
+///
+/// ![Dirichlet distribution](https://raw.githubusercontent.com/rust-random/charts/main/charts/dirichlet.png)
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Dirichlet, Distribution};
+///
+/// let dirichlet = Dirichlet::new([1.0, 2.0, 3.0]).unwrap();
+/// let samples: [f64; 3] = dirichlet.sample(&mut rand::rng());
+/// println!("{:?} is from a Dirichlet([1.0, 2.0, 3.0]) distribution", samples);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "DirichletFields<F>"))]
+pub struct Dirichlet<F, const N: usize>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    inner: DirichletDyn<F>,
+}
+
+/// The serialized fields of [`Dirichlet`], validated on deserialization.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "Dirichlet")]
+struct DirichletFields<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    inner: DirichletDyn<F>,
+}
+
+#[cfg(feature = "serde")]
+impl<F, const N: usize> TryFrom<DirichletFields<F>> for Dirichlet<F, N>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    type Error = &'static str;
+
+    fn try_from(fields: DirichletFields<F>) -> Result<Self, &'static str> {
+        if fields.inner.len != N {
+            return Err("dimension does not match N in Dirichlet distribution");
+        }
+        Ok(Dirichlet {
+            inner: fields.inner,
+        })
+    }
+}
+
+/// Error type returned from [`Dirichlet::new`] and [`DirichletDyn::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DirichletError {
+    /// `alpha.len() < 2`.
+    AlphaTooShort,
+    /// `alpha <= 0.0` or `nan`.
+    AlphaTooSmall,
+    /// `alpha` is subnormal.
+    /// Variate generation methods are not reliable with subnormal inputs.
+    AlphaSubnormal,
+    /// `alpha` is infinite.
+    AlphaInfinite,
+    /// Failed to create required Gamma distribution(s).
+    FailedToCreateGamma,
+    /// Failed to create required Beta distribition(s).
+    FailedToCreateBeta,
+}
+
+impl fmt::Display for DirichletError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DirichletError::AlphaTooShort => "less than 2 dimensions in Dirichlet distribution",
+            DirichletError::AlphaTooSmall => "alpha is not positive in Dirichlet distribution",
+            DirichletError::AlphaSubnormal => {
+                "alpha contains a subnormal value in Dirichlet distribution"
+            }
+            DirichletError::AlphaInfinite => {
+                "alpha contains an infinite value in Dirichlet distribution"
+            }
+            DirichletError::FailedToCreateGamma => {
+                "failed to create required Gamma distribution for Dirichlet distribution"
+            }
+            DirichletError::FailedToCreateBeta => {
+                "failed to create required Beta distribition for Dirichlet distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DirichletError {}
+
+impl<F> DirichletDyn<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    /// Construct a new `DirichletDyn` with the given alpha parameter `alpha`.
+    ///
+    /// Requires `alpha.len() >= 2`, and each value in `alpha` must be positive,
+    /// finite and not subnormal.
+    pub fn new(alpha: &[F]) -> Result<DirichletDyn<F>, DirichletError> {
+        if alpha.len() < 2 {
+            return Err(DirichletError::AlphaTooShort);
+        }
+        for &ai in alpha.iter() {
+            if !(ai > F::zero()) {
+                // This also catches nan.
+                return Err(DirichletError::AlphaTooSmall);
+            }
+            if ai.is_infinite() {
+                return Err(DirichletError::AlphaInfinite);
+            }
+            if !ai.is_normal() {
+                return Err(DirichletError::AlphaSubnormal);
+            }
+        }
+
+        let repr = if alpha.iter().all(|&x| x <= NumCast::from(0.1).unwrap()) {
+            // Use the Beta method when all the alphas are less than 0.1  This
+            // threshold provides a reasonable compromise between using the faster
+            // Gamma method for as wide a range as possible while ensuring that
+            // the probability of generating nans is negligibly small.
+            //
+            // The i-th sampler is `Beta(αᵢ, αᵢ₊₁ + ... + αₖ)`.
+            let mut rest = F::zero();
+            let mut samplers = Vec::with_capacity(alpha.len() - 1);
+            for i in (0..alpha.len() - 1).rev() {
+                rest = rest + alpha[i + 1];
+                let dist =
+                    Beta::new(alpha[i], rest).map_err(|_| DirichletError::FailedToCreateBeta)?;
+                samplers.push(dist);
+            }
+            samplers.reverse();
+            DirichletRepr::FromBeta(samplers.into_boxed_slice())
+        } else {
+            let samplers = alpha
+                .iter()
+                .map(|&a| Gamma::new(a, F::one()))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| DirichletError::FailedToCreateGamma)?;
+            DirichletRepr::FromGamma(samplers.into_boxed_slice())
+        };
+
+        Ok(DirichletDyn {
+            len: alpha.len(),
+            repr,
+        })
+    }
+
+    /// Sample into `samples`, which must have length `self.len`
+    fn sample_into<R: Rng + ?Sized>(&self, rng: &mut R, samples: &mut [F]) {
+        debug_assert_eq!(samples.len(), self.len);
+        match &self.repr {
+            DirichletRepr::FromGamma(samplers) => {
+                let mut sum = F::zero();
+                for (s, g) in samples.iter_mut().zip(samplers.iter()) {
+                    *s = g.sample(rng);
+                    sum = sum + *s;
+                }
+                let invacc = F::one() / sum;
+                for s in samples.iter_mut() {
+                    *s = *s * invacc;
+                }
+            }
+            DirichletRepr::FromBeta(samplers) => {
+                let mut acc = F::one();
+                for (s, beta) in samples.iter_mut().zip(samplers.iter()) {
+                    let beta_sample = beta.sample(rng);
+                    *s = acc * beta_sample;
+                    acc = acc * (F::one() - beta_sample);
+                }
+                samples[self.len - 1] = acc;
+            }
+        }
+    }
+}
+
+impl<F> Distribution<Vec<F>> for DirichletDyn<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<F> {
+        let mut samples = vec![F::zero(); self.len];
+        self.sample_into(rng, &mut samples);
+        samples
+    }
+}
+
+impl<F, const N: usize> Dirichlet<F, N>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    /// Construct a new `Dirichlet` with the given alpha parameter `alpha`.
+    ///
+    /// Requires `N >= 2`, and each value in `alpha` must be positive,
+    /// finite and not subnormal.
+    #[inline]
+    pub fn new(alpha: [F; N]) -> Result<Dirichlet<F, N>, DirichletError> {
+        let inner = DirichletDyn::new(&alpha)?;
+        Ok(Dirichlet { inner })
+    }
+}
+
+impl<F, const N: usize> Distribution<[F; N]> for Dirichlet<F, N>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [F; N] {
+        let mut samples = [F::zero(); N];
+        self.inner.sample_into(rng, &mut samples);
+        samples
+    }
+}
+
+impl<F, const N: usize> Distribution<Vec<F>> for Dirichlet<F, N>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+    Exp1: Distribution<F>,
+    Open01: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<F> {
+        self.inner.sample(rng)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_dirichlet() {
+        let d = Dirichlet::new([1.0, 2.0, 3.0]).unwrap();
+        let mut rng = crate::test::rng(221);
+        let samples: [f64; 3] = d.sample(&mut rng);
+        assert!(samples.into_iter().all(|x: f64| x > 0.0));
+    }
+
+    #[test]
+    fn test_dirichlet_invalid() {
+        use DirichletError::*;
+        assert_eq!(Dirichlet::new([0.5]).unwrap_err(), AlphaTooShort);
+        assert_eq!(DirichletDyn::<f64>::new(&[]).unwrap_err(), AlphaTooShort);
+        assert_eq!(Dirichlet::new([0.1, 0.0, 0.3]).unwrap_err(), AlphaTooSmall);
+        assert_eq!(Dirichlet::new([0.1, -1.5, 0.3]).unwrap_err(), AlphaTooSmall);
+        assert_eq!(
+            Dirichlet::new([0.5, f64::NAN, 0.25]).unwrap_err(),
+            AlphaTooSmall
+        );
+        assert_eq!(
+            Dirichlet::new([0.5, 1.5e-321, 0.25]).unwrap_err(),
+            AlphaSubnormal
+        );
+        assert_eq!(
+            Dirichlet::new([0.5, f64::INFINITY, 0.25]).unwrap_err(),
+            AlphaInfinite
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_dirichlet_serde() {
+        // Gamma and Beta representations
+        for alpha in [[1.0, 2.0, 3.0], [0.05, 0.05, 0.05]] {
+            let d = Dirichlet::new(alpha).unwrap();
+            let mut bytes = bincode::serialize(&d).unwrap();
+            let de: Dirichlet<f64, 3> = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(de, d);
+            let de: DirichletDyn<f64> = bincode::deserialize(&bytes).unwrap();
+            assert_eq!(de, d.inner);
+            // Wrong dimension
+            assert!(bincode::deserialize::<Dirichlet<f64, 4>>(&bytes).is_err());
+
+            // `len` (serialized first) does not match the samplers
+            for len in [0u64, 2, 4] {
+                bytes[..8].copy_from_slice(&len.to_le_bytes());
+                assert!(bincode::deserialize::<DirichletDyn<f64>>(&bytes).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn dirichlet_distributions_can_be_compared() {
+        assert_eq!(Dirichlet::new([1.0, 2.0]), Dirichlet::new([1.0, 2.0]));
+        assert_eq!(
+            DirichletDyn::new(&[1.0, 2.0]),
+            DirichletDyn::new(&[1.0, 2.0])
+        );
+    }
+
+    #[test]
+    fn test_dirichlet_const_matches_dyn() {
+        for alpha in [[0.05, 0.1, 0.01], [0.5, 2.0, 3.0]] {
+            let d = Dirichlet::new(alpha).unwrap();
+            let d_dyn = DirichletDyn::new(&alpha).unwrap();
+            let mut rng = crate::test::rng(222);
+            let mut rng_dyn = crate::test::rng(222);
+            for _ in 0..10 {
+                let x: [f64; 3] = d.sample(&mut rng);
+                let y: Vec<f64> = d_dyn.sample(&mut rng_dyn);
+                assert_eq!(&x[..], &y[..]);
+            }
+        }
+    }
+
+    /// Check that the means of the components of n samples from
+    /// the Dirichlet distribution agree with the expected means
+    /// with an absolute tolerance of `tol`.
+    ///
+    /// This is a crude statistical test, but it will catch egregious
+    /// mistakes.  It will also also fail if any samples contain nan.
+    fn check_dirichlet_means<const N: usize>(alpha: [f64; N], n: i32, tol: f64, seed: u64) {
+        let d = Dirichlet::new(alpha).unwrap();
+        let mut rng = crate::test::rng(seed);
+        let mut sums = [0.0; N];
+        for _ in 0..n {
+            let samples: [f64; N] = d.sample(&mut rng);
+            assert_almost_eq!(samples.iter().sum::<f64>(), 1.0, 1e-12);
+            for i in 0..N {
+                sums[i] += samples[i];
+            }
+        }
+        let sample_mean = sums.map(|x| x / n as f64);
+        let alpha_sum: f64 = alpha.iter().sum();
+        let expected_mean = alpha.map(|x| x / alpha_sum);
+        for i in 0..N {
+            assert_almost_eq!(sample_mean[i], expected_mean[i], tol);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_dirichlet_means() {
+        // Check the means of 20000 samples for several different alphas.
+        let n = 20000;
+        let tol = 2e-2;
+        let seed = 1317624576693539401;
+        check_dirichlet_means([0.5, 0.25], n, tol, seed);
+        check_dirichlet_means([123.0, 75.0], n, tol, seed);
+        check_dirichlet_means([2.0, 2.5, 5.0, 7.0], n, tol, seed);
+        check_dirichlet_means([0.1, 8.0, 1.0, 2.0, 2.0, 0.85, 0.05, 12.5], n, tol, seed);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_dirichlet_means_very_small_alpha() {
+        // With values of alpha that are all 0.001, check that the means of the
+        // components of 10000 samples are within 1% of the expected means.
+        // With the sampling method based on gamma variates, this test would
+        // fail, with about 10% of the samples containing nan.
+        let alpha = [0.001; 3];
+        let n = 10000;
+        let tol = 1e-2;
+        let seed = 1317624576693539401;
+        check_dirichlet_means(alpha, n, tol, seed);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_dirichlet_means_small_alpha() {
+        // With values of alpha that are all less than 0.1, check that the
+        // means of the components of 150000 samples are within 0.1% of the
+        // expected means.
+        let alpha = [0.05, 0.025, 0.075, 0.05];
+        let n = 150000;
+        let tol = 1e-3;
+        let seed = 1317624576693539401;
+        check_dirichlet_means(alpha, n, tol, seed);
+    }
+}
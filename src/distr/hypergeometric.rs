@@ -0,0 +1,610 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The hypergeometric distribution `Hypergeometric(N, K, n)`.
+
+use crate::distr::uniform::Uniform;
+use crate::distr::Distribution;
+use crate::Rng;
+use core::fmt;
+#[allow(unused_imports)]
+use num_traits::Float;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum SamplingMethod {
+    InverseTransform {
+        initial_p: f64,
+        initial_x: u64,
+    },
+    RejectionAcceptance {
+        m: f64,
+        lambda_l: f64,
+        lambda_r: f64,
+        x_l: f64,
+        x_r: f64,
+        p1: f64,
+        p2: f64,
+        p3: f64,
+    },
+}
+
+/// The [hypergeometric distribution](https://en.wikipedia.org/wiki/Hypergeometric_distribution) `Hypergeometric(N, K, n)`.
+///
+/// This is the distribution of successes in samples of size `n` drawn without
+/// replacement from a population of size `N` containing `K` success states.
+///
+/// See the [binomial distribution](crate::distr::Binomial) for the analogous distribution
+/// for sampling with replacement. It is a good approximation when the population
+/// size is much larger than the sample size.
+///
+/// # Density function
+///
+/// `f(k) = binomial(K, k) * binomial(N-K, n-k) / binomial(N, n)`,
+/// where `binomial(a, b) = a! / (b! * (a - b)!)`.
+///
+/// # Plot
+///
+/// The following plot of the hypergeometric distribution illustrates the probability of drawing
+/// `k` successes in `n = 10` draws from a population of `N = 50` items, of which either `K = 12`
+/// or `K = 35` are successes.
+///
+/// ![Hypergeometric distribution](https://raw.githubusercontent.com/rust-random/charts/main/charts/hypergeometric.svg)
+///
+/// # Example
+/// ```
+/// use rand::distr::{Distribution, Hypergeometric};
+///
+/// let hypergeo = Hypergeometric::new(60, 24, 7).unwrap();
+/// let v = hypergeo.sample(&mut rand::rng());
+/// println!("{} is from a hypergeometric distribution", v);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hypergeometric {
+    n1: u64,
+    n2: u64,
+    k: u64,
+    offset_x: i128,
+    sign_x: i128,
+    sampling_method: SamplingMethod,
+}
+
+/// Error type returned from [`Hypergeometric::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HypergeometricError {
+    /// `total_population_size` is too large, causing floating point underflow.
+    PopulationTooLarge,
+    /// `population_with_feature > total_population_size`.
+    ProbabilityTooLarge,
+    /// `sample_size > total_population_size`.
+    SampleSizeTooLarge,
+}
+
+impl fmt::Display for HypergeometricError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            HypergeometricError::PopulationTooLarge => {
+                "total_population_size is too large causing underflow in hypergeometric distribution"
+            }
+            HypergeometricError::ProbabilityTooLarge => {
+                "population_with_feature > total_population_size in hypergeometric distribution"
+            }
+            HypergeometricError::SampleSizeTooLarge => {
+                "sample_size > total_population_size in hypergeometric distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for HypergeometricError {}
+
+// evaluate (a! / (a - h)!) / (b! / (b - h)!) for h <= a <= b
+//
+// This equals the product over i < h of (a - i) / (b - i), and also the
+// product over i < b - a of (b - h - i) / (b - i). The shorter product is
+// evaluated directly if short enough. Otherwise the second product is
+// evaluated in log space, replacing the sum of logs by the integral over
+// [a + 1/2, b + 1/2] (midpoint rule). This is accurate when `a - h` is large,
+// which holds for the long products arising in algorithm HIN.
+fn ratio_of_falling_factorials(a: u64, b: u64, h: u64) -> f64 {
+    const MAX_EXACT_TERMS: u64 = 1 << 12;
+    if h <= MAX_EXACT_TERMS && h <= b - a {
+        return (0..h).fold(1.0, |p, i| p * ((a - i) as f64 / (b - i) as f64));
+    } else if b - a <= MAX_EXACT_TERMS {
+        let c = b - h;
+        return (0..b - a).fold(1.0, |p, i| p * ((c - i) as f64 / (b - i) as f64));
+    }
+
+    let d = (b - a) as f64;
+    let h = h as f64;
+    let u = b as f64 + 0.5;
+    let l = a as f64 + 0.5;
+    let l_h = (a as f64 - h) + 0.5;
+    // U ln(1 - h/U) - L ln(1 - h/L) - h ln((U - h) / (L - h)), rearranged to
+    // avoid cancellation of large terms
+    let ln_p =
+        d * Float::ln_1p(-h / u) + l * Float::ln_1p(h * d / (u * l_h)) - h * Float::ln_1p(d / l_h);
+    Float::exp(ln_p)
+}
+
+#[allow(clippy::excessive_precision)]
+const LOGSQRT2PI: f64 = 0.91893853320467274178; // log(sqrt(2*pi))
+
+fn ln_of_factorial(v: f64) -> f64 {
+    // the paper calls for ln(v!), but also wants to pass in fractions,
+    // so we need to use Stirling's approximation to fill in the gaps:
+
+    // shift v by 3, because Stirling is bad for small values
+    let v_3 = v + 3.0;
+    let ln_fac = (v_3 + 0.5) * Float::ln(v_3) - v_3 + LOGSQRT2PI + 1.0 / (12.0 * v_3);
+    // make the correction for the shift
+    ln_fac - Float::ln((v + 3.0) * (v + 2.0) * (v + 1.0))
+}
+
+// evaluate ln((v + h)!) - ln(v!) without cancellation for large `v`
+fn ln_of_factorial_diff(v: f64, h: f64) -> f64 {
+    let w = v + h;
+    if f64::min(v, w) < 1e8 {
+        return ln_of_factorial(w) - ln_of_factorial(v);
+    }
+    // Stirling's approximation, with (v + 1/2) ln(w / v) computed directly
+    (v + 0.5) * Float::ln_1p(h / v) + h * (Float::ln(w) - 1.0) + (1.0 / w - 1.0 / v) / 12.0
+}
+
+// evaluate ln(f(y) / f(m)), where `f` is the hypergeometric density
+fn ln_of_density_ratio(n1: u64, n2: u64, k: u64, m: f64, y: f64) -> f64 {
+    let h = m - y;
+    ln_of_factorial_diff(y, h)
+        - ln_of_factorial_diff(n1 as f64 - m, h)
+        - ln_of_factorial_diff(k as f64 - m, h)
+        + ln_of_factorial_diff((n2 - k) as f64 + y, h)
+}
+
+impl Hypergeometric {
+    /// Constructs a new `Hypergeometric` with the shape parameters
+    /// `N = total_population_size`,
+    /// `K = population_with_feature`,
+    /// `n = sample_size`.
+    #[allow(clippy::many_single_char_names)] // Same names as in the reference.
+    pub fn new(
+        total_population_size: u64,
+        population_with_feature: u64,
+        sample_size: u64,
+    ) -> Result<Self, HypergeometricError> {
+        if population_with_feature > total_population_size {
+            return Err(HypergeometricError::ProbabilityTooLarge);
+        }
+
+        if sample_size > total_population_size {
+            return Err(HypergeometricError::SampleSizeTooLarge);
+        }
+
+        // set-up constants as function of original parameters
+        let n = total_population_size;
+        let (mut sign_x, mut offset_x) = (1, 0);
+        let (n1, n2) = {
+            // switch around success and failure states if necessary to ensure n1 <= n2
+            let population_without_feature = n - population_with_feature;
+            if population_with_feature > population_without_feature {
+                sign_x = -1;
+                offset_x = sample_size as i128;
+                (population_without_feature, population_with_feature)
+            } else {
+                (population_with_feature, population_without_feature)
+            }
+        };
+        // when sampling more than half the total population, take the smaller
+        // group as sampled instead (we can then return n1-x instead).
+        //
+        // Note: the boundary condition given in the paper is `sample_size < n / 2`;
+        // we're deviating here, because when n is even, it doesn't matter whether
+        // we switch here or not, but when n is odd `n/2 < n - n/2`, so switching
+        // when `k == n/2`, we'd actually be taking the _larger_ group as sampled.
+        let k = if sample_size <= n / 2 {
+            sample_size
+        } else {
+            offset_x += n1 as i128 * sign_x;
+            sign_x *= -1;
+            n - sample_size
+        };
+
+        // Algorithm H2PE has bounded runtime only if `M - max(0, k-n2) >= 10`,
+        // where `M` is the mode of the distribution.
+        // Use algorithm HIN for the remaining parameter space.
+        //
+        // Voratas Kachitvichyanukul and Bruce W. Schmeiser. 1985. Computer
+        // generation of hypergeometric random variates.
+        // J. Statist. Comput. Simul. Vol.22 (August 1985), 127-145
+        // https://www.researchgate.net/publication/233212638
+        const HIN_THRESHOLD: f64 = 10.0;
+        let m = Float::floor((k as f64 + 1.0) * (n1 as f64 + 1.0) / (n as f64 + 2.0));
+        let sampling_method = if m - f64::max(0.0, k as f64 - n2 as f64) < HIN_THRESHOLD {
+            let (initial_p, initial_x) = if k < n2 {
+                // f(0) = (n2! (n - k)!) / (n! (n2 - k)!)
+                (ratio_of_falling_factorials(n2, n, k), 0)
+            } else {
+                // f(k - n2) = (n1! k!) / (n! (k - n2)!)
+                (ratio_of_falling_factorials(k, n, n2), k - n2)
+            };
+
+            if initial_p <= 0.0 || !initial_p.is_finite() {
+                return Err(HypergeometricError::PopulationTooLarge);
+            }
+
+            SamplingMethod::InverseTransform {
+                initial_p,
+                initial_x,
+            }
+        } else {
+            let numerator = (n - k) as f64 * k as f64 * n1 as f64 * n2 as f64;
+            let denominator = (n - 1) as f64 * n as f64 * n as f64;
+            let d = 1.5 * Float::sqrt(numerator / denominator) + 0.5;
+
+            let x_l = m - d + 0.5;
+            let x_r = m + d + 0.5;
+
+            let k_l = Float::exp(ln_of_density_ratio(n1, n2, k, m, x_l));
+            let k_r = Float::exp(ln_of_density_ratio(n1, n2, k, m, x_r - 1.0));
+
+            let numerator = x_l * ((n2 - k) as f64 + x_l);
+            let denominator = (n1 as f64 - x_l + 1.0) * (k as f64 - x_l + 1.0);
+            let lambda_l = -Float::ln(numerator / denominator);
+
+            let numerator = (n1 as f64 - x_r + 1.0) * (k as f64 - x_r + 1.0);
+            let denominator = x_r * ((n2 - k) as f64 + x_r);
+            let lambda_r = -Float::ln(numerator / denominator);
+
+            // the paper literally gives `p2 + kL/lambdaL` where it (probably)
+            // should have been `p2 <- p1 + kL/lambdaL`; another print error?!
+            let p1 = 2.0 * d;
+            let p2 = p1 + k_l / lambda_l;
+            let p3 = p2 + k_r / lambda_r;
+
+            SamplingMethod::RejectionAcceptance {
+                m,
+                lambda_l,
+                lambda_r,
+                x_l,
+                x_r,
+                p1,
+                p2,
+                p3,
+            }
+        };
+
+        Ok(Hypergeometric {
+            n1,
+            n2,
+            k,
+            offset_x,
+            sign_x,
+            sampling_method,
+        })
+    }
+}
+
+impl Distribution<u64> for Hypergeometric {
+    #[allow(clippy::many_single_char_names)] // Same names as in the reference.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        use SamplingMethod::*;
+
+        let Hypergeometric {
+            n1,
+            n2,
+            k,
+            sign_x,
+            offset_x,
+            sampling_method,
+        } = *self;
+        let x = match sampling_method {
+            InverseTransform {
+                initial_p: mut p,
+                initial_x: mut x,
+            } => {
+                let mut u = rng.random::<f64>();
+
+                // the paper erroneously uses `until n < p`, which doesn't make any sense
+                while u > p && x < k {
+                    u -= p;
+                    p *= (n1 - x) as f64 * (k - x) as f64;
+                    p /= (x + 1) as f64 * ((n2 - k + x) as f64 + 1.0);
+                    x += 1;
+                }
+                x
+            }
+            RejectionAcceptance {
+                m,
+                lambda_l,
+                lambda_r,
+                x_l,
+                x_r,
+                p1,
+                p2,
+                p3,
+            } => {
+                let distr_region_select = Uniform::new(0.0, p3).unwrap();
+                loop {
+                    let (y, v) = loop {
+                        let u = distr_region_select.sample(rng);
+                        let v = rng.random::<f64>(); // for the accept/reject decision
+
+                        if u <= p1 {
+                            // Region 1, central bell
+                            let y = Float::floor(x_l + u);
+                            break (y, v);
+                        } else if u <= p2 {
+                            // Region 2, left exponential tail
+                            let y = Float::floor(x_l + Float::ln(v) / lambda_l);
+                            if y >= k.saturating_sub(n2) as f64 {
+                                let v = v * (u - p1) * lambda_l;
+                                break (y, v);
+                            }
+                        } else {
+                            // Region 3, right exponential tail
+                            let y = Float::floor(x_r - Float::ln(v) / lambda_r);
+                            if y as u64 <= u64::min(n1, k) {
+                                let v = v * (u - p2) * lambda_r;
+                                break (y, v);
+                            }
+                        }
+                    };
+
+                    // Step 4: Acceptance/Rejection Comparison
+                    if m < 100.0 || y <= 50.0 {
+                        // Step 4.1: evaluate f(y) via recursive relationship
+                        let mut f = 1.0;
+                        if m < y {
+                            for i in (m as u64 + 1)..=(y as u64) {
+                                f *= (n1 - i + 1) as f64 * (k - i + 1) as f64;
+                                f /= i as f64 * (n2 - k + i) as f64;
+                            }
+                        } else {
+                            for i in (y as u64 + 1)..=(m as u64) {
+                                f *= i as f64 * (n2 - k + i) as f64;
+                                f /= (n1 - i + 1) as f64 * (k - i + 1) as f64;
+                            }
+                        }
+
+                        if v <= f {
+                            break y as u64;
+                        }
+                    } else {
+                        // Step 4.2: Squeezing
+                        let y1 = y + 1.0;
+                        let ym = y - m;
+                        let yn = n1 as f64 - y + 1.0;
+                        let yk = k as f64 - y + 1.0;
+                        let nk = n2 as f64 - k as f64 + y1;
+                        let r = -ym / y1;
+                        let s = ym / yn;
+                        let t = ym / yk;
+                        let e = -ym / nk;
+                        let g = yn * yk / (y1 * nk) - 1.0;
+                        let dg = if g < 0.0 { 1.0 + g } else { 1.0 };
+                        let gu = g * (1.0 + g * (-0.5 + g / 3.0));
+                        let gl = gu - Float::powi(g, 4) / (4.0 * dg);
+                        let xm = m + 0.5;
+                        let xn = n1 as f64 - m + 0.5;
+                        let xk = k as f64 - m + 0.5;
+                        let nm = n2 as f64 - k as f64 + xm;
+                        let ub = xm * r * (1.0 + r * (-0.5 + r / 3.0))
+                            + xn * s * (1.0 + s * (-0.5 + s / 3.0))
+                            + xk * t * (1.0 + t * (-0.5 + t / 3.0))
+                            + nm * e * (1.0 + e * (-0.5 + e / 3.0))
+                            + y * gu
+                            - m * gl
+                            + 0.0034;
+                        let av = Float::ln(v);
+                        if av > ub {
+                            continue;
+                        }
+                        let dr = if r < 0.0 {
+                            xm * Float::powi(r, 4) / (1.0 + r)
+                        } else {
+                            xm * Float::powi(r, 4)
+                        };
+                        let ds = if s < 0.0 {
+                            xn * Float::powi(s, 4) / (1.0 + s)
+                        } else {
+                            xn * Float::powi(s, 4)
+                        };
+                        let dt = if t < 0.0 {
+                            xk * Float::powi(t, 4) / (1.0 + t)
+                        } else {
+                            xk * Float::powi(t, 4)
+                        };
+                        let de = if e < 0.0 {
+                            nm * Float::powi(e, 4) / (1.0 + e)
+                        } else {
+                            nm * Float::powi(e, 4)
+                        };
+
+                        if av < ub - 0.25 * (dr + ds + dt + de) + (y + m) * (gl - gu) - 0.0078 {
+                            break y as u64;
+                        }
+
+                        // Step 4.3: Final Acceptance/Rejection Test
+                        let av_critical = ln_of_density_ratio(n1, n2, k, m, y);
+                        if Float::ln(v) <= av_critical {
+                            break y as u64;
+                        }
+                    }
+                }
+            }
+        };
+
+        (offset_x + sign_x * x as i128) as u64
+    }
+}
+
+#[cfg(test)]
+mod test {
+
+    use super::*;
+
+    #[test]
+    fn test_hypergeometric_invalid_params() {
+        assert!(Hypergeometric::new(100, 101, 5).is_err());
+        assert!(Hypergeometric::new(100, 10, 101).is_err());
+        assert!(Hypergeometric::new(100, 101, 101).is_err());
+        assert!(Hypergeometric::new(100, 10, 5).is_ok());
+    }
+
+    fn test_hypergeometric_mean_and_variance<R: Rng>(n: u64, k: u64, s: u64, rng: &mut R) {
+        let distr = Hypergeometric::new(n, k, s).unwrap();
+
+        let expected_mean = s as f64 * k as f64 / n as f64;
+        let expected_variance = {
+            let numerator = (s * k * (n - k) * (n - s)) as f64;
+            let denominator = (n * n * (n - 1)) as f64;
+            numerator / denominator
+        };
+
+        let mut results = [0.0; 1000];
+        for i in results.iter_mut() {
+            *i = distr.sample(rng) as f64;
+        }
+
+        let mean = results.iter().sum::<f64>() / results.len() as f64;
+        assert!((mean - expected_mean).abs() < expected_mean / 50.0);
+
+        let variance =
+            results.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / results.len() as f64;
+        assert!((variance - expected_variance).abs() < expected_variance / 10.0);
+    }
+
+    #[test]
+    fn test_hypergeometric() {
+        let mut rng = crate::test::rng(737);
+
+        // exercise algorithm HIN:
+        test_hypergeometric_mean_and_variance(500, 400, 30, &mut rng);
+        test_hypergeometric_mean_and_variance(250, 200, 230, &mut rng);
+        test_hypergeometric_mean_and_variance(100, 20, 6, &mut rng);
+        test_hypergeometric_mean_and_variance(50, 10, 47, &mut rng);
+
+        // exercise algorithm H2PE
+        test_hypergeometric_mean_and_variance(5000, 2500, 500, &mut rng);
+        test_hypergeometric_mean_and_variance(10100, 10000, 1000, &mut rng);
+        test_hypergeometric_mean_and_variance(100100, 100, 10000, &mut rng);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_hypergeometric_gof() {
+        fn check(n: u64, k: u64, s: u64, seed: u64) {
+            // f(x + 1) = f(x) (K - x) (n - x) / ((x + 1) (N - K - n + x + 1))
+            let (n, k, s) = (n as f64, k as f64, s as f64);
+            let x_min = (s + k - n).max(0.0);
+            let ln_f = |x: f64| {
+                ln_of_factorial(k) - ln_of_factorial(x) - ln_of_factorial(k - x)
+                    + ln_of_factorial(n - k)
+                    - ln_of_factorial(s - x)
+                    - ln_of_factorial(n - k - s + x)
+                    - ln_of_factorial(n)
+                    + ln_of_factorial(s)
+                    + ln_of_factorial(n - s)
+            };
+            let mut pmf = [0.0; 1025];
+            let mut f = ln_f(x_min).exp();
+            let mut x = x_min;
+            while x <= s.min(k) {
+                pmf[x as usize] = f;
+                f *= (k - x) * (s - x) / ((x + 1.0) * (n - k - s + x + 1.0));
+                x += 1.0;
+            }
+            let distr = Hypergeometric::new(n as u64, k as u64, s as u64).unwrap();
+            crate::test::check_discrete_gof(distr, seed, &pmf[..=s as usize]);
+        }
+
+        // HIN
+        check(50, 20, 10, 527);
+        check(100, 20, 94, 528);
+        // H2PE
+        check(2000, 800, 500, 529);
+        check(10100, 10000, 1000, 530);
+    }
+
+    #[test]
+    fn test_hypergeometric_extreme() {
+        let mut rng = crate::test::rng(531);
+
+        let distr = Hypergeometric::new(u64::MAX, u64::MAX - 1, 10).unwrap();
+        for _ in 0..100 {
+            assert!(distr.sample(&mut rng) >= 9);
+        }
+
+        let distr = Hypergeometric::new(u64::MAX, 1 << 62, 1 << 62).unwrap();
+        let mean = (1u64 << 60) as f64;
+        for _ in 0..100 {
+            let x = distr.sample(&mut rng) as f64;
+            assert!((x - mean).abs() < 1e11);
+        }
+
+        // Both the success and failure states and the sampled groups are swapped
+        let k = u64::MAX - (1 << 62);
+        let distr = Hypergeometric::new(u64::MAX, k, k).unwrap();
+        let mean = k as f64 * (k as f64 / u64::MAX as f64);
+        for _ in 0..100 {
+            let x = distr.sample(&mut rng) as f64;
+            assert!((x - mean).abs() < 1e11);
+        }
+
+        let distr = Hypergeometric::new(u64::MAX, u64::MAX, u64::MAX).unwrap();
+        assert_eq!(distr.sample(&mut rng), u64::MAX);
+
+        // HIN with a huge sample: set-up must not iterate over the sample
+        let distr = Hypergeometric::new(u64::MAX, 5, 1 << 40).unwrap();
+        for _ in 0..100 {
+            assert!(distr.sample(&mut rng) <= 5);
+        }
+        let distr = Hypergeometric::new(1 << 42, 1 << 21, 1 << 21).unwrap();
+        let total: u64 = (0..10_000).map(|_| distr.sample(&mut rng)).sum();
+        assert!((total as f64 / 10_000.0 - 1.0).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_ratio_of_falling_factorials() {
+        // Compare against the product of (1 - h / (b - i)) for i < b - a,
+        // covering both direct products and the log-space approximation
+        for &(a, b, h) in &[
+            (99_995, 100_000, 5_000),
+            (99_995, 100_000, 50_000),
+            (99_900, 100_000, 1_000),
+            (90_000, 100_000, 1_000),
+            (390_000, 400_000, 20_000),
+        ] {
+            let exact = (0..b - a)
+                .map(|i| (-(h as f64) / (b - i) as f64).ln_1p())
+                .sum::<f64>()
+                .exp();
+            let approx = ratio_of_falling_factorials(a, b, h);
+            assert!((approx / exact - 1.0).abs() < 1e-9, "{} {}", approx, exact);
+        }
+    }
+
+    #[test]
+    fn hypergeometric_distributions_can_be_compared() {
+        assert_eq!(Hypergeometric::new(1, 2, 3), Hypergeometric::new(1, 2, 3));
+    }
+
+    #[test]
+    fn stirling() {
+        // ln(0.5!) = ln(Γ(1.5)) = ln(sqrt(π) / 2)
+        let expected = (core::f64::consts::PI.sqrt() / 2.0).ln();
+        assert!((expected - ln_of_factorial(0.5)).abs() < 1e-4);
+
+        let mut expected = 0.0;
+        for v in 1..=8 {
+            expected += (v as f64).ln();
+            assert!((expected - ln_of_factorial(v as f64)).abs() < 1e-4);
+        }
+    }
+}
@@ -0,0 +1,144 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The Lévy distribution `Levy(μ, c)`.
+
+use crate::distr::{Distribution, StandardNormal};
+use crate::Rng;
+use core::fmt;
+use num_traits::Float;
+
+/// The [Lévy distribution](https://en.wikipedia.org/wiki/L%C3%A9vy_distribution) `Levy(μ, c)`.
+///
+/// The Lévy distribution is a continuous probability distribution
+/// with location parameter `μ` (`mu`) and scale parameter `c`, supported on
+/// `x > μ`. It is a stable distribution with a very heavy right tail: its
+/// mean and variance are infinite.
+///
+/// It is the distribution of the first time a standard Brownian motion hits
+/// a level `sqrt(c)`, and of `μ + c / Z²` where `Z` is
+/// [`StandardNormal`].
+///
+/// # Density function
+///
+/// `f(x) = sqrt(c / 2π) exp(-c / (2 (x - μ))) / (x - μ)^(3/2)` for `x > μ`.
+///
+/// # Example
+/// ```
+/// use rand::prelude::*;
+/// use rand::distr::Levy;
+///
+/// let val: f64 = rand::rng().sample(Levy::new(0.0, 1.0).unwrap());
+/// println!("{}", val);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Levy<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    location: F,
+    scale: F,
+}
+
+/// Error type returned from [`Levy::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LevyError {
+    /// location is infinite or NaN
+    LocationNotFinite,
+    /// scale is not finite positive number
+    ScaleNotPositive,
+}
+
+impl fmt::Display for LevyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LevyError::ScaleNotPositive => "scale is not positive and finite in Lévy distribution",
+            LevyError::LocationNotFinite => "location is not finite in Lévy distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LevyError {}
+
+impl<F> Levy<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    /// Construct a new `Levy` distribution with given `location` and `scale`.
+    pub fn new(location: F, scale: F) -> Result<Levy<F>, LevyError> {
+        if !(scale > F::zero()) || scale.is_infinite() {
+            return Err(LevyError::ScaleNotPositive);
+        }
+        if !location.is_finite() {
+            return Err(LevyError::LocationNotFinite);
+        }
+        Ok(Levy { location, scale })
+    }
+}
+
+impl<F> Distribution<F> for Levy<F>
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        let z: F = rng.sample(StandardNormal);
+        self.location + self.scale / (z * z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(Levy::new(0.0, 0.0), Err(LevyError::ScaleNotPositive));
+        assert_eq!(Levy::new(0.0, -1.0), Err(LevyError::ScaleNotPositive));
+        assert_eq!(Levy::new(0.0, f64::NAN), Err(LevyError::ScaleNotPositive));
+        assert_eq!(
+            Levy::new(0.0, f64::INFINITY),
+            Err(LevyError::ScaleNotPositive)
+        );
+        assert_eq!(Levy::new(f64::NAN, 1.0), Err(LevyError::LocationNotFinite));
+        assert_eq!(
+            Levy::new(f64::INFINITY, 1.0),
+            Err(LevyError::LocationNotFinite)
+        );
+    }
+
+    #[test]
+    fn test_support() {
+        let distr = Levy::new(-2.0, 0.5).unwrap();
+        let mut rng = crate::test::rng(251);
+        for _ in 0..1000 {
+            let x: f64 = distr.sample(&mut rng);
+            assert!(x > -2.0);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_cdf() {
+        use crate::test::erfc;
+
+        let (location, scale) = (1.0, 2.5);
+        let cdf = |x: f64| erfc((scale / (2.0 * (x - location))).sqrt());
+        let xs = [1.2, 1.5, 2.0, 4.0, 10.0, 100.0, 1e4, 1e6];
+        crate::test::check_cdf(Levy::new(location, scale).unwrap(), 252, cdf, &xs);
+    }
+
+    #[test]
+    fn levy_distributions_can_be_compared() {
+        assert_eq!(Levy::new(1.0, 2.0), Levy::new(1.0, 2.0));
+    }
+}
@@ -0,0 +1,137 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The logistic distribution `Logistic(μ, s)`.
+
+use crate::distr::{Distribution, Open01};
+use crate::Rng;
+use core::fmt;
+use num_traits::Float;
+
+/// The [logistic distribution](https://en.wikipedia.org/wiki/Logistic_distribution) `Logistic(μ, s)`.
+///
+/// The logistic distribution is a continuous probability distribution
+/// with location parameter `μ` (`mu`) and scale parameter `s`. Its cumulative
+/// distribution function is the logistic function, and it resembles the
+/// normal distribution with heavier (exponential) tails.
+///
+/// # Density function
+///
+/// `f(x) = exp(-z) / (s (1 + exp(-z))²)`, where `z = (x - μ) / s`.
+///
+/// # Example
+/// ```
+/// use rand::prelude::*;
+/// use rand::distr::Logistic;
+///
+/// let val: f64 = rand::rng().sample(Logistic::new(0.0, 1.0).unwrap());
+/// println!("{}", val);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Logistic<F>
+where
+    F: Float,
+    Open01: Distribution<F>,
+{
+    location: F,
+    scale: F,
+}
+
+/// Error type returned from [`Logistic::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LogisticError {
+    /// location is infinite or NaN
+    LocationNotFinite,
+    /// scale is not finite positive number
+    ScaleNotPositive,
+}
+
+impl fmt::Display for LogisticError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LogisticError::ScaleNotPositive => {
+                "scale is not positive and finite in logistic distribution"
+            }
+            LogisticError::LocationNotFinite => "location is not finite in logistic distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for LogisticError {}
+
+impl<F> Logistic<F>
+where
+    F: Float,
+    Open01: Distribution<F>,
+{
+    /// Construct a new `Logistic` distribution with given `location` and `scale`.
+    pub fn new(location: F, scale: F) -> Result<Logistic<F>, LogisticError> {
+        if !(scale > F::zero()) || scale.is_infinite() {
+            return Err(LogisticError::ScaleNotPositive);
+        }
+        if !location.is_finite() {
+            return Err(LogisticError::LocationNotFinite);
+        }
+        Ok(Logistic { location, scale })
+    }
+}
+
+impl<F> Distribution<F> for Logistic<F>
+where
+    F: Float,
+    Open01: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> F {
+        // Inverse CDF: ln(u / (1 - u)), accurate for u close to 1
+        let u: F = rng.sample(Open01);
+        self.location + self.scale * (u.ln() - (-u).ln_1p())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid() {
+        use LogisticError::*;
+        assert_eq!(Logistic::new(0.0, 0.0), Err(ScaleNotPositive));
+        assert_eq!(Logistic::new(0.0, -1.0), Err(ScaleNotPositive));
+        assert_eq!(Logistic::new(0.0, f64::NAN), Err(ScaleNotPositive));
+        assert_eq!(Logistic::new(0.0, f64::INFINITY), Err(ScaleNotPositive));
+        assert_eq!(Logistic::new(f64::NAN, 1.0), Err(LocationNotFinite));
+        assert_eq!(
+            Logistic::new(f64::NEG_INFINITY, 1.0),
+            Err(LocationNotFinite)
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_cdf() {
+        let (location, scale) = (-1.0, 0.5);
+        let cdf = |x: f64| 1.0 / (1.0 + (-(x - location) / scale).exp());
+        let xs = [-6.0, -4.0, -2.0, -1.0, 0.0, 1.0, 2.0, 4.0];
+        crate::test::check_cdf(Logistic::new(location, scale).unwrap(), 261, cdf, &xs);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_moments() {
+        // Variance is s² π² / 3
+        let variance = 2.0 * 2.0 * core::f64::consts::PI.powi(2) / 3.0;
+        crate::test::check_moments(Logistic::new(3.0, 2.0).unwrap(), 262, 3.0, variance, 0.02);
+    }
+
+    #[test]
+    fn logistic_distributions_can_be_compared() {
+        assert_eq!(Logistic::new(1.0, 2.0), Logistic::new(1.0, 2.0));
+    }
+}
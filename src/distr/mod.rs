@@ -74,24 +74,113 @@
 //!
 //! For weighted sampling of discrete values see the [`weighted`] module.
 //!
-//! This crate no longer includes other non-uniform distributions; instead
-//! it is recommended that you use either [`rand_distr`] or [`statrs`].
+//! Other non-uniform distributions are also provided (requires `distr_math`,
+//! enabled by default):
+//!
+//! - Normal and related: [`StandardNormal`], [`Normal`], [`LogNormal`]
+//! - Exponential and Gamma family: [`Exp1`], [`Exp`], [`Gamma`], [`Beta`],
+//!   [`ChiSquared`], [`StudentT`], [`FisherF`]
+//! - Discrete counts: [`Poisson`], [`Binomial`], [`Geometric`],
+//!   [`StandardGeometric`], [`NegativeBinomial`], [`Hypergeometric`]
+//! - Multivariate (requires `alloc`): [`Dirichlet`], [`Multinomial`],
+//!   [`MultivariateNormal`] and their run-time dimension variants
+//! - Heavy-tailed and extreme-value: [`Pareto`], [`Weibull`], [`Gumbel`],
+//!   [`Frechet`], [`Cauchy`], [`Levy`], [`Logistic`], [`Zipf`], [`Zeta`]
+//! - Geometric: [`UnitSphere`], [`UnitBall`], [`UnitCircle`], [`UnitDisc`],
+//!   [`UnitSimplex`], [`UniformTriangle`] and [`UniformPolygon`] (requires
+//!   `alloc`)
+//!
+//! For other non-uniform distributions it is recommended that you use either
+//! [`rand_distr`] or [`statrs`].
+//!
+//! These distributions use floating-point math functions (such as `ln` and
+//! `exp`) via the [`num_traits::Float`] trait. By default, these are
+//! implemented by [`libm`], giving results which are the same on all
+//! platforms. The `std_math` feature uses the math functions of `std`
+//! instead, which may be faster. Note that `num-traits` selects the functions
+//! of `std` whenever its own `std` feature is enabled, which any crate in the
+//! dependency graph may do; results are only portable if it is not.
 //!
 //!
 //! [probability distribution]: https://en.wikipedia.org/wiki/Probability_distribution
 //! [`rand_distr`]: https://crates.io/crates/rand_distr
 //! [`statrs`]: https://crates.io/crates/statrs
+//! [`libm`]: https://crates.io/crates/libm
 
 //! [`random`]: crate::random
 //! [`rand_distr`]: https://crates.io/crates/rand_distr
 //! [`statrs`]: https://crates.io/crates/statrs
 
 mod bernoulli;
+#[cfg(feature = "distr_math")]
+mod beta;
+#[cfg(feature = "distr_math")]
+mod binomial;
+#[cfg(feature = "distr_math")]
+mod cauchy;
+#[cfg(feature = "distr_math")]
+mod chi_squared;
+#[cfg(all(feature = "alloc", feature = "distr_math"))]
+mod dirichlet;
 mod distribution;
+#[cfg(feature = "distr_math")]
+mod exponential;
+#[cfg(feature = "distr_math")]
+mod fisher_f;
 mod float;
+#[cfg(feature = "distr_math")]
+mod frechet;
+#[cfg(feature = "distr_math")]
+mod gamma;
+#[cfg(feature = "distr_math")]
+mod geometric;
+#[cfg(feature = "distr_math")]
+mod gumbel;
+#[cfg(feature = "distr_math")]
+mod hypergeometric;
 mod integer;
+#[cfg(feature = "distr_math")]
+mod levy;
+#[cfg(feature = "distr_math")]
+mod logistic;
+#[cfg(all(feature = "alloc", feature = "distr_math"))]
+mod multinomial;
+#[cfg(all(feature = "alloc", feature = "distr_math"))]
+mod multivariate_normal;
+#[cfg(feature = "distr_math")]
+mod negative_binomial;
+#[cfg(feature = "distr_math")]
+mod normal;
 mod other;
-mod utils;
+#[cfg(feature = "distr_math")]
+mod pareto;
+#[cfg(feature = "distr_math")]
+mod poisson;
+#[cfg(all(feature = "alloc", feature = "distr_math"))]
+mod polygon;
+#[cfg(feature = "distr_math")]
+mod student_t;
+#[cfg(feature = "distr_math")]
+mod triangle;
+#[cfg(feature = "distr_math")]
+mod unit_ball;
+#[cfg(feature = "distr_math")]
+mod unit_circle;
+#[cfg(feature = "distr_math")]
+mod unit_disc;
+#[cfg(feature = "distr_math")]
+mod unit_simplex;
+#[cfg(feature = "distr_math")]
+mod unit_sphere;
+pub(crate) mod utils;
+#[cfg(feature = "distr_math")]
+mod weibull;
+#[cfg(feature = "distr_math")]
+mod zeta;
+#[cfg(feature = "distr_math")]
+mod ziggurat_tables;
+#[cfg(feature = "distr_math")]
+mod zipf;
 
 #[doc(hidden)]
 pub mod hidden_export {
@@ -103,13 +192,77 @@ pub mod uniform;
 pub mod weighted;
 
 pub use self::bernoulli::{Bernoulli, BernoulliError};
+#[cfg(feature = "distr_math")]
+pub use self::beta::{Beta, BetaError};
+#[cfg(feature = "distr_math")]
+pub use self::binomial::{Binomial, BinomialError};
+#[cfg(feature = "distr_math")]
+pub use self::cauchy::{Cauchy, CauchyError};
+#[cfg(feature = "distr_math")]
+pub use self::chi_squared::{ChiSquared, ChiSquaredError};
+#[cfg(all(feature = "alloc", feature = "distr_math"))]
+pub use self::dirichlet::{Dirichlet, DirichletDyn, DirichletError};
 #[cfg(feature = "alloc")]
 pub use self::distribution::SampleString;
 pub use self::distribution::{Distribution, Iter, Map};
+#[cfg(feature = "distr_math")]
+pub use self::exponential::{Exp, Exp1, ExpError};
+#[cfg(feature = "distr_math")]
+pub use self::fisher_f::{FisherF, FisherFError};
 pub use self::float::{Open01, OpenClosed01};
+#[cfg(feature = "distr_math")]
+pub use self::frechet::{Frechet, FrechetError};
+#[cfg(feature = "distr_math")]
+pub use self::gamma::{Gamma, GammaError};
+#[cfg(feature = "distr_math")]
+pub use self::geometric::{Geometric, GeometricError, StandardGeometric};
+#[cfg(feature = "distr_math")]
+pub use self::gumbel::{Gumbel, GumbelError};
+#[cfg(feature = "distr_math")]
+pub use self::hypergeometric::{Hypergeometric, HypergeometricError};
+#[cfg(feature = "distr_math")]
+pub use self::levy::{Levy, LevyError};
+#[cfg(feature = "distr_math")]
+pub use self::logistic::{Logistic, LogisticError};
+#[cfg(all(feature = "alloc", feature = "distr_math"))]
+pub use self::multinomial::{Multinomial, MultinomialDyn};
+#[cfg(all(feature = "alloc", feature = "distr_math"))]
+pub use self::multivariate_normal::{
+    MultivariateNormal, MultivariateNormalDyn, MultivariateNormalError,
+};
+#[cfg(feature = "distr_math")]
+pub use self::negative_binomial::{NegativeBinomial, NegativeBinomialError};
+#[cfg(feature = "distr_math")]
+pub use self::normal::{LogNormal, Normal, NormalError, StandardNormal};
 pub use self::other::{Alphabetic, Alphanumeric};
+#[cfg(feature = "distr_math")]
+pub use self::pareto::{Pareto, ParetoError};
+#[cfg(feature = "distr_math")]
+pub use self::poisson::{Poisson, PoissonError};
+#[cfg(all(feature = "alloc", feature = "distr_math"))]
+pub use self::polygon::{PolygonError, UniformPolygon};
+#[cfg(feature = "distr_math")]
+pub use self::student_t::StudentT;
+#[cfg(feature = "distr_math")]
+pub use self::triangle::{TriangleError, UniformTriangle};
 #[doc(inline)]
 pub use self::uniform::Uniform;
+#[cfg(feature = "distr_math")]
+pub use self::unit_ball::UnitBall;
+#[cfg(feature = "distr_math")]
+pub use self::unit_circle::UnitCircle;
+#[cfg(feature = "distr_math")]
+pub use self::unit_disc::UnitDisc;
+#[cfg(feature = "distr_math")]
+pub use self::unit_simplex::UnitSimplex;
+#[cfg(feature = "distr_math")]
+pub use self::unit_sphere::UnitSphere;
+#[cfg(feature = "distr_math")]
+pub use self::weibull::{Weibull, WeibullError};
+#[cfg(feature = "distr_math")]
+pub use self::zeta::{Zeta, ZetaError};
+#[cfg(feature = "distr_math")]
+pub use self::zipf::{Zipf, ZipfError};
 
 #[allow(unused)]
 use crate::Rng;
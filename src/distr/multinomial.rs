@@ -0,0 +1,337 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The multinomial distribution `Multinomial(n, p₁, ..., pₖ)`.
+
+use crate::distr::weighted::Error as WeightError;
+use crate::distr::{Binomial, Distribution};
+use crate::Rng;
+use alloc::{boxed::Box, vec, vec::Vec};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The [multinomial distribution](https://en.wikipedia.org/wiki/Multinomial_distribution) `Multinomial(n, p₁, ..., pₖ)`
+/// with the number of categories `k` chosen at run-time.
+///
+/// This is the distribution of the counts `[x₁, ..., xₖ]` of each category
+/// in `n` independent trials, where each trial selects category `i` with
+/// probability `pᵢ`. Samples always sum to `n`. For `k = 2` this is the
+/// [`Binomial`] distribution.
+///
+/// The probabilities are given as weights, which are normalised to sum to 1.
+///
+/// See [`Multinomial`] for a version where `k` is a compile-time constant.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, MultinomialDyn};
+///
+/// let multinomial = MultinomialDyn::new(100, &[0.2, 0.3, 0.5]).unwrap();
+/// let counts: Vec<u64> = multinomial.sample(&mut rand::rng());
+/// assert_eq!(counts.iter().sum::<u64>(), 100);
+/// ```
+///
+/// # Notes
+///
+/// Each count is sampled from the [`Binomial`] distribution, conditional on
+/// the previous counts. Sampling is thus exact, and takes time proportional
+/// to `k` (at most).
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "MultinomialDynFields"))]
+pub struct MultinomialDyn {
+    n: u64,
+    /// Probability of category `i` given that the category is not below `i`
+    cond: Box<[f64]>,
+}
+
+/// The serialized fields of [`MultinomialDyn`], validated on deserialization.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "MultinomialDyn")]
+struct MultinomialDynFields {
+    n: u64,
+    cond: Box<[f64]>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<MultinomialDynFields> for MultinomialDyn {
+    type Error = WeightError;
+
+    fn try_from(fields: MultinomialDynFields) -> Result<Self, WeightError> {
+        if fields.cond.is_empty() {
+            return Err(WeightError::InvalidInput);
+        }
+        if !fields.cond.iter().all(|p| (0.0..=1.0).contains(p)) {
+            return Err(WeightError::InvalidWeight);
+        }
+        // Some category must take all remaining trials, else samples do not
+        // sum to `n`
+        if !fields.cond.contains(&1.0) {
+            return Err(WeightError::InsufficientNonZero);
+        }
+        Ok(MultinomialDyn {
+            n: fields.n,
+            cond: fields.cond,
+        })
+    }
+}
+
+/// The [multinomial distribution](https://en.wikipedia.org/wiki/Multinomial_distribution) `Multinomial(n, p₁, ..., pₖ)`
+/// with the number of categories `k = K` fixed at compile-time.
+///
+/// This is the same distribution as [`MultinomialDyn`], but samples arrays
+/// `[u64; K]` (as well as `Vec<u64>`).
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, Multinomial};
+///
+/// // Outcomes of 600 throws of a die
+/// let multinomial = Multinomial::new(600, [1.0; 6]).unwrap();
+/// let counts: [u64; 6] = multinomial.sample(&mut rand::rng());
+/// assert_eq!(counts.iter().sum::<u64>(), 600);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "MultinomialFields"))]
+pub struct Multinomial<const K: usize> {
+    inner: MultinomialDyn,
+}
+
+/// The serialized fields of [`Multinomial`], validated on deserialization.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "Multinomial")]
+struct MultinomialFields {
+    inner: MultinomialDyn,
+}
+
+#[cfg(feature = "serde")]
+impl<const K: usize> TryFrom<MultinomialFields> for Multinomial<K> {
+    type Error = WeightError;
+
+    fn try_from(fields: MultinomialFields) -> Result<Self, WeightError> {
+        if fields.inner.cond.len() != K {
+            return Err(WeightError::InvalidInput);
+        }
+        Ok(Multinomial {
+            inner: fields.inner,
+        })
+    }
+}
+
+impl MultinomialDyn {
+    /// Construct a new `MultinomialDyn` with `n` trials, where the
+    /// probability of each category is proportional to its weight.
+    ///
+    /// Error cases:
+    /// -   [`WeightError::InvalidInput`] when `weights` is empty.
+    /// -   [`WeightError::InvalidWeight`] when a weight is negative, infinite
+    ///     or NaN.
+    /// -   [`WeightError::InsufficientNonZero`] when all weights are zero.
+    /// -   [`WeightError::Overflow`] when the sum of weights overflows.
+    pub fn new(n: u64, weights: &[f64]) -> Result<MultinomialDyn, WeightError> {
+        if weights.is_empty() {
+            return Err(WeightError::InvalidInput);
+        }
+        if !weights.iter().all(|&w| w >= 0.0 && w.is_finite()) {
+            return Err(WeightError::InvalidWeight);
+        }
+
+        // cond[i] = w[i] / (w[i] + ... + w[k - 1])
+        let mut cond = vec![0.0; weights.len()].into_boxed_slice();
+        let mut rest = 0.0;
+        for (c, &w) in cond.iter_mut().zip(weights).rev() {
+            rest += w;
+            if rest > 0.0 {
+                *c = (w / rest).min(1.0);
+            }
+        }
+        if rest == 0.0 {
+            return Err(WeightError::InsufficientNonZero);
+        }
+        if !rest.is_finite() {
+            return Err(WeightError::Overflow);
+        }
+
+        Ok(MultinomialDyn { n, cond })
+    }
+
+    /// Sample into `counts`, which must have length `self.cond.len()`
+    fn sample_into<R: Rng + ?Sized>(&self, rng: &mut R, counts: &mut [u64]) {
+        debug_assert_eq!(counts.len(), self.cond.len());
+        let mut remaining = self.n;
+        for (x, &p) in counts.iter_mut().zip(self.cond.iter()) {
+            if remaining == 0 {
+                break;
+            }
+            *x = Binomial::new(remaining, p).unwrap().sample(rng);
+            remaining -= *x;
+        }
+    }
+}
+
+impl Distribution<Vec<u64>> for MultinomialDyn {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<u64> {
+        let mut counts = vec![0; self.cond.len()];
+        self.sample_into(rng, &mut counts);
+        counts
+    }
+}
+
+impl<const K: usize> Multinomial<K> {
+    /// Construct a new `Multinomial` with `n` trials, where the probability
+    /// of each category is proportional to its weight.
+    ///
+    /// Error cases are as for [`MultinomialDyn::new`].
+    #[inline]
+    pub fn new(n: u64, weights: [f64; K]) -> Result<Multinomial<K>, WeightError> {
+        let inner = MultinomialDyn::new(n, &weights)?;
+        Ok(Multinomial { inner })
+    }
+}
+
+impl<const K: usize> Distribution<[u64; K]> for Multinomial<K> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [u64; K] {
+        let mut counts = [0; K];
+        self.inner.sample_into(rng, &mut counts);
+        counts
+    }
+}
+
+impl<const K: usize> Distribution<Vec<u64>> for Multinomial<K> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<u64> {
+        self.inner.sample(rng)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_multinomial_invalid() {
+        assert_eq!(
+            MultinomialDyn::new(10, &[]).unwrap_err(),
+            WeightError::InvalidInput
+        );
+        assert_eq!(
+            Multinomial::new(10, [0.5, -0.1]).unwrap_err(),
+            WeightError::InvalidWeight
+        );
+        assert_eq!(
+            Multinomial::new(10, [0.5, f64::NAN]).unwrap_err(),
+            WeightError::InvalidWeight
+        );
+        assert_eq!(
+            Multinomial::new(10, [0.5, f64::INFINITY]).unwrap_err(),
+            WeightError::InvalidWeight
+        );
+        assert_eq!(
+            Multinomial::new(10, [0.0, 0.0]).unwrap_err(),
+            WeightError::InsufficientNonZero
+        );
+        assert_eq!(
+            Multinomial::new(10, [f64::MAX, f64::MAX]).unwrap_err(),
+            WeightError::Overflow
+        );
+    }
+
+    #[test]
+    fn test_multinomial_sum() {
+        let mut rng = crate::test::rng(231);
+        for n in [0, 1, 17, 1000, 1 << 40, u64::MAX] {
+            let distr = Multinomial::new(n, [0.1, 0.0, 2.0, 3.5, 0.0]).unwrap();
+            for _ in 0..100 {
+                let counts: [u64; 5] = distr.sample(&mut rng);
+                assert_eq!(counts[1], 0);
+                assert_eq!(counts[4], 0);
+                assert_eq!(counts.iter().fold(0u64, |a, &b| a.wrapping_add(b)), n);
+            }
+        }
+
+        let distr = MultinomialDyn::new(u64::MAX, &[1.0]).unwrap();
+        assert_eq!(distr.sample(&mut rng), vec![u64::MAX]);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_multinomial_gof() {
+        // Each count has a binomial marginal distribution
+        let n = 40;
+        let weights = [1.0, 3.0, 0.5, 2.5, 3.0];
+        let total: f64 = weights.iter().sum();
+        for i in 0..weights.len() {
+            let p = weights[i] / total;
+            let mut pmf = [0.0; 41];
+            pmf[0] = (1.0 - p).powi(n as i32);
+            for k in 1..pmf.len() {
+                pmf[k] = pmf[k - 1] * (n - k as u64 + 1) as f64 / k as f64 * p / (1.0 - p);
+            }
+            let distr = Multinomial::new(n, weights)
+                .unwrap()
+                .map(move |x: [u64; 5]| x[i]);
+            crate::test::check_discrete_gof(distr, 232 + i as u64, &pmf);
+        }
+    }
+
+    #[test]
+    fn test_multinomial_const_matches_dyn() {
+        let weights = [0.3, 0.2, 0.5];
+        let distr = Multinomial::new(1000, weights).unwrap();
+        let distr_dyn = MultinomialDyn::new(1000, &weights).unwrap();
+        let mut rng = crate::test::rng(237);
+        let mut rng_dyn = crate::test::rng(237);
+        for _ in 0..10 {
+            let x: [u64; 3] = distr.sample(&mut rng);
+            let y: Vec<u64> = distr_dyn.sample(&mut rng_dyn);
+            assert_eq!(&x[..], &y[..]);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_multinomial_serde() {
+        let distr = Multinomial::new(100, [0.2, 0.3, 0.0, 0.5]).unwrap();
+        let bytes = bincode::serialize(&distr).unwrap();
+        let de: Multinomial<4> = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(de, distr);
+        let de: MultinomialDyn = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(de, distr.inner);
+        // Wrong number of categories
+        assert!(bincode::deserialize::<Multinomial<3>>(&bytes).is_err());
+        assert!(bincode::deserialize::<Multinomial<5>>(&bytes).is_err());
+
+        #[derive(Serialize)]
+        struct Fields {
+            n: u64,
+            cond: Vec<f64>,
+        }
+        for cond in [
+            vec![],
+            vec![0.5, 1.5],
+            vec![-0.5, 1.0],
+            vec![f64::NAN, 1.0],
+            vec![0.5, 0.5],
+        ] {
+            let bytes = bincode::serialize(&Fields { n: 10, cond }).unwrap();
+            assert!(bincode::deserialize::<MultinomialDyn>(&bytes).is_err());
+        }
+    }
+
+    #[test]
+    fn multinomial_distributions_can_be_compared() {
+        assert_eq!(
+            Multinomial::new(10, [1.0, 2.0]),
+            Multinomial::new(10, [1.0, 2.0])
+        );
+    }
+}
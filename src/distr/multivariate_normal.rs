@@ -0,0 +1,402 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The multivariate normal distribution `N(μ, Σ)`.
+
+use crate::distr::{Distribution, StandardNormal};
+use crate::Rng;
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::fmt;
+#[allow(unused_imports)]
+use num_traits::Float;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// The [multivariate normal distribution](https://en.wikipedia.org/wiki/Multivariate_normal_distribution) `N(μ, Σ)`
+/// with dimension `k` chosen at run-time.
+///
+/// This is the distribution of the random vector `μ + L z`, where `μ` is the
+/// mean, `L` is the lower-triangular Cholesky factor of the covariance matrix
+/// `Σ = L Lᵀ` and `z` is a vector of `k` independent [`StandardNormal`]
+/// samples. The covariance matrix must be symmetric and positive definite.
+///
+/// See [`MultivariateNormal`] for a version where `k` is a compile-time
+/// constant.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, MultivariateNormalDyn};
+///
+/// let mean = [1.0, -2.0];
+/// // Row-major covariance matrix
+/// let cov = [4.0, 1.2, 1.2, 1.0];
+/// let mvn = MultivariateNormalDyn::new(&mean, &cov).unwrap();
+/// let v: Vec<f64> = mvn.sample(&mut rand::rng());
+/// println!("{:?} is from a N({:?}, {:?}) distribution", v, mean, cov);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "MultivariateNormalDynFields"))]
+pub struct MultivariateNormalDyn {
+    mean: Box<[f64]>,
+    /// Cholesky factor `L`, packed by rows: `L[i][j]` is at `i (i + 1) / 2 + j`
+    chol: Box<[f64]>,
+}
+
+/// The serialized fields of [`MultivariateNormalDyn`], validated on
+/// deserialization.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "MultivariateNormalDyn")]
+struct MultivariateNormalDynFields {
+    mean: Box<[f64]>,
+    chol: Box<[f64]>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<MultivariateNormalDynFields> for MultivariateNormalDyn {
+    type Error = MultivariateNormalError;
+
+    fn try_from(fields: MultivariateNormalDynFields) -> Result<Self, MultivariateNormalError> {
+        let k = fields.mean.len();
+        let tri = k
+            .checked_add(1)
+            .and_then(|k1| k1.checked_mul(k))
+            .map(|x| x / 2);
+        if tri != Some(fields.chol.len()) {
+            return Err(MultivariateNormalError::DimensionMismatch);
+        }
+        Ok(MultivariateNormalDyn {
+            mean: fields.mean,
+            chol: fields.chol,
+        })
+    }
+}
+
+/// The [multivariate normal distribution](https://en.wikipedia.org/wiki/Multivariate_normal_distribution) `N(μ, Σ)`
+/// with dimension `k = N` fixed at compile-time.
+///
+/// This is the same distribution as [`MultivariateNormalDyn`], but samples
+/// arrays `[f64; N]` (as well as `Vec<f64>`).
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, MultivariateNormal};
+///
+/// let mvn = MultivariateNormal::new([1.0, -2.0], [[4.0, 1.2], [1.2, 1.0]]).unwrap();
+/// let v: [f64; 2] = mvn.sample(&mut rand::rng());
+/// println!("{:?} is from a multivariate normal distribution", v);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "MultivariateNormalFields"))]
+pub struct MultivariateNormal<const N: usize> {
+    inner: MultivariateNormalDyn,
+}
+
+/// The serialized fields of [`MultivariateNormal`], validated on
+/// deserialization.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "MultivariateNormal")]
+struct MultivariateNormalFields {
+    inner: MultivariateNormalDyn,
+}
+
+#[cfg(feature = "serde")]
+impl<const N: usize> TryFrom<MultivariateNormalFields> for MultivariateNormal<N> {
+    type Error = MultivariateNormalError;
+
+    fn try_from(fields: MultivariateNormalFields) -> Result<Self, MultivariateNormalError> {
+        if fields.inner.mean.len() != N {
+            return Err(MultivariateNormalError::DimensionMismatch);
+        }
+        Ok(MultivariateNormal {
+            inner: fields.inner,
+        })
+    }
+}
+
+/// Error type returned from [`MultivariateNormal::new`] and
+/// [`MultivariateNormalDyn::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MultivariateNormalError {
+    /// The covariance matrix does not have `k * k` elements.
+    DimensionMismatch,
+    /// The mean is infinite or NaN.
+    MeanNotFinite,
+    /// The covariance matrix has an infinite or NaN element.
+    CovarianceNotFinite,
+    /// The covariance matrix is not symmetric.
+    CovarianceNotSymmetric,
+    /// The covariance matrix is not positive definite.
+    ///
+    /// This includes (numerically) singular matrices.
+    CovarianceNotPositiveDefinite,
+}
+
+impl fmt::Display for MultivariateNormalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MultivariateNormalError::DimensionMismatch => {
+                "covariance matrix dimension does not match mean in multivariate normal distribution"
+            }
+            MultivariateNormalError::MeanNotFinite => {
+                "mean is not finite in multivariate normal distribution"
+            }
+            MultivariateNormalError::CovarianceNotFinite => {
+                "covariance is not finite in multivariate normal distribution"
+            }
+            MultivariateNormalError::CovarianceNotSymmetric => {
+                "covariance matrix is not symmetric in multivariate normal distribution"
+            }
+            MultivariateNormalError::CovarianceNotPositiveDefinite => {
+                "covariance matrix is not positive definite in multivariate normal distribution"
+            }
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MultivariateNormalError {}
+
+impl MultivariateNormalDyn {
+    /// Construct a new `MultivariateNormalDyn` from the mean `mean` (of length
+    /// `k`) and the covariance matrix `cov` (of `k * k` elements, in
+    /// row-major order).
+    ///
+    /// The covariance matrix must be exactly symmetric, and is rejected unless
+    /// its Cholesky factorization succeeds.
+    pub fn new(
+        mean: &[f64],
+        cov: &[f64],
+    ) -> Result<MultivariateNormalDyn, MultivariateNormalError> {
+        let k = mean.len();
+        if k.checked_mul(k) != Some(cov.len()) {
+            return Err(MultivariateNormalError::DimensionMismatch);
+        }
+        if !mean.iter().all(|x| x.is_finite()) {
+            return Err(MultivariateNormalError::MeanNotFinite);
+        }
+        if !cov.iter().all(|x| x.is_finite()) {
+            return Err(MultivariateNormalError::CovarianceNotFinite);
+        }
+        for i in 0..k {
+            for j in 0..i {
+                if cov[i * k + j] != cov[j * k + i] {
+                    return Err(MultivariateNormalError::CovarianceNotSymmetric);
+                }
+            }
+        }
+
+        // Cholesky–Banachiewicz algorithm
+        let tri = |i: usize| i * (i + 1) / 2;
+        let mut chol = vec![0.0; tri(k)].into_boxed_slice();
+        for i in 0..k {
+            for j in 0..=i {
+                let mut sum = cov[i * k + j];
+                for l in 0..j {
+                    sum -= chol[tri(i) + l] * chol[tri(j) + l];
+                }
+                chol[tri(i) + j] = if i == j {
+                    if !(sum > 0.0) {
+                        return Err(MultivariateNormalError::CovarianceNotPositiveDefinite);
+                    }
+                    Float::sqrt(sum)
+                } else {
+                    sum / chol[tri(j) + j]
+                };
+            }
+        }
+
+        Ok(MultivariateNormalDyn {
+            mean: mean.into(),
+            chol,
+        })
+    }
+
+    /// Sample into `x`, which must have length `self.mean.len()`
+    fn sample_into<R: Rng + ?Sized>(&self, rng: &mut R, x: &mut [f64]) {
+        debug_assert_eq!(x.len(), self.mean.len());
+        for z in x.iter_mut() {
+            *z = rng.sample(StandardNormal);
+        }
+        // Compute x = μ + L z in place; row i only reads z[..=i]
+        for i in (0..x.len()).rev() {
+            let row = &self.chol[i * (i + 1) / 2..][..=i];
+            let lz: f64 = row.iter().zip(x.iter()).map(|(l, z)| l * z).sum();
+            x[i] = self.mean[i] + lz;
+        }
+    }
+}
+
+impl Distribution<Vec<f64>> for MultivariateNormalDyn {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+        let mut x = vec![0.0; self.mean.len()];
+        self.sample_into(rng, &mut x);
+        x
+    }
+}
+
+impl<const N: usize> MultivariateNormal<N> {
+    /// Construct a new `MultivariateNormal` from the mean `mean` and the
+    /// covariance matrix `cov`.
+    ///
+    /// Error cases are as for [`MultivariateNormalDyn::new`].
+    pub fn new(
+        mean: [f64; N],
+        cov: [[f64; N]; N],
+    ) -> Result<MultivariateNormal<N>, MultivariateNormalError> {
+        let cov: Vec<f64> = cov.iter().flatten().copied().collect();
+        let inner = MultivariateNormalDyn::new(&mean, &cov)?;
+        Ok(MultivariateNormal { inner })
+    }
+}
+
+impl<const N: usize> Distribution<[f64; N]> for MultivariateNormal<N> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [f64; N] {
+        let mut x = [0.0; N];
+        self.inner.sample_into(rng, &mut x);
+        x
+    }
+}
+
+impl<const N: usize> Distribution<Vec<f64>> for MultivariateNormal<N> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Vec<f64> {
+        self.inner.sample(rng)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::distr::Normal;
+
+    #[test]
+    fn test_multivariate_normal_invalid() {
+        use MultivariateNormalError::*;
+        let mvn = |mean: &[f64], cov: &[f64]| MultivariateNormalDyn::new(mean, cov).unwrap_err();
+        assert_eq!(mvn(&[0.0, 0.0], &[1.0, 0.0, 0.0]), DimensionMismatch);
+        assert_eq!(mvn(&[], &[1.0]), DimensionMismatch);
+        assert_eq!(mvn(&[f64::NAN], &[1.0]), MeanNotFinite);
+        assert_eq!(mvn(&[0.0], &[f64::INFINITY]), CovarianceNotFinite);
+        assert_eq!(
+            mvn(&[0.0, 0.0], &[1.0, 0.5, 0.4, 1.0]),
+            CovarianceNotSymmetric
+        );
+        assert_eq!(mvn(&[0.0], &[0.0]), CovarianceNotPositiveDefinite);
+        assert_eq!(mvn(&[0.0], &[-1.0]), CovarianceNotPositiveDefinite);
+        // Singular
+        assert_eq!(
+            mvn(&[0.0, 0.0], &[1.0, 1.0, 1.0, 1.0]),
+            CovarianceNotPositiveDefinite
+        );
+        // Indefinite, with positive diagonal
+        assert_eq!(
+            mvn(&[0.0, 0.0], &[1.0, 2.0, 2.0, 1.0]),
+            CovarianceNotPositiveDefinite
+        );
+        assert_eq!(
+            MultivariateNormal::new(
+                [0.0; 3],
+                [[1.0, 0.9, 0.0], [0.9, 1.0, 0.9], [0.0, 0.9, 1.0]]
+            )
+            .unwrap_err(),
+            CovarianceNotPositiveDefinite
+        );
+    }
+
+    #[test]
+    fn test_multivariate_normal_1d() {
+        // Equivalent to `Normal`
+        let mvn = MultivariateNormal::new([3.0], [[4.0]]).unwrap();
+        let normal = Normal::new(3.0, 2.0).unwrap();
+        let mut rng = crate::test::rng(241);
+        let mut rng2 = crate::test::rng(241);
+        for _ in 0..10 {
+            let x: [f64; 1] = mvn.sample(&mut rng);
+            assert_almost_eq!(x[0], normal.sample(&mut rng2), 1e-14);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_multivariate_normal_moments() {
+        const N: usize = 100_000;
+        let mean = [1.0, -2.0, 0.5];
+        let cov = [[4.0, 1.2, -0.8], [1.2, 1.0, 0.3], [-0.8, 0.3, 2.0]];
+        let mvn = MultivariateNormal::new(mean, cov).unwrap();
+        let mut rng = crate::test::rng(242);
+
+        let mut sum = [0.0; 3];
+        let mut sum_sq = [[0.0; 3]; 3];
+        for _ in 0..N {
+            let x: [f64; 3] = mvn.sample(&mut rng);
+            for i in 0..3 {
+                sum[i] += x[i];
+                for j in 0..3 {
+                    sum_sq[i][j] += (x[i] - mean[i]) * (x[j] - mean[j]);
+                }
+            }
+        }
+        for i in 0..3 {
+            assert_almost_eq!(sum[i] / N as f64, mean[i], 0.03);
+            for j in 0..3 {
+                assert_almost_eq!(sum_sq[i][j] / N as f64, cov[i][j], 0.06);
+            }
+        }
+    }
+
+    #[test]
+    fn test_multivariate_normal_const_matches_dyn() {
+        let mvn = MultivariateNormal::new([1.0, 2.0], [[2.0, -0.5], [-0.5, 1.0]]).unwrap();
+        let mvn_dyn = MultivariateNormalDyn::new(&[1.0, 2.0], &[2.0, -0.5, -0.5, 1.0]).unwrap();
+        assert_eq!(mvn.inner, mvn_dyn);
+        let mut rng = crate::test::rng(243);
+        let x: [f64; 2] = mvn.sample(&mut rng);
+        let mut rng = crate::test::rng(243);
+        let y: Vec<f64> = mvn.sample(&mut rng);
+        assert_eq!(&x[..], &y[..]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_multivariate_normal_serde() {
+        let mvn = MultivariateNormal::new([1.0, 2.0], [[2.0, -0.5], [-0.5, 1.0]]).unwrap();
+        let bytes = bincode::serialize(&mvn).unwrap();
+        let de: MultivariateNormal<2> = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(de, mvn);
+        let de: MultivariateNormalDyn = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(de, mvn.inner);
+        // Wrong dimension
+        assert!(bincode::deserialize::<MultivariateNormal<3>>(&bytes).is_err());
+
+        // Length of the Cholesky factor does not match the mean
+        #[derive(Serialize)]
+        struct Fields {
+            mean: Vec<f64>,
+            chol: Vec<f64>,
+        }
+        let bytes = bincode::serialize(&Fields {
+            mean: vec![0.0; 3],
+            chol: vec![1.0; 3],
+        })
+        .unwrap();
+        assert!(bincode::deserialize::<MultivariateNormalDyn>(&bytes).is_err());
+    }
+
+    #[test]
+    fn multivariate_normal_distributions_can_be_compared() {
+        assert_eq!(
+            MultivariateNormal::new([1.0], [[1.0]]),
+            MultivariateNormal::new([1.0], [[1.0]])
+        );
+    }
+}
@@ -0,0 +1,214 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The negative binomial distribution `NegativeBinomial(r, p)`.
+
+use crate::distr::{Distribution, Gamma, Poisson, StandardNormal};
+use crate::Rng;
+use core::fmt;
+#[allow(unused_imports)]
+use num_traits::Float;
+
+/// The [negative binomial distribution](https://en.wikipedia.org/wiki/Negative_binomial_distribution) `NegativeBinomial(r, p)`.
+///
+/// This is the distribution of the number of failures (bounded to
+/// `[0, u64::MAX]`) before the `r`-th success in a series of
+/// [`Bernoulli`](crate::distr::Bernoulli) trials, where the probability of
+/// success on each trial is `p`. The parameter `r` need not be an integer.
+///
+/// For `r = 1` this is the [`Geometric`](crate::distr::Geometric)
+/// distribution.
+///
+/// # Density function
+///
+/// `f(k) = Γ(k + r) / (k! Γ(r)) p^r (1 - p)^k` for `k >= 0`.
+///
+/// # Example
+/// ```
+/// use rand::distr::{Distribution, NegativeBinomial};
+///
+/// let nb = NegativeBinomial::new(3.0, 0.4).unwrap();
+/// let v = nb.sample(&mut rand::rng());
+/// println!("{} failures before the third success", v);
+/// ```
+///
+/// # Notes
+///
+/// Samples are generated as a Gamma-Poisson mixture: first
+/// `λ ~ Gamma(r, (1 - p) / p)`, then `k ~ Poisson(λ)`. Where `λ` exceeds
+/// [`Poisson::MAX_LAMBDA`], the Poisson distribution is approximated by the
+/// Normal distribution `N(λ, λ)` (saturating at `u64::MAX`).
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NegativeBinomial {
+    // `None` when `p = 1`
+    gamma: Option<Gamma<f64>>,
+}
+
+/// Error type returned from [`NegativeBinomial::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NegativeBinomialError {
+    /// `r <= 0` or `nan`.
+    ShapeTooSmall,
+    /// `r = ∞`.
+    ShapeTooLarge,
+    /// `p <= 0` or `nan`, or `p` is so small that `(1 - p) / p` overflows.
+    ProbabilityTooSmall,
+    /// `p > 1`.
+    ProbabilityTooLarge,
+}
+
+impl fmt::Display for NegativeBinomialError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            NegativeBinomialError::ShapeTooSmall => {
+                "r is not positive in negative binomial distribution"
+            }
+            NegativeBinomialError::ShapeTooLarge => {
+                "r is infinite in negative binomial distribution"
+            }
+            NegativeBinomialError::ProbabilityTooSmall => {
+                "p is not positive or too small in negative binomial distribution"
+            }
+            NegativeBinomialError::ProbabilityTooLarge => "p > 1 in negative binomial distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NegativeBinomialError {}
+
+impl NegativeBinomial {
+    /// Construct a new `NegativeBinomial` with the given shape parameters
+    /// `r` (number of successes, `r > 0`) and `p` (probability of success,
+    /// `0 < p <= 1`).
+    pub fn new(r: f64, p: f64) -> Result<NegativeBinomial, NegativeBinomialError> {
+        if !(r > 0.0) {
+            return Err(NegativeBinomialError::ShapeTooSmall);
+        }
+        if !r.is_finite() {
+            return Err(NegativeBinomialError::ShapeTooLarge);
+        }
+        if !(p > 0.0) {
+            return Err(NegativeBinomialError::ProbabilityTooSmall);
+        }
+        if !(p <= 1.0) {
+            return Err(NegativeBinomialError::ProbabilityTooLarge);
+        }
+
+        if p == 1.0 {
+            return Ok(NegativeBinomial { gamma: None });
+        }
+
+        let scale = (1.0 - p) / p;
+        if !scale.is_finite() {
+            return Err(NegativeBinomialError::ProbabilityTooSmall);
+        }
+        let gamma = Gamma::new(r, scale).map_err(|_| NegativeBinomialError::ProbabilityTooSmall)?;
+        Ok(NegativeBinomial { gamma: Some(gamma) })
+    }
+}
+
+impl Distribution<u64> for NegativeBinomial {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        let gamma = match self.gamma {
+            Some(gamma) => gamma,
+            None => return 0,
+        };
+
+        let lambda: f64 = gamma.sample(rng);
+        if !(lambda > 0.0) {
+            0
+        } else if lambda <= Poisson::<f64>::MAX_LAMBDA {
+            Poisson::new(lambda).unwrap().sample(rng)
+        } else if lambda.is_finite() {
+            // For such large lambda, Poisson(λ) is very close to N(λ, λ)
+            let z: f64 = rng.sample(StandardNormal);
+            (lambda + Float::sqrt(lambda) * z) as u64
+        } else {
+            u64::MAX
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_negative_binomial_invalid() {
+        use NegativeBinomialError::*;
+        assert_eq!(NegativeBinomial::new(0.0, 0.5), Err(ShapeTooSmall));
+        assert_eq!(NegativeBinomial::new(f64::NAN, 0.5), Err(ShapeTooSmall));
+        assert_eq!(
+            NegativeBinomial::new(f64::INFINITY, 0.5),
+            Err(ShapeTooLarge)
+        );
+        assert_eq!(NegativeBinomial::new(1.0, 0.0), Err(ProbabilityTooSmall));
+        assert_eq!(
+            NegativeBinomial::new(1.0, f64::NAN),
+            Err(ProbabilityTooSmall)
+        );
+        assert_eq!(NegativeBinomial::new(1.0, 1e-320), Err(ProbabilityTooSmall));
+        assert_eq!(NegativeBinomial::new(1.0, 1.5), Err(ProbabilityTooLarge));
+    }
+
+    #[test]
+    fn test_negative_binomial_extreme() {
+        let mut rng = crate::test::rng(361);
+
+        let nb = NegativeBinomial::new(2.5, 1.0).unwrap();
+        assert_eq!(nb.sample(&mut rng), 0);
+
+        // Mean far beyond `u64::MAX`: samples saturate
+        let nb = NegativeBinomial::new(1e10, 1e-20).unwrap();
+        for _ in 0..100 {
+            assert_eq!(nb.sample(&mut rng), u64::MAX);
+        }
+
+        // Mean around `Poisson::MAX_LAMBDA`
+        let nb = NegativeBinomial::new(1e6, 1e-13).unwrap();
+        for _ in 0..100 {
+            let x = nb.sample(&mut rng) as f64;
+            assert!((x - 1e19).abs() < 1e17);
+        }
+
+        // Tiny shape: mostly zero
+        let nb = NegativeBinomial::new(1e-300, 0.5).unwrap();
+        for _ in 0..100 {
+            assert_eq!(nb.sample(&mut rng), 0);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_negative_binomial_gof() {
+        fn check(r: f64, p: f64, seed: u64) {
+            // f(k + 1) = f(k) (k + r) / (k + 1) (1 - p)
+            let mut pmf = [0.0; 400];
+            pmf[0] = p.powf(r);
+            for k in 1..pmf.len() {
+                pmf[k] = pmf[k - 1] * (k as f64 - 1.0 + r) / k as f64 * (1.0 - p);
+            }
+            crate::test::check_discrete_gof(NegativeBinomial::new(r, p).unwrap(), seed, &pmf);
+        }
+
+        check(1.0, 0.3, 362);
+        check(3.5, 0.4, 363);
+        check(0.5, 0.1, 364);
+        check(40.0, 0.8, 365);
+    }
+
+    #[test]
+    fn negative_binomial_distributions_can_be_compared() {
+        assert_eq!(
+            NegativeBinomial::new(1.0, 0.5),
+            NegativeBinomial::new(1.0, 0.5)
+        );
+    }
+}
@@ -0,0 +1,221 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The `UniformPolygon` distribution.
+
+use crate::distr::{Distribution, StandardUniform, UniformTriangle};
+use crate::Rng;
+use alloc::{boxed::Box, vec::Vec};
+use core::fmt;
+use num_traits::Float;
+
+/// Samples uniformly (by area) from a convex polygon in the plane.
+///
+/// The polygon is given by its vertices in order, either clockwise or
+/// anticlockwise. It is split into a fan of triangles from the first vertex;
+/// each sample picks a triangle with probability proportional to its area
+/// (by binary search over the cumulative areas), then samples a point from
+/// it as [`UniformTriangle`] does.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, UniformPolygon};
+///
+/// let hexagon = UniformPolygon::new(&[
+///     [1.0, 0.0], [0.5, 0.866], [-0.5, 0.866],
+///     [-1.0, 0.0], [-0.5, -0.866], [0.5, -0.866],
+/// ]).unwrap();
+/// let p: [f64; 2] = hexagon.sample(&mut rand::rng());
+/// println!("{:?} is from the hexagon.", p);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct UniformPolygon<F> {
+    triangles: Box<[UniformTriangle<F, 2>]>,
+    /// Cumulative areas (times two) of `triangles`
+    cumulative: Box<[F]>,
+}
+
+/// Error type returned from [`UniformPolygon::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PolygonError {
+    /// Fewer than three vertices were given.
+    TooFewVertices,
+    /// A coordinate of a vertex is infinite or NaN, or the polygon is too
+    /// large to represent its area.
+    NotFinite,
+    /// The polygon is not convex, or its vertices are not given in order.
+    NotConvex,
+    /// The polygon has zero area.
+    ZeroArea,
+}
+
+impl fmt::Display for PolygonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            PolygonError::TooFewVertices => {
+                "fewer than three vertices in uniform polygon distribution"
+            }
+            PolygonError::NotFinite => "vertex is not finite in uniform polygon distribution",
+            PolygonError::NotConvex => "polygon is not convex in uniform polygon distribution",
+            PolygonError::ZeroArea => "polygon has zero area in uniform polygon distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PolygonError {}
+
+/// Twice the signed area of the triangle `a`, `b`, `c`; positive when the
+/// vertices are anticlockwise.
+fn cross<F: Float>(a: [F; 2], b: [F; 2], c: [F; 2]) -> F {
+    (b[0] - a[0]) * (c[1] - a[1]) - (b[1] - a[1]) * (c[0] - a[0])
+}
+
+impl<F: Float> UniformPolygon<F> {
+    /// Construct a new `UniformPolygon` from the vertices of a convex polygon.
+    ///
+    /// Collinear and repeated vertices are allowed. Checking convexity takes
+    /// time quadratic in the number of vertices.
+    pub fn new(vertices: &[[F; 2]]) -> Result<UniformPolygon<F>, PolygonError> {
+        let n = vertices.len();
+        if n < 3 {
+            return Err(PolygonError::TooFewVertices);
+        }
+
+        let v0 = vertices[0];
+        let mut triangles = Vec::with_capacity(n - 2);
+        let mut cumulative = Vec::with_capacity(n - 2);
+        let mut total = F::zero();
+        for w in vertices[1..].windows(2) {
+            let t = UniformTriangle::new([v0, w[0], w[1]]).map_err(|_| PolygonError::NotFinite)?;
+            total = total + cross(v0, w[0], w[1]);
+            triangles.push(t);
+            cumulative.push(total);
+        }
+        if !total.is_finite() {
+            return Err(PolygonError::NotFinite);
+        }
+        if total == F::zero() {
+            return Err(PolygonError::ZeroArea);
+        }
+
+        // Every vertex must lie on the inner side of every edge
+        let sign = total.signum();
+        for i in 0..n {
+            let (a, b) = (vertices[i], vertices[(i + 1) % n]);
+            if vertices.iter().any(|&c| cross(a, b, c) * sign < F::zero()) {
+                return Err(PolygonError::NotConvex);
+            }
+        }
+
+        // With consistent orientation, all fan areas have the same sign
+        if sign < F::zero() {
+            for c in cumulative.iter_mut() {
+                *c = -*c;
+            }
+        }
+
+        Ok(UniformPolygon {
+            triangles: triangles.into_boxed_slice(),
+            cumulative: cumulative.into_boxed_slice(),
+        })
+    }
+}
+
+impl<F> Distribution<[F; 2]> for UniformPolygon<F>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [F; 2] {
+        let total = self.cumulative[self.cumulative.len() - 1];
+        let x = rng.random::<F>() * total;
+        let i = self.cumulative.partition_point(|&c| c <= x);
+        // Rounding may give `x == total`
+        let i = i.min(self.triangles.len() - 1);
+        self.triangles[i].sample(rng)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid() {
+        use PolygonError::*;
+        assert_eq!(UniformPolygon::<f64>::new(&[]), Err(TooFewVertices));
+        assert_eq!(
+            UniformPolygon::new(&[[0.0, 0.0], [1.0, 0.0]]),
+            Err(TooFewVertices)
+        );
+        assert_eq!(
+            UniformPolygon::new(&[[0.0, 0.0], [1.0, f64::NAN], [0.0, 1.0]]),
+            Err(NotFinite)
+        );
+        assert_eq!(
+            UniformPolygon::new(&[[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]]),
+            Err(ZeroArea)
+        );
+        // A dart (non-convex) and a bow tie (self-intersecting)
+        assert_eq!(
+            UniformPolygon::new(&[[0.0, 0.0], [2.0, 1.0], [0.0, 2.0], [1.0, 1.0]]),
+            Err(NotConvex)
+        );
+        assert_eq!(
+            UniformPolygon::new(&[[0.0, 0.0], [2.0, 2.0], [2.0, 0.0], [0.0, 1.0]]),
+            Err(NotConvex)
+        );
+    }
+
+    #[test]
+    fn test_orientation() {
+        let square = [[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]];
+        let mut reversed = square;
+        reversed.reverse();
+        let mut rng = crate::test::rng(51);
+        for vertices in [square, reversed] {
+            let polygon = UniformPolygon::new(&vertices).unwrap();
+            for _ in 0..100 {
+                let [x, y] = polygon.sample(&mut rng);
+                assert!((0.0..=1.0).contains(&x) && (0.0..=1.0).contains(&y));
+            }
+        }
+
+        // Collinear vertices are allowed
+        let polygon = UniformPolygon::new(&[[0.0f32, 0.0], [1.0, 0.0], [2.0, 0.0], [0.0, 2.0]]);
+        assert!(polygon.is_ok());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn uniformity() {
+        use crate::test::check_cdf;
+
+        let square =
+            UniformPolygon::new(&[[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]).unwrap();
+        let xs = [0.01, 0.1, 0.3, 0.5, 0.7, 0.9, 0.99];
+        check_cdf(square.clone().map(|p: [f64; 2]| p[0]), 52, |x| x, &xs);
+        check_cdf(square.map(|p: [f64; 2]| p[1]), 53, |y| y, &xs);
+
+        // A trapezoid of area 1.5: a unit square with a triangle to its right
+        let trapezoid =
+            UniformPolygon::new(&[[0.0, 0.0], [2.0, 0.0], [1.0, 1.0], [0.0, 1.0]]).unwrap();
+        let cdf = |x: f64| {
+            if x <= 1.0 {
+                x / 1.5
+            } else {
+                let t = x - 1.0;
+                (1.0 + t - t * t / 2.0) / 1.5
+            }
+        };
+        let xs = [0.1, 0.5, 0.9, 1.1, 1.3, 1.5, 1.7, 1.9];
+        check_cdf(trapezoid.map(|p: [f64; 2]| p[0]), 54, cdf, &xs);
+    }
+}
@@ -0,0 +1,149 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The `UniformTriangle` distribution.
+
+use crate::distr::{Distribution, StandardUniform};
+use crate::Rng;
+use core::fmt;
+use num_traits::Float;
+
+/// Samples uniformly (by area) from a triangle in `N` dimensions.
+///
+/// The triangle is given by its three vertices `a`, `b` and `c`, which are
+/// points `[F; N]` (typically in two or three dimensions). Samples are
+/// `a + u (b - a) + v (c - a)` where `(u, v)` is uniform on the unit
+/// triangle `u, v ≥ 0, u + v ≤ 1`.
+///
+/// For convex polygons, see [`UniformPolygon`].
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{Distribution, UniformTriangle};
+///
+/// let triangle = UniformTriangle::new([[0.0, 0.0, 1.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]).unwrap();
+/// let p: [f64; 3] = triangle.sample(&mut rand::rng());
+/// println!("{:?} is from the triangle.", p);
+/// ```
+///
+/// [`UniformPolygon`]: crate::distr::UniformPolygon
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UniformTriangle<F, const N: usize> {
+    a: [F; N],
+    ab: [F; N],
+    ac: [F; N],
+}
+
+/// Error type returned from [`UniformTriangle::new`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TriangleError {
+    /// A coordinate of a vertex, or of the difference of two vertices, is
+    /// infinite or NaN.
+    NotFinite,
+}
+
+impl fmt::Display for TriangleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TriangleError::NotFinite => "vertex is not finite in uniform triangle distribution",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TriangleError {}
+
+impl<F: Float, const N: usize> UniformTriangle<F, N> {
+    /// Construct a new `UniformTriangle` with the given vertices.
+    ///
+    /// Degenerate triangles (where the vertices are collinear) are allowed.
+    pub fn new(vertices: [[F; N]; 3]) -> Result<UniformTriangle<F, N>, TriangleError> {
+        let [a, b, c] = vertices;
+        let mut ab = [F::zero(); N];
+        let mut ac = [F::zero(); N];
+        for i in 0..N {
+            ab[i] = b[i] - a[i];
+            ac[i] = c[i] - a[i];
+            if !(a[i].is_finite() && ab[i].is_finite() && ac[i].is_finite()) {
+                return Err(TriangleError::NotFinite);
+            }
+        }
+        Ok(UniformTriangle { a, ab, ac })
+    }
+}
+
+impl<F, const N: usize> Distribution<[F; N]> for UniformTriangle<F, N>
+where
+    F: Float,
+    StandardUniform: Distribution<F>,
+{
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [F; N] {
+        let (mut u, mut v): (F, F) = (rng.random(), rng.random());
+        if u + v > F::one() {
+            // Reflect into the lower-left half of the unit square
+            u = F::one() - u;
+            v = F::one() - v;
+        }
+        let mut p = self.a;
+        for ((x, &ab), &ac) in p.iter_mut().zip(&self.ab).zip(&self.ac) {
+            *x = *x + u * ab + v * ac;
+        }
+        p
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid() {
+        let t = UniformTriangle::new([[0.0, 0.0], [1.0, f64::NAN], [0.0, 1.0]]);
+        assert_eq!(t, Err(TriangleError::NotFinite));
+        let t = UniformTriangle::new([[0.0, 0.0], [1.0, 0.0], [0.0, f64::INFINITY]]);
+        assert_eq!(t, Err(TriangleError::NotFinite));
+        let t = UniformTriangle::new([[-f64::MAX], [f64::MAX], [0.0]]);
+        assert_eq!(t, Err(TriangleError::NotFinite));
+    }
+
+    #[test]
+    fn test_degenerate() {
+        let t = UniformTriangle::new([[1.0, 2.0]; 3]).unwrap();
+        let mut rng = crate::test::rng(41);
+        assert_eq!(t.sample(&mut rng), [1.0, 2.0]);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn uniformity() {
+        use crate::test::check_cdf;
+        let xs = [0.01, 0.1, 0.3, 0.5, 0.7, 0.9, 0.99];
+
+        // In the unit triangle, x has density 2 (1 - x) and x + y density 2 s
+        let t = UniformTriangle::new([[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]]).unwrap();
+        let cdf = |x: f64| 1.0 - (1.0 - x) * (1.0 - x);
+        check_cdf(t.map(|p: [f64; 2]| p[0]), 42, cdf, &xs);
+        check_cdf(t.map(|p: [f64; 2]| p[1]), 43, cdf, &xs);
+        check_cdf(t.map(|p: [f64; 2]| p[0] + p[1]), 44, |s| s * s, &xs);
+
+        // A triangle in 3D, symmetric about its apex c = [0.5, 0, 0.5]: the
+        // distance from the base has density 2 (1 - h)
+        let t = UniformTriangle::new([[0.0, 1.0, 0.0], [1.0, 1.0, 0.0], [0.5, 0.0, 0.5]]).unwrap();
+        check_cdf(t.map(|p: [f64; 3]| 1.0 - p[1]), 45, cdf, &xs);
+        check_cdf(t.map(|p: [f64; 3]| 2.0 * p[2]), 46, cdf, &xs);
+    }
+
+    #[test]
+    fn uniform_triangle_distributions_can_be_compared() {
+        assert_eq!(
+            UniformTriangle::new([[0.0], [1.0], [2.0]]),
+            UniformTriangle::new([[0.0], [1.0], [2.0]])
+        );
+    }
+}
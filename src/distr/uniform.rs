@@ -167,7 +167,10 @@ use serde::{Deserialize, Serialize};
 ///   expected uniform distribution of output. In the worst case, bias affects
 ///   1 in `2^n` samples where n is 56 (`i8` and `u8`), 48 (`i16` and `u16`), 96
 ///   (`i32` and `u32`), 64 (`i64` and `u64`), 128 (`i128` and `u128`).
-///   The `unbiased` feature flag fixes this bias.
+///   The `unbiased` feature flag fixes this bias globally; alternatively use
+///   [`Rng::random_range_unbiased`] or
+///   [`UniformSampler::sample_single_unbiased`] for individual samples.
+///   (Sampling from a `Uniform` object is always unbiased.)
 /// - `usize` ([`UniformUsize`]) is handled specially, using the `u32`
 ///   implementation where possible to enable portable results across 32-bit and
 ///   64-bit CPU architectures.
@@ -367,6 +370,50 @@ pub trait UniformSampler: Sized {
         let uniform: Self = UniformSampler::new_inclusive(low, high)?;
         Ok(uniform.sample(rng))
     }
+
+    /// Sample a single value uniformly and without bias from a range with
+    /// inclusive lower bound and exclusive upper bound `[low, high)`.
+    ///
+    /// This is like [`UniformSampler::sample_single`], but always uses an
+    /// unbiased algorithm, regardless of the `unbiased` feature flag.
+    ///
+    /// By default this is implemented using
+    /// `UniformSampler::new(low, high).sample(rng)`. Integer types provide a
+    /// more optimal implementation, matching [`UniformSampler::sample_single`]
+    /// when the `unbiased` feature flag is enabled.
+    fn sample_single_unbiased<R: Rng + ?Sized, B1, B2>(
+        low: B1,
+        high: B2,
+        rng: &mut R,
+    ) -> Result<Self::X, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let uniform: Self = UniformSampler::new(low, high)?;
+        Ok(uniform.sample(rng))
+    }
+
+    /// Sample a single value uniformly and without bias from a range with
+    /// inclusive lower bound and inclusive upper bound `[low, high]`.
+    ///
+    /// This is like [`UniformSampler::sample_single_inclusive`], but always
+    /// uses an unbiased algorithm, regardless of the `unbiased` feature flag.
+    ///
+    /// By default this is implemented using
+    /// `UniformSampler::new_inclusive(low, high).sample(rng)`.
+    fn sample_single_inclusive_unbiased<R: Rng + ?Sized, B1, B2>(
+        low: B1,
+        high: B2,
+        rng: &mut R,
+    ) -> Result<Self::X, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let uniform: Self = UniformSampler::new_inclusive(low, high)?;
+        Ok(uniform.sample(rng))
+    }
 }
 
 impl<X: SampleUniform> TryFrom<Range<X>> for Uniform<X> {
@@ -423,6 +470,18 @@ pub trait SampleRange<T> {
     /// Generate a sample from the given range.
     fn sample_single<R: RngCore + ?Sized>(self, rng: &mut R) -> Result<T, Error>;
 
+    /// Generate a sample from the given range without bias.
+    ///
+    /// See [`UniformSampler::sample_single_unbiased`]. The default
+    /// implementation uses [`SampleRange::sample_single`]; implementations
+    /// which may be biased should override this.
+    fn sample_single_unbiased<R: RngCore + ?Sized>(self, rng: &mut R) -> Result<T, Error>
+    where
+        Self: Sized,
+    {
+        self.sample_single(rng)
+    }
+
     /// Check whether the range is empty.
     fn is_empty(&self) -> bool;
 }
@@ -433,6 +492,11 @@ impl<T: SampleUniform + PartialOrd> SampleRange<T> for Range<T> {
         T::Sampler::sample_single(self.start, self.end, rng)
     }
 
+    #[inline]
+    fn sample_single_unbiased<R: RngCore + ?Sized>(self, rng: &mut R) -> Result<T, Error> {
+        T::Sampler::sample_single_unbiased(self.start, self.end, rng)
+    }
+
     #[inline]
     fn is_empty(&self) -> bool {
         !(self.start < self.end)
@@ -445,6 +509,11 @@ impl<T: SampleUniform + PartialOrd> SampleRange<T> for RangeInclusive<T> {
         T::Sampler::sample_single_inclusive(self.start(), self.end(), rng)
     }
 
+    #[inline]
+    fn sample_single_unbiased<R: RngCore + ?Sized>(self, rng: &mut R) -> Result<T, Error> {
+        T::Sampler::sample_single_inclusive_unbiased(self.start(), self.end(), rng)
+    }
+
     #[inline]
     fn is_empty(&self) -> bool {
         !(self.start() <= self.end())
@@ -459,6 +528,11 @@ macro_rules! impl_sample_range_u {
                 <$t as SampleUniform>::Sampler::sample_single(0, self.end, rng)
             }
 
+            #[inline]
+            fn sample_single_unbiased<R: RngCore + ?Sized>(self, rng: &mut R) -> Result<$t, Error> {
+                <$t as SampleUniform>::Sampler::sample_single_unbiased(0, self.end, rng)
+            }
+
             #[inline]
             fn is_empty(&self) -> bool {
                 0 == self.end
@@ -471,6 +545,11 @@ macro_rules! impl_sample_range_u {
                 <$t as SampleUniform>::Sampler::sample_single_inclusive(0, self.end, rng)
             }
 
+            #[inline]
+            fn sample_single_unbiased<R: RngCore + ?Sized>(self, rng: &mut R) -> Result<$t, Error> {
+                <$t as SampleUniform>::Sampler::sample_single_inclusive_unbiased(0, self.end, rng)
+            }
+
             #[inline]
             fn is_empty(&self) -> bool {
                 false
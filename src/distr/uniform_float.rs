@@ -192,6 +192,25 @@ macro_rules! uniform_float_impl {
                 // to use a single instruction.
                 Ok(value0_1 * scale + low)
             }
+
+            // The `unbiased` feature does not affect floats
+            #[inline]
+            fn sample_single_unbiased<R: Rng + ?Sized, B1, B2>(low_b: B1, high_b: B2, rng: &mut R) -> Result<Self::X, Error>
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                Self::sample_single(low_b, high_b, rng)
+            }
+
+            #[inline]
+            fn sample_single_inclusive_unbiased<R: Rng + ?Sized, B1, B2>(low_b: B1, high_b: B2, rng: &mut R) -> Result<Self::X, Error>
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                Self::sample_single_inclusive(low_b, high_b, rng)
+            }
         }
     };
 }
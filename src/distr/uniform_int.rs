@@ -60,8 +60,12 @@ use serde::{Deserialize, Serialize};
 ///
 /// # Bias
 ///
-/// Unless the `unbiased` feature flag is used, outputs may have a small bias.
-/// In the worst case, bias affects 1 in `2^n` samples where n is
+/// Sampling from a [`Uniform`] object is unbiased. Unless the `unbiased`
+/// feature flag is used, outputs of [`UniformSampler::sample_single`] and
+/// [`UniformSampler::sample_single_inclusive`] (and thus of
+/// [`Rng::random_range`]) may have a small bias; use
+/// [`UniformSampler::sample_single_unbiased`] or [`Rng::random_range_unbiased`]
+/// to avoid this for individual samples. In the worst case, bias affects 1 in `2^n` samples where n is
 /// 56 (`i8` and `u8`), 48 (`i16` and `u16`), 96 (`i32` and `u32`), 64 (`i64`
 /// and `u64`), 128 (`i128` and `u128`).
 ///
@@ -216,6 +220,38 @@ macro_rules! uniform_int_impl {
                 high_b: B2,
                 rng: &mut R,
             ) -> Result<Self::X, Error>
+            where
+                B1: SampleBorrow<$ty> + Sized,
+                B2: SampleBorrow<$ty> + Sized,
+            {
+                Self::sample_single_inclusive_unbiased(low_b, high_b, rng)
+            }
+
+            #[inline]
+            fn sample_single_unbiased<R: Rng + ?Sized, B1, B2>(
+                low_b: B1,
+                high_b: B2,
+                rng: &mut R,
+            ) -> Result<Self::X, Error>
+            where
+                B1: SampleBorrow<Self::X> + Sized,
+                B2: SampleBorrow<Self::X> + Sized,
+            {
+                let low = *low_b.borrow();
+                let high = *high_b.borrow();
+                if !(low < high) {
+                    return Err(Error::EmptyRange);
+                }
+                Self::sample_single_inclusive_unbiased(low, high - 1, rng)
+            }
+
+            /// Sample single value, Canon's method, unbiased
+            #[inline]
+            fn sample_single_inclusive_unbiased<R: Rng + ?Sized, B1, B2>(
+                low_b: B1,
+                high_b: B2,
+                rng: &mut R,
+            ) -> Result<Self::X, Error>
             where
                 B1: SampleBorrow<$ty> + Sized,
                 B2: SampleBorrow<$ty> + Sized,
@@ -565,6 +601,59 @@ impl UniformSampler for UniformUsize {
 
         UniformInt::<u32>::sample_single_inclusive(low as u32, high as u32, rng).map(|x| x as usize)
     }
+
+    #[inline]
+    fn sample_single_unbiased<R: Rng + ?Sized, B1, B2>(
+        low_b: B1,
+        high_b: B2,
+        rng: &mut R,
+    ) -> Result<Self::X, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let low = *low_b.borrow();
+        let high = *high_b.borrow();
+        if !(low < high) {
+            return Err(Error::EmptyRange);
+        }
+
+        if cfg!(target_pointer_width = "64") && high > (u32::MAX as usize) {
+            return UniformInt::<u64>::sample_single_unbiased(low as u64, high as u64, rng)
+                .map(|x| x as usize);
+        }
+
+        UniformInt::<u32>::sample_single_unbiased(low as u32, high as u32, rng).map(|x| x as usize)
+    }
+
+    #[inline]
+    fn sample_single_inclusive_unbiased<R: Rng + ?Sized, B1, B2>(
+        low_b: B1,
+        high_b: B2,
+        rng: &mut R,
+    ) -> Result<Self::X, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let low = *low_b.borrow();
+        let high = *high_b.borrow();
+        if !(low <= high) {
+            return Err(Error::EmptyRange);
+        }
+
+        if cfg!(target_pointer_width = "64") && high > (u32::MAX as usize) {
+            return UniformInt::<u64>::sample_single_inclusive_unbiased(
+                low as u64,
+                high as u64,
+                rng,
+            )
+            .map(|x| x as usize);
+        }
+
+        UniformInt::<u32>::sample_single_inclusive_unbiased(low as u32, high as u32, rng)
+            .map(|x| x as usize)
+    }
 }
 
 #[cfg(test)]
@@ -703,6 +792,107 @@ mod tests {
         assert!(Uniform::try_from(100..=99).is_err());
     }
 
+    #[test]
+    fn test_unbiased_single() {
+        let mut rng = crate::test::rng(253);
+        macro_rules! t {
+            ($($ty:ident),*) => {$(
+                let ranges = [
+                    ($ty::MIN, $ty::MAX),
+                    ($ty::MIN, $ty::MIN + 5),
+                    ($ty::MAX - 5, $ty::MAX),
+                ];
+                for &(low, high) in ranges.iter() {
+                    for _ in 0..100 {
+                        let v: $ty = rng.random_range_unbiased(low..high);
+                        assert!(low <= v && v < high);
+                        let v: $ty = rng.random_range_unbiased(low..=high);
+                        assert!(low <= v && v <= high);
+                    }
+
+                    // With the `unbiased` feature, results must match
+                    #[cfg(feature = "unbiased")]
+                    {
+                        let mut rng1 = crate::test::rng(254);
+                        let mut rng2 = crate::test::rng(254);
+                        for _ in 0..10 {
+                            assert_eq!(
+                                rng1.random_range::<$ty, _>(low..=high),
+                                rng2.random_range_unbiased::<$ty, _>(low..=high)
+                            );
+                        }
+                    }
+                }
+            )*};
+        }
+        t!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, usize);
+
+        assert_eq!(
+            <u32 as SampleUniform>::Sampler::sample_single_unbiased(5, 5, &mut rng),
+            Err(Error::EmptyRange)
+        );
+        assert_eq!(
+            <usize as SampleUniform>::Sampler::sample_single_inclusive_unbiased(6, 5, &mut rng),
+            Err(Error::EmptyRange)
+        );
+    }
+
+    #[test]
+    fn unbiased_value_stability() {
+        // Results are the same with and without the `unbiased` feature
+        fn test_samples<T: SampleUniform + Copy + Debug + PartialOrd>(
+            low: T,
+            high: T,
+            expected: &[T; 6],
+        ) {
+            let mut rng = crate::test::rng(898);
+            let mut buf = [low; 6];
+            for x in &mut buf[0..2] {
+                *x = rng.random_range_unbiased(low..high);
+            }
+            for x in &mut buf[2..4] {
+                *x = rng.random_range_unbiased(low..=high);
+            }
+            buf[4] = T::Sampler::sample_single_unbiased(low, high, &mut rng).unwrap();
+            buf[5] = T::Sampler::sample_single_inclusive_unbiased(low, high, &mut rng).unwrap();
+            assert_eq!(&buf, expected);
+        }
+
+        test_samples(0u8, 200, &[14, 89, 118, 75, 119, 160]);
+        test_samples(-100i16, 30_000, &[2017, 13420, 17636, 11246, 17937, 23945]);
+        test_samples(
+            0u32,
+            (1 << 31) + 1,
+            &[
+                151084584, 1265394128, 809513006, 1715444297, 1021713010, 1742473112,
+            ],
+        );
+        test_samples(
+            i64::MIN,
+            3 << 61,
+            &[
+                -1973014090217265977,
+                3670237973141213616,
+                -8875172524485178040,
+                -6041055085049302030,
+                2210155443017148977,
+                -2972154632715625515,
+            ],
+        );
+        test_samples(
+            7u128,
+            u128::MAX / 3 * 2,
+            &[
+                85514970587857174554926383033871356645,
+                27769810679258759915698321710931511844,
+                122436992146882095795073876679074283950,
+                16253119286433433738005804717288839352,
+                9675944481255667973497306908558566288,
+                1847218822314092625165012932621808657,
+            ],
+        );
+    }
+
     #[test]
     fn value_stability() {
         fn test_samples<T: SampleUniform + Copy + Debug + PartialEq + Add<T>>(
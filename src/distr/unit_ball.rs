@@ -0,0 +1,129 @@
+// Copyright 2019 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The `UnitBall` distribution.
+
+use crate::distr::unit_sphere::sample_normalised;
+use crate::distr::{uniform::SampleUniform, Distribution, StandardNormal, Uniform};
+use crate::Rng;
+use num_traits::Float;
+
+/// Samples uniformly from the volume of the unit ball in `N` dimensions.
+///
+/// This samples arrays `[F; N]` of length at most 1. In three dimensions, this
+/// is implemented via rejection sampling; otherwise by scaling a sample of
+/// the [`UnitSphere`](crate::distr::UnitSphere) by `U^(1/N)`, where `U` is
+/// uniform on `[0, 1)`.
+///
+/// For a distribution that samples only from the surface of the unit ball,
+/// see [`UnitSphere`](crate::distr::UnitSphere).
+///
+/// For two dimensions, [`UnitDisc`](crate::distr::UnitDisc) is faster.
+///
+/// # Plot
+///
+/// The following plot shows the unit ball in three dimensions.
+/// This distribution samples individual points from the entire volume
+/// of the ball.
+///
+/// ![Unit ball](https://raw.githubusercontent.com/rust-random/charts/main/charts/unit_ball.svg)
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{UnitBall, Distribution};
+///
+/// let v: [f64; 3] = UnitBall.sample(&mut rand::rng());
+/// println!("{:?} is from the unit ball.", v);
+///
+/// let v: [f64; 6] = UnitBall.sample(&mut rand::rng());
+/// println!("{:?} is from the 6-dimensional unit ball.", v);
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnitBall;
+
+impl<F, const N: usize> Distribution<[F; N]> for UnitBall
+where
+    F: Float + SampleUniform,
+    StandardNormal: Distribution<F>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [F; N] {
+        let mut v = [F::zero(); N];
+        if N == 3 {
+            v.copy_from_slice(&sample_rejection::<F, R>(rng));
+        } else if N > 0 {
+            v = sample_normalised(rng);
+            let u: F = Uniform::new(F::zero(), F::one()).unwrap().sample(rng);
+            let r = u.powf(F::from(N).unwrap().recip());
+            for x in v.iter_mut() {
+                *x = *x * r;
+            }
+        }
+        v
+    }
+}
+
+fn sample_rejection<F: Float + SampleUniform, R: Rng + ?Sized>(rng: &mut R) -> [F; 3] {
+    let uniform = Uniform::new(F::from(-1.).unwrap(), F::from(1.).unwrap()).unwrap();
+    let mut x1;
+    let mut x2;
+    let mut x3;
+    loop {
+        x1 = uniform.sample(rng);
+        x2 = uniform.sample(rng);
+        x3 = uniform.sample(rng);
+        if x1 * x1 + x2 * x2 + x3 * x3 <= F::from(1.).unwrap() {
+            break;
+        }
+    }
+    [x1, x2, x3]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnitBall;
+    use crate::distr::Distribution;
+
+    #[test]
+    fn norm() {
+        let mut rng = crate::test::rng(11);
+        for _ in 0..1000 {
+            let x: [f64; 3] = UnitBall.sample(&mut rng);
+            assert!(x.iter().map(|x| x * x).sum::<f64>() <= 1.0);
+            let x: [f32; 4] = UnitBall.sample(&mut rng);
+            assert!(x.iter().map(|x| x * x).sum::<f32>() <= 1.0 + 1e-6);
+        }
+        let x: [f64; 0] = UnitBall.sample(&mut rng);
+        assert_eq!(x, []);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn uniformity() {
+        use crate::test::check_cdf;
+        let xs = [-0.99, -0.9, -0.5, -0.1, 0.0, 0.3, 0.7, 0.95];
+
+        // In three dimensions, projections have density 3 / 4 (1 - t²)
+        let cdf = |t: f64| (2.0 + 3.0 * t - t * t * t) / 4.0;
+        check_cdf(UnitBall.map(|x: [f64; 3]| x[0]), 12, cdf, &xs);
+        let u = [2. / 3., -1. / 3., 2. / 3.];
+        let distr = UnitBall.map(move |x: [f64; 3]| x[0] * u[0] + x[1] * u[1] + x[2] * u[2]);
+        check_cdf(distr, 13, cdf, &xs);
+
+        // In N dimensions, the norm r has CDF r^N
+        fn norm(x: &[f64]) -> f64 {
+            x.iter().map(|x| x * x).sum::<f64>().sqrt()
+        }
+        let xs = [0.1, 0.5, 0.8, 0.9, 0.99];
+        check_cdf(UnitBall.map(|x: [f64; 2]| norm(&x)), 14, |r| r.powi(2), &xs);
+        check_cdf(UnitBall.map(|x: [f64; 5]| norm(&x)), 15, |r| r.powi(5), &xs);
+        check_cdf(UnitBall.map(|x: [f64; 8]| norm(&x)), 16, |r| r.powi(8), &xs);
+    }
+}
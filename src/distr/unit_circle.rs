@@ -0,0 +1,97 @@
+// Copyright 2018 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The `UnitCircle` distribution.
+
+use crate::distr::{uniform::SampleUniform, Distribution, Uniform};
+use crate::Rng;
+use num_traits::Float;
+
+/// Samples uniformly from the circumference of the unit circle in two dimensions.
+///
+/// Implemented via a method by von Neumann[^1].
+///
+/// For a distribution that also samples from the interior of the unit circle,
+/// see [`UnitDisc`](crate::distr::UnitDisc).
+///
+/// For a similar distribution in three dimensions, see [`UnitSphere`](crate::distr::UnitSphere).
+///
+/// # Plot
+///
+/// The following plot shows the unit circle.
+///
+/// ![Unit circle](https://raw.githubusercontent.com/rust-random/charts/main/charts/unit_circle.svg)
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{UnitCircle, Distribution};
+///
+/// let v: [f64; 2] = UnitCircle.sample(&mut rand::rng());
+/// println!("{:?} is from the unit circle.", v)
+/// ```
+///
+/// [^1]: von Neumann, J. (1951) [*Various Techniques Used in Connection with
+///       Random Digits.*](https://mcnp.lanl.gov/pdf_files/nbs_vonneumann.pdf)
+///       NBS Appl. Math. Ser., No. 12. Washington, DC: U.S. Government Printing
+///       Office, pp. 36-38.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnitCircle;
+
+impl<F: Float + SampleUniform> Distribution<[F; 2]> for UnitCircle {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [F; 2] {
+        let uniform = Uniform::new(F::from(-1.).unwrap(), F::from(1.).unwrap()).unwrap();
+        let mut x1;
+        let mut x2;
+        let mut sum;
+        loop {
+            x1 = uniform.sample(rng);
+            x2 = uniform.sample(rng);
+            sum = x1 * x1 + x2 * x2;
+            if sum < F::from(1.).unwrap() {
+                break;
+            }
+        }
+        let diff = x1 * x1 - x2 * x2;
+        [diff / sum, F::from(2.).unwrap() * x1 * x2 / sum]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnitCircle;
+    use crate::distr::Distribution;
+
+    #[test]
+    fn norm() {
+        let mut rng = crate::test::rng(1);
+        for _ in 0..1000 {
+            let x: [f64; 2] = UnitCircle.sample(&mut rng);
+            assert_almost_eq!(x[0] * x[0] + x[1] * x[1], 1., 1e-15);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn uniformity() {
+        use crate::test::check_cdf;
+        use core::f64::consts::PI;
+
+        // The angle is uniform on (-π, π]
+        let xs = [-3.1, -2.0, -1.0, 0.0, 0.5, 1.5, 3.0];
+        let distr = UnitCircle.map(|x: [f64; 2]| x[1].atan2(x[0]));
+        check_cdf(distr, 2, |a| (a + PI) / (2.0 * PI), &xs);
+
+        // Projections have density 1 / (π sqrt(1 - t²))
+        let xs = [-0.99, -0.9, -0.5, -0.1, 0.0, 0.3, 0.7, 0.95];
+        let distr = UnitCircle.map(|x: [f64; 2]| 0.6 * x[0] + 0.8 * x[1]);
+        check_cdf(distr, 3, |t| 0.5 + t.asin() / PI, &xs);
+    }
+}
@@ -0,0 +1,92 @@
+// Copyright 2019 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The `UnitDisc` distribution.
+
+use crate::distr::{uniform::SampleUniform, Distribution, Uniform};
+use crate::Rng;
+use num_traits::Float;
+
+/// Samples uniformly from the unit disc in two dimensions.
+///
+/// Implemented via rejection sampling.
+///
+/// For a distribution that samples only from the circumference of the unit disc,
+/// see [`UnitCircle`](crate::distr::UnitCircle).
+///
+/// For a similar distribution in three dimensions, see [`UnitBall`](crate::distr::UnitBall).
+///
+/// # Plot
+///
+/// The following plot shows the unit disc.
+/// This distribution samples individual points from the entire area of the disc.
+///
+/// ![Unit disc](https://raw.githubusercontent.com/rust-random/charts/main/charts/unit_disc.svg)
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{UnitDisc, Distribution};
+///
+/// let v: [f64; 2] = UnitDisc.sample(&mut rand::rng());
+/// println!("{:?} is from the unit Disc.", v)
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnitDisc;
+
+impl<F: Float + SampleUniform> Distribution<[F; 2]> for UnitDisc {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [F; 2] {
+        let uniform = Uniform::new(F::from(-1.).unwrap(), F::from(1.).unwrap()).unwrap();
+        let mut x1;
+        let mut x2;
+        loop {
+            x1 = uniform.sample(rng);
+            x2 = uniform.sample(rng);
+            if x1 * x1 + x2 * x2 <= F::from(1.).unwrap() {
+                break;
+            }
+        }
+        [x1, x2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnitDisc;
+    use crate::distr::Distribution;
+
+    #[test]
+    fn norm() {
+        let mut rng = crate::test::rng(21);
+        for _ in 0..1000 {
+            let x: [f64; 2] = UnitDisc.sample(&mut rng);
+            assert!(x[0] * x[0] + x[1] * x[1] <= 1.0);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn uniformity() {
+        use crate::test::check_cdf;
+        let xs = [-0.99, -0.9, -0.5, -0.1, 0.0, 0.3, 0.7, 0.95];
+
+        // Projections have density 2 / π sqrt(1 - t²)
+        let cdf = |t: f64| 0.5 + (t * (1.0 - t * t).sqrt() + t.asin()) / core::f64::consts::PI;
+        check_cdf(UnitDisc.map(|x: [f64; 2]| x[0]), 22, cdf, &xs);
+        let u = [0.6, -0.8];
+        let distr = UnitDisc.map(move |x: [f64; 2]| x[0] * u[0] + x[1] * u[1]);
+        check_cdf(distr, 23, cdf, &xs);
+
+        // The norm r has CDF r²
+        let xs = [0.1, 0.5, 0.8, 0.9, 0.99];
+        let distr = UnitDisc.map(|x: [f64; 2]| (x[0] * x[0] + x[1] * x[1]).sqrt());
+        check_cdf(distr, 24, |r| r * r, &xs);
+    }
+}
@@ -0,0 +1,104 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The `UnitSimplex` distribution.
+
+use crate::distr::{Distribution, Exp1};
+use crate::Rng;
+use num_traits::Float;
+
+/// Samples uniformly from the probability simplex in `N` dimensions.
+///
+/// The probability simplex is the set of points `x = [x₁, x₂, ..., x_N]` such
+/// that `xᵢ ≥ 0` and `∑ xᵢ = 1`; samples are thus probability vectors. This
+/// is the [`Dirichlet`] distribution with all `αᵢ = 1`, but does not require
+/// allocation.
+///
+/// Implemented by normalising a vector of [`Exp1`] samples.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{UnitSimplex, Distribution};
+///
+/// let p: [f64; 4] = UnitSimplex.sample(&mut rand::rng());
+/// println!("{:?} is from the probability simplex.", p);
+/// ```
+///
+/// [`Dirichlet`]: crate::distr::Dirichlet
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnitSimplex;
+
+impl<F, const N: usize> Distribution<[F; N]> for UnitSimplex
+where
+    F: Float,
+    Exp1: Distribution<F>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [F; N] {
+        let mut v = [F::zero(); N];
+        if N == 0 {
+            return v;
+        }
+        loop {
+            let mut sum = F::zero();
+            for x in v.iter_mut() {
+                *x = rng.sample(Exp1);
+                sum = sum + *x;
+            }
+            if sum > F::zero() {
+                let inv_sum = sum.recip();
+                for x in v.iter_mut() {
+                    *x = *x * inv_sum;
+                }
+                return v;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnitSimplex;
+    use crate::distr::Distribution;
+
+    #[test]
+    fn sum() {
+        let mut rng = crate::test::rng(31);
+        for _ in 0..1000 {
+            let x: [f64; 5] = UnitSimplex.sample(&mut rng);
+            assert!(x.iter().all(|&x| x >= 0.0));
+            assert_almost_eq!(x.iter().sum::<f64>(), 1.0, 1e-15);
+            let x: [f32; 2] = UnitSimplex.sample(&mut rng);
+            assert_almost_eq!(x[0] + x[1], 1.0, 1e-6);
+        }
+        let x: [f64; 1] = UnitSimplex.sample(&mut rng);
+        assert_eq!(x, [1.0]);
+        let x: [f64; 0] = UnitSimplex.sample(&mut rng);
+        assert_eq!(x, []);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn uniformity() {
+        use crate::test::check_cdf;
+        let xs = [0.01, 0.1, 0.3, 0.5, 0.7, 0.9, 0.99];
+
+        // Each coordinate has distribution Beta(1, N - 1)
+        let cdf = |t: f64| 1.0 - (1.0 - t).powi(3);
+        check_cdf(UnitSimplex.map(|x: [f64; 4]| x[2]), 32, cdf, &xs);
+
+        // The sum of two coordinates has distribution Beta(2, N - 2)
+        let cdf = |t: f64| t * t;
+        check_cdf(UnitSimplex.map(|x: [f64; 3]| x[0] + x[2]), 33, cdf, &xs);
+
+        // In two dimensions, the first coordinate is uniform
+        check_cdf(UnitSimplex.map(|x: [f64; 2]| x[0]), 34, |t| t, &xs);
+    }
+}
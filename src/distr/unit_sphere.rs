@@ -0,0 +1,172 @@
+// Copyright 2018-2019 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! The `UnitSphere` distribution.
+
+use crate::distr::{uniform::SampleUniform, Distribution, StandardNormal, Uniform};
+use crate::Rng;
+use num_traits::Float;
+
+/// Samples uniformly from the surface of the unit sphere in `N` dimensions.
+///
+/// This samples arrays `[F; N]` of unit length. In three dimensions, this is
+/// implemented via a method by Marsaglia[^1]; otherwise by normalising a
+/// vector of [`StandardNormal`] samples[^2].
+///
+/// For a distribution that also samples from the interior of the sphere,
+/// see [`UnitBall`](crate::distr::UnitBall).
+///
+/// For two dimensions, [`UnitCircle`](crate::distr::UnitCircle) is faster.
+///
+/// # Plot
+///
+/// The following plot shows the unit sphere as a wireframe.
+/// The wireframe is meant to illustrate that this distribution samples
+/// from the surface of the sphere only, not from the interior.
+///
+/// ![Unit sphere](https://raw.githubusercontent.com/rust-random/charts/main/charts/unit_sphere.svg)
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::{UnitSphere, Distribution};
+///
+/// let v: [f64; 3] = UnitSphere.sample(&mut rand::rng());
+/// println!("{:?} is from the unit sphere surface.", v);
+///
+/// let v: [f32; 10] = UnitSphere.sample(&mut rand::rng());
+/// println!("{:?} is from the 10-dimensional unit sphere surface.", v);
+/// ```
+///
+/// [^1]: Marsaglia, George (1972). [*Choosing a Point from the Surface of a
+///       Sphere.*](https://doi.org/10.1214/aoms/1177692644)
+///       Ann. Math. Statist. 43, no. 2, 645--646.
+/// [^2]: Muller, Mervin E. (1959). [*A Note on a Method for Generating Points
+///       Uniformly on N-Dimensional Spheres.*](https://doi.org/10.1145/377939.377946)
+///       Comm. ACM 2, no. 4, 19--20.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UnitSphere;
+
+impl<F, const N: usize> Distribution<[F; N]> for UnitSphere
+where
+    F: Float + SampleUniform,
+    StandardNormal: Distribution<F>,
+{
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> [F; N] {
+        let mut v = [F::zero(); N];
+        if N == 3 {
+            v.copy_from_slice(&sample_marsaglia::<F, R>(rng));
+        } else if N > 0 {
+            v = sample_normalised(rng);
+        }
+        v
+    }
+}
+
+fn sample_marsaglia<F: Float + SampleUniform, R: Rng + ?Sized>(rng: &mut R) -> [F; 3] {
+    let uniform = Uniform::new(F::from(-1.).unwrap(), F::from(1.).unwrap()).unwrap();
+    loop {
+        let (x1, x2) = (uniform.sample(rng), uniform.sample(rng));
+        let sum = x1 * x1 + x2 * x2;
+        if sum >= F::from(1.).unwrap() {
+            continue;
+        }
+        let factor = F::from(2.).unwrap() * (F::one() - sum).sqrt();
+        return [
+            x1 * factor,
+            x2 * factor,
+            F::from(1.).unwrap() - F::from(2.).unwrap() * sum,
+        ];
+    }
+}
+
+/// Sample a unit vector in `N > 0` dimensions by normalising a vector of
+/// independent normal samples, whose distribution is rotationally symmetric
+pub(crate) fn sample_normalised<F, R: Rng + ?Sized, const N: usize>(rng: &mut R) -> [F; N]
+where
+    F: Float,
+    StandardNormal: Distribution<F>,
+{
+    loop {
+        let mut v = [F::zero(); N];
+        let mut sum = F::zero();
+        for x in v.iter_mut() {
+            *x = rng.sample(StandardNormal);
+            sum = sum + *x * *x;
+        }
+        // Reject (extremely unlikely) vectors too short to normalise accurately
+        if sum >= F::min_positive_value().sqrt() {
+            let inv_norm = sum.sqrt().recip();
+            for x in v.iter_mut() {
+                *x = *x * inv_norm;
+            }
+            return v;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::UnitSphere;
+    use crate::distr::Distribution;
+
+    #[test]
+    fn norm() {
+        let mut rng = crate::test::rng(1);
+        for _ in 0..1000 {
+            let x: [f64; 3] = UnitSphere.sample(&mut rng);
+            assert_almost_eq!(x[0] * x[0] + x[1] * x[1] + x[2] * x[2], 1., 1e-15);
+        }
+    }
+
+    #[test]
+    fn norm_n_dim() {
+        let mut rng = crate::test::rng(2);
+        for _ in 0..100 {
+            let x: [f64; 7] = UnitSphere.sample(&mut rng);
+            assert_almost_eq!(x.iter().map(|x| x * x).sum::<f64>(), 1., 1e-14);
+            let x: [f32; 2] = UnitSphere.sample(&mut rng);
+            assert_almost_eq!(x[0] * x[0] + x[1] * x[1], 1., 1e-6);
+            let x: [f64; 1] = UnitSphere.sample(&mut rng);
+            assert_almost_eq!(x[0].abs(), 1., 1e-15);
+        }
+        let x: [f64; 0] = UnitSphere.sample(&mut rng);
+        assert_eq!(x, []);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn uniformity() {
+        use crate::test::check_cdf;
+        let xs = [-0.99, -0.9, -0.5, -0.1, 0.0, 0.3, 0.7, 0.95];
+
+        // In three dimensions, any projection is uniform on [-1, 1]
+        let cdf = |t: f64| (t + 1.0) / 2.0;
+        for (i, u) in [
+            [1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0],
+            [1. / 3., 2. / 3., -2. / 3.],
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let distr = UnitSphere.map(move |x: [f64; 3]| x[0] * u[0] + x[1] * u[1] + x[2] * u[2]);
+            check_cdf(distr, 3 + i as u64, cdf, &xs);
+        }
+
+        // In four dimensions, projections have density 2 / π sqrt(1 - t²)
+        let cdf = |t: f64| 0.5 + (t * (1.0 - t * t).sqrt() + t.asin()) / core::f64::consts::PI;
+        check_cdf(UnitSphere.map(|x: [f64; 4]| x[1]), 6, cdf, &xs);
+
+        // In five dimensions, projections have density 3 / 4 (1 - t²)
+        let cdf = |t: f64| (2.0 + 3.0 * t - t * t * t) / 4.0;
+        check_cdf(UnitSphere.map(|x: [f64; 5]| x[4]), 7, cdf, &xs);
+    }
+}
@@ -0,0 +1,503 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Sampling indices from logits with temperature, top-k, min-p and top-p
+//! (nucleus) filtering.
+
+use super::{Error, WeightedIndex};
+use crate::distr::Distribution;
+use crate::Rng;
+use alloc::{boxed::Box, vec::Vec};
+use num_traits::Float;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Parameters of a [`LogitsIndex`]
+///
+/// The default parameters (temperature `1`, no top-k, `top_p = 1` and
+/// `min_p = 0`) sample from the softmax of the logits without filtering.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::weighted::LogitsConfig;
+///
+/// let config = LogitsConfig {
+///     temperature: 0.7,
+///     top_k: Some(40),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct LogitsConfig {
+    /// The temperature `T`, which must be finite and non-negative
+    ///
+    /// Logits are divided by `T` before the softmax: a lower temperature
+    /// makes likely indices more likely. A temperature of `0` always picks
+    /// the index with the largest logit (the first, on ties).
+    pub temperature: f64,
+    /// Keep only the `k` indices with the largest logits, if set
+    ///
+    /// `k` must be at least `1`. Among equal logits at the boundary, which
+    /// are kept is unspecified.
+    pub top_k: Option<usize>,
+    /// Keep only the smallest set of most likely indices whose probability
+    /// sums to at least `top_p`, which must be in `(0, 1]`
+    pub top_p: f64,
+    /// Keep only indices whose probability is at least `min_p` times that of
+    /// the most likely index; `min_p` must be in `[0, 1]`
+    pub min_p: f64,
+}
+
+impl Default for LogitsConfig {
+    fn default() -> Self {
+        LogitsConfig {
+            temperature: 1.0,
+            top_k: None,
+            top_p: 1.0,
+            min_p: 0.0,
+        }
+    }
+}
+
+/// A distribution sampling indices from logits (unnormalised
+/// log-probabilities), as used to sample tokens from the output of a
+/// language model.
+///
+/// Index `i` is sampled with probability proportional to
+/// `exp(logits[i] / T)` (the softmax at temperature `T`), restricted to the
+/// indices remaining after filtering. The filters of [`LogitsConfig`] are
+/// applied in order top-k, min-p, top-p, with probabilities computed at
+/// temperature `T`. A logit of `-∞` masks an index, which is never sampled.
+///
+/// The softmax is computed relative to the largest logit, so any finite
+/// logits are handled without overflow.
+///
+/// # Performance
+///
+/// Construction takes expected `O(n)` time for `n` logits, or `O(n log m)`
+/// when top-p filtering keeps `m` indices: the vocabulary is never fully
+/// sorted. Sampling takes `O(log m)` time for `m` remaining indices, as for
+/// [`WeightedIndex`].
+///
+/// # Example
+///
+/// ```
+/// use rand::prelude::*;
+/// use rand::distr::weighted::{LogitsConfig, LogitsIndex};
+///
+/// let logits = [1.5f32, -0.3, 2.7, f32::NEG_INFINITY, 0.8];
+/// let config = LogitsConfig {
+///     temperature: 0.8,
+///     top_p: 0.9,
+///     ..Default::default()
+/// };
+/// let dist = LogitsIndex::new(&logits, config).unwrap();
+/// let token = dist.sample(&mut rand::rng());
+/// assert_ne!(token, 3);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "LogitsIndexFields"))]
+pub struct LogitsIndex {
+    /// The indices remaining after filtering
+    indices: Box<[usize]>,
+    weighted: WeightedIndex<f64>,
+}
+
+/// The serialized fields of [`LogitsIndex`], validated on deserialization.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "LogitsIndex")]
+struct LogitsIndexFields {
+    indices: Box<[usize]>,
+    weighted: WeightedIndex<f64>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<LogitsIndexFields> for LogitsIndex {
+    type Error = Error;
+
+    fn try_from(fields: LogitsIndexFields) -> Result<Self, Error> {
+        if fields.weighted.weights().count() != fields.indices.len() {
+            return Err(Error::InvalidInput);
+        }
+        Ok(LogitsIndex {
+            indices: fields.indices,
+            weighted: fields.weighted,
+        })
+    }
+}
+
+impl LogitsIndex {
+    /// Creates a new `LogitsIndex` [`Distribution`] from `logits`, filtered
+    /// according to `config`.
+    ///
+    /// Error cases:
+    /// -   [`Error::InvalidInput`] when `logits` is empty or a parameter of
+    ///     `config` is out of range.
+    /// -   [`Error::InvalidWeight`] when a logit is not-a-number or `+∞`.
+    /// -   [`Error::InsufficientNonZero`] when all logits are `-∞`.
+    pub fn new<X>(logits: &[X], config: LogitsConfig) -> Result<LogitsIndex, Error>
+    where
+        X: Copy + Into<f64>,
+    {
+        let LogitsConfig {
+            temperature,
+            top_k,
+            top_p,
+            min_p,
+        } = config;
+        if logits.is_empty()
+            || !(temperature >= 0.0 && temperature.is_finite())
+            || top_k == Some(0)
+            || !(top_p > 0.0 && top_p <= 1.0)
+            || !(0.0..=1.0).contains(&min_p)
+        {
+            return Err(Error::InvalidInput);
+        }
+
+        // Unmasked indices and their logits
+        let mut candidates: Vec<(usize, f64)> = Vec::with_capacity(logits.len());
+        for (i, &logit) in logits.iter().enumerate() {
+            let logit: f64 = logit.into();
+            if logit.is_nan() || logit == f64::INFINITY {
+                return Err(Error::InvalidWeight);
+            }
+            if logit > f64::NEG_INFINITY {
+                candidates.push((i, logit));
+            }
+        }
+        if candidates.is_empty() {
+            return Err(Error::InsufficientNonZero);
+        }
+
+        // Descending order of logits (and thus of probabilities)
+        let descending = |a: &(usize, f64), b: &(usize, f64)| b.1.total_cmp(&a.1);
+
+        if temperature == 0.0 {
+            let mut best = candidates[0];
+            for &c in &candidates[1..] {
+                if c.1 > best.1 {
+                    best = c;
+                }
+            }
+            candidates.clear();
+            candidates.push(best);
+        }
+
+        if let Some(k) = top_k {
+            if k < candidates.len() {
+                candidates.select_nth_unstable_by(k - 1, descending);
+                candidates.truncate(k);
+            }
+        }
+
+        // Stable softmax: the largest weight is exactly 1. Subtracting before
+        // dividing avoids overflow for small temperatures.
+        let max_logit = candidates
+            .iter()
+            .fold(f64::NEG_INFINITY, |max, c| max.max(c.1));
+        for c in candidates.iter_mut() {
+            c.1 = if temperature == 0.0 {
+                1.0
+            } else {
+                Float::exp((c.1 - max_logit) / temperature)
+            };
+        }
+
+        if min_p > 0.0 {
+            candidates.retain(|c| c.1 >= min_p);
+        }
+
+        if top_p < 1.0 {
+            top_p_filter(&mut candidates, top_p, descending);
+        }
+
+        let indices = candidates.iter().map(|c| c.0).collect();
+        let weighted = WeightedIndex::new(candidates.iter().map(|c| c.1))?;
+        Ok(LogitsIndex { indices, weighted })
+    }
+}
+
+/// Truncates `candidates` to the smallest set of largest weights whose sum
+/// is at least `top_p` times the total.
+///
+/// Candidates are sorted in chunks of doubling size, each selected from the
+/// remainder in linear time, so only the kept candidates (and fewer than as
+/// many again) are sorted.
+fn top_p_filter<F>(candidates: &mut Vec<(usize, f64)>, top_p: f64, descending: F)
+where
+    F: Fn(&(usize, f64), &(usize, f64)) -> core::cmp::Ordering + Copy,
+{
+    let total: f64 = candidates.iter().map(|c| c.1).sum();
+    let target = top_p * total;
+    let len = candidates.len();
+    let mut cumulative = 0.0;
+    let (mut sorted, mut chunk) = (0, 16);
+    while sorted < len {
+        let end = len.min(sorted + chunk);
+        if end < len {
+            candidates[sorted..].select_nth_unstable_by(end - sorted - 1, descending);
+        }
+        candidates[sorted..end].sort_unstable_by(descending);
+        for i in sorted..end {
+            cumulative += candidates[i].1;
+            if cumulative >= target {
+                candidates.truncate(i + 1);
+                return;
+            }
+        }
+        sorted = end;
+        chunk *= 2;
+    }
+}
+
+impl Distribution<usize> for LogitsIndex {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        self.indices[self.weighted.sample(rng)]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Softmax of `logits / temperature`, keeping only indices in `keep`
+    fn softmax(logits: &[f64], temperature: f64, keep: &[usize]) -> Vec<f64> {
+        let mut p: Vec<f64> = logits.iter().map(|l| (l / temperature).exp()).collect();
+        for (i, p) in p.iter_mut().enumerate() {
+            if !keep.contains(&i) {
+                *p = 0.0;
+            }
+        }
+        let sum: f64 = p.iter().sum();
+        p.iter().map(|p| p / sum).collect()
+    }
+
+    fn kept(distr: &LogitsIndex) -> Vec<usize> {
+        let mut indices = distr.indices.to_vec();
+        indices.sort_unstable();
+        indices
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_logits_index_serde() {
+        let distr = LogitsIndex::new(&[1.0, 2.0, 3.0], LogitsConfig::default()).unwrap();
+
+        let ser_distr = bincode::serialize(&distr).unwrap();
+        let de_distr: LogitsIndex = bincode::deserialize(&ser_distr).unwrap();
+
+        assert_eq!(de_distr, distr);
+
+        // The number of indices does not match the weights
+        #[derive(Serialize)]
+        struct Fields<'a> {
+            indices: &'a [usize],
+            weighted: WeightedIndex<f64>,
+        }
+        for indices in [&[0, 1][..], &[0, 1, 2, 3]] {
+            let fields = Fields {
+                indices,
+                weighted: distr.weighted.clone(),
+            };
+            let bytes = bincode::serialize(&fields).unwrap();
+            assert!(bincode::deserialize::<LogitsIndex>(&bytes).is_err());
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let config = LogitsConfig::default();
+        let empty: [f32; 0] = [];
+        assert_eq!(LogitsIndex::new(&empty, config), Err(Error::InvalidInput));
+        assert_eq!(
+            LogitsIndex::new(&[0.0, f64::NAN], config),
+            Err(Error::InvalidWeight)
+        );
+        assert_eq!(
+            LogitsIndex::new(&[f32::INFINITY], config),
+            Err(Error::InvalidWeight)
+        );
+        assert_eq!(
+            LogitsIndex::new(&[f32::NEG_INFINITY; 3], config),
+            Err(Error::InsufficientNonZero)
+        );
+
+        let invalid = [
+            LogitsConfig {
+                temperature: -1.0,
+                ..config
+            },
+            LogitsConfig {
+                temperature: f64::INFINITY,
+                ..config
+            },
+            LogitsConfig {
+                temperature: f64::NAN,
+                ..config
+            },
+            LogitsConfig {
+                top_k: Some(0),
+                ..config
+            },
+            LogitsConfig {
+                top_p: 0.0,
+                ..config
+            },
+            LogitsConfig {
+                top_p: 1.5,
+                ..config
+            },
+            LogitsConfig {
+                min_p: -0.1,
+                ..config
+            },
+            LogitsConfig {
+                min_p: f64::NAN,
+                ..config
+            },
+        ];
+        for config in invalid {
+            assert_eq!(
+                LogitsIndex::new(&[1.0], config),
+                Err(Error::InvalidInput),
+                "{:?}",
+                config
+            );
+        }
+    }
+
+    #[test]
+    fn test_filters() {
+        let logits = [0.0, 3.0, 1.0, f64::NEG_INFINITY, 2.0, 3.0, -1.0];
+        let config = LogitsConfig::default();
+        let distr = LogitsIndex::new(&logits, config).unwrap();
+        assert_eq!(kept(&distr), [0, 1, 2, 4, 5, 6]);
+
+        let top_k = LogitsConfig {
+            top_k: Some(3),
+            ..config
+        };
+        let distr = LogitsIndex::new(&logits, top_k).unwrap();
+        assert_eq!(kept(&distr), [1, 4, 5]);
+
+        // Probabilities relative to the largest are e^-1, e^-2, ...
+        let min_p = LogitsConfig {
+            min_p: 0.3,
+            ..config
+        };
+        let distr = LogitsIndex::new(&logits, min_p).unwrap();
+        assert_eq!(kept(&distr), [1, 4, 5]);
+
+        // At a low temperature, only the largest logits remain
+        let distr = LogitsIndex::new(
+            &logits,
+            LogitsConfig {
+                temperature: 0.5,
+                ..min_p
+            },
+        )
+        .unwrap();
+        assert_eq!(kept(&distr), [1, 5]);
+
+        // Probabilities are roughly 0.39, 0.39, 0.14, 0.05, ...
+        let top_p = LogitsConfig {
+            top_p: 0.8,
+            ..config
+        };
+        let distr = LogitsIndex::new(&logits, top_p).unwrap();
+        assert_eq!(kept(&distr), [1, 4, 5]);
+        let distr = LogitsIndex::new(
+            &logits,
+            LogitsConfig {
+                top_p: 0.1,
+                ..config
+            },
+        )
+        .unwrap();
+        assert_eq!(distr.indices.len(), 1);
+
+        let greedy = LogitsConfig {
+            temperature: 0.0,
+            ..config
+        };
+        let distr = LogitsIndex::new(&logits, greedy).unwrap();
+        assert_eq!(kept(&distr), [1]);
+        let mut rng = crate::test::rng(740);
+        for _ in 0..10 {
+            assert_eq!(distr.sample(&mut rng), 1);
+        }
+    }
+
+    #[test]
+    fn test_top_p_large() {
+        // Many candidates exercise the chunked selection; the kept set is
+        // the largest logits
+        let logits: Vec<f32> = (0..1000)
+            .map(|i| ((i * 7919) % 1000) as f32 / 100.0)
+            .collect();
+        let config = LogitsConfig {
+            top_p: 0.99,
+            ..Default::default()
+        };
+        let distr = LogitsIndex::new(&logits, config).unwrap();
+        let m = distr.indices.len();
+        assert!(100 < m && m < 1000);
+        let mut sorted = logits.clone();
+        sorted.sort_unstable_by(|a, b| b.total_cmp(a));
+        let threshold = sorted[m - 1];
+        let mut kept = kept(&distr);
+        kept.retain(|&i| logits[i] >= threshold);
+        assert_eq!(kept.len(), m);
+    }
+
+    #[test]
+    fn test_extreme_logits() {
+        let mut rng = crate::test::rng(741);
+        let distr = LogitsIndex::new(&[-1e300, 1e300, 0.0], LogitsConfig::default()).unwrap();
+        for _ in 0..10 {
+            assert_eq!(distr.sample(&mut rng), 1);
+        }
+
+        let config = LogitsConfig {
+            temperature: 1e-300,
+            ..Default::default()
+        };
+        let distr = LogitsIndex::new(&[1.0, 2.0, 1.5], config).unwrap();
+        for _ in 0..10 {
+            assert_eq!(distr.sample(&mut rng), 1);
+        }
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_gof() {
+        let logits = [0.5, -1.0, 2.0, 1.0, 0.0, 1.5, -0.5, 0.3];
+        let config = LogitsConfig::default();
+        let distr = LogitsIndex::new(&logits, config).unwrap();
+        let pmf = softmax(&logits, 1.0, &[0, 1, 2, 3, 4, 5, 6, 7]);
+        crate::test::check_discrete_gof(distr.map(|i| i as u64), 742, &pmf);
+
+        // Offsets do not change the distribution
+        let shifted = logits.map(|l| l - 1e4);
+        let distr = LogitsIndex::new(&shifted, config).unwrap();
+        crate::test::check_discrete_gof(distr.map(|i| i as u64), 743, &pmf);
+
+        let config = LogitsConfig {
+            temperature: 0.5,
+            top_k: Some(4),
+            ..config
+        };
+        let distr = LogitsIndex::new(&logits, config).unwrap();
+        let pmf = softmax(&logits, 0.5, &[0, 2, 3, 5]);
+        crate::test::check_discrete_gof(distr.map(|i| i as u64), 744, &pmf);
+    }
+}
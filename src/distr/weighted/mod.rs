@@ -9,15 +9,29 @@
 //! Weighted (index) sampling
 //!
 //! Primarily, this module houses the [`WeightedIndex`] distribution.
-//! See also [`rand_distr::weighted`] for alternative implementations supporting
-//! potentially-faster sampling or a more easily modifiable tree structure.
-//!
-//! [`rand_distr::weighted`]: https://docs.rs/rand_distr/latest/rand_distr/weighted/index.html
+//! [`WeightedAliasIndex`] supports faster (`O(1)`) sampling from a fixed set
+//! of weights, while [`WeightedTreeIndex`] supports adding, removing and
+//! updating weights in `O(log n)` time. [`WeightedLogIndex`] takes weights as
+//! logarithms, supporting weights too small or large to represent directly,
+//! and [`LogitsIndex`] samples from logits with temperature, top-k, min-p and
+//! top-p filtering.
 
 use core::fmt;
+#[cfg(feature = "distr_math")]
+mod logits_index;
+mod weighted_alias;
 mod weighted_index;
+#[cfg(feature = "distr_math")]
+mod weighted_log;
+mod weighted_tree;
 
+#[cfg(feature = "distr_math")]
+pub use logits_index::{LogitsConfig, LogitsIndex};
+pub use weighted_alias::{AliasableWeight, WeightedAliasIndex};
 pub use weighted_index::WeightedIndex;
+#[cfg(feature = "distr_math")]
+pub use weighted_log::WeightedLogIndex;
+pub use weighted_tree::WeightedTreeIndex;
 
 /// Bounds on a weight
 ///
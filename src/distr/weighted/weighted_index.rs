@@ -32,8 +32,8 @@ use serde::{Deserialize, Serialize};
 ///
 /// Time complexity of sampling from `WeightedIndex` is `O(log N)` where
 /// `N` is the number of weights.
-/// See also [`rand_distr::weighted`] for alternative implementations supporting
-/// potentially-faster sampling or a more easily modifiable tree structure.
+/// See also [`WeightedAliasIndex`] for `O(1)` sampling, and
+/// [`WeightedTreeIndex`] for a more easily modifiable tree structure.
 ///
 /// A `WeightedIndex<X>` contains a `Vec<X>` and a [`Uniform<X>`] and so its
 /// size is the sum of the size of those objects, possibly plus some alignment.
@@ -75,7 +75,8 @@ use serde::{Deserialize, Serialize};
 ///
 /// [`Uniform<X>`]: crate::distr::Uniform
 /// [`RngCore`]: crate::RngCore
-/// [`rand_distr::weighted`]: https://docs.rs/rand_distr/latest/rand_distr/weighted/index.html
+/// [`WeightedAliasIndex`]: super::WeightedAliasIndex
+/// [`WeightedTreeIndex`]: super::WeightedTreeIndex
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct WeightedIndex<X: SampleUniform + PartialOrd> {
@@ -154,8 +155,8 @@ impl<X: SampleUniform + PartialOrd> WeightedIndex<X> {
     ///     preferred.
     ///
     /// Updates take `O(N)` time. If you need to frequently update weights, consider
-    /// [`rand_distr::weighted_tree`](https://docs.rs/rand_distr/*/rand_distr/weighted_tree/index.html)
-    /// as an alternative where an update is `O(log N)`.
+    /// [`WeightedTreeIndex`](super::WeightedTreeIndex) as an alternative where
+    /// an update is `O(log N)`.
     pub fn update_weights(&mut self, new_weights: &[(usize, &X)]) -> Result<(), Error>
     where
         X: for<'a> core::ops::AddAssign<&'a X>
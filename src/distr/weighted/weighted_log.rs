@@ -0,0 +1,222 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Weighted index sampling with weights given in log-space.
+
+use super::{Error, Weight, WeightedIndex};
+use crate::distr::uniform::{SampleBorrow, SampleUniform};
+use crate::distr::Distribution;
+use crate::Rng;
+use alloc::vec::Vec;
+use core::fmt;
+use num_traits::Float;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A distribution using weighted sampling of discrete items, with weights
+/// given as natural logarithms.
+///
+/// Sampling a `WeightedLogIndex` returns index `i` with probability
+/// proportional to `exp(log_weights[i])`: the same distribution as a
+/// [`WeightedIndex`] over the weights `exp(log_weights[i])`. Log-weights may be
+/// any finite value (for example log-likelihoods, or logarithms of
+/// probabilities as small as `1e-300` and below); a log-weight of `-∞` is a
+/// weight of zero, and such elements are never picked.
+///
+/// Construction subtracts the largest log-weight before exponentiating, so
+/// the largest weight is `1` and no overflow or total underflow can occur.
+/// Elements whose weight is smaller than that of the largest by a factor
+/// beyond the range of `F` (about `e^-745` for `f64`) are never picked.
+///
+/// # Performance
+///
+/// Construction takes `O(N)` time and a call to [`Float::exp`] per weight;
+/// sampling takes `O(log N)` time as for [`WeightedIndex`].
+///
+/// # Example
+///
+/// ```
+/// use rand::prelude::*;
+/// use rand::distr::weighted::WeightedLogIndex;
+///
+/// let choices = ['a', 'b', 'c'];
+/// // Weights of e^-1000, 2 e^-1000 and e^-1000 would underflow to zero
+/// let log_weights = [-1000.0, -1000.0 + 2f64.ln(), -1000.0];
+/// let dist = WeightedLogIndex::new(&log_weights).unwrap();
+/// let mut rng = rand::rng();
+/// for _ in 0..100 {
+///     // 25% chance to print 'a', 50% chance to print 'b', 25% chance to print 'c'
+///     println!("{}", choices[dist.sample(&mut rng)]);
+/// }
+/// ```
+///
+/// [`Float::exp`]: num_traits::Float::exp
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "F: Serialize, F::Sampler: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "F: Deserialize<'de>, F::Sampler: Deserialize<'de>"))
+)]
+pub struct WeightedLogIndex<F: SampleUniform + PartialOrd> {
+    inner: WeightedIndex<F>,
+    max_log_weight: F,
+}
+
+impl<F: Float + SampleUniform + Weight> WeightedLogIndex<F> {
+    /// Creates a new `WeightedLogIndex` [`Distribution`] using the
+    /// log-weights `log_weights`.
+    ///
+    /// Error cases:
+    /// -   [`Error::InvalidInput`] when the iterator `log_weights` is empty.
+    /// -   [`Error::InvalidWeight`] when a log-weight is not-a-number or `+∞`.
+    /// -   [`Error::InsufficientNonZero`] when all log-weights are `-∞`.
+    pub fn new<I>(log_weights: I) -> Result<WeightedLogIndex<F>, Error>
+    where
+        I: IntoIterator,
+        I::Item: SampleBorrow<F>,
+    {
+        let log_weights: Vec<F> = log_weights.into_iter().map(|w| *w.borrow()).collect();
+        if log_weights.is_empty() {
+            return Err(Error::InvalidInput);
+        }
+
+        let mut max_log_weight = F::neg_infinity();
+        for &w in &log_weights {
+            if w.is_nan() || w == F::infinity() {
+                return Err(Error::InvalidWeight);
+            }
+            max_log_weight = max_log_weight.max(w);
+        }
+        if max_log_weight == F::neg_infinity() {
+            return Err(Error::InsufficientNonZero);
+        }
+
+        let weights = log_weights.iter().map(|&w| (w - max_log_weight).exp());
+        let inner = WeightedIndex::new(weights)?;
+        Ok(WeightedLogIndex {
+            inner,
+            max_log_weight,
+        })
+    }
+
+    /// Returns the natural logarithm of the sum of all weights.
+    pub fn total_log_weight(&self) -> F {
+        self.max_log_weight + self.inner.total_weight().ln()
+    }
+}
+
+impl<F: SampleUniform + PartialOrd> fmt::Debug for WeightedLogIndex<F>
+where
+    F: fmt::Debug,
+    WeightedIndex<F>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("WeightedLogIndex")
+            .field("inner", &self.inner)
+            .field("max_log_weight", &self.max_log_weight)
+            .finish()
+    }
+}
+
+impl<F: SampleUniform + PartialOrd + Clone> Clone for WeightedLogIndex<F>
+where
+    WeightedIndex<F>: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            max_log_weight: self.max_log_weight.clone(),
+        }
+    }
+}
+
+impl<F: SampleUniform + PartialOrd> PartialEq for WeightedLogIndex<F>
+where
+    WeightedIndex<F>: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.inner == other.inner && self.max_log_weight == other.max_log_weight
+    }
+}
+
+impl<F: SampleUniform + PartialOrd> Distribution<usize> for WeightedLogIndex<F> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        self.inner.sample(rng)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_weighted_log_index_serde() {
+        let distr = WeightedLogIndex::new([-1.0, -2.0, -3.0]).unwrap();
+
+        let ser_distr = bincode::serialize(&distr).unwrap();
+        let de_distr: WeightedLogIndex<f64> = bincode::deserialize(&ser_distr).unwrap();
+
+        assert_eq!(de_distr, distr);
+    }
+
+    #[test]
+    fn test_errors() {
+        let empty: [f64; 0] = [];
+        assert_eq!(WeightedLogIndex::new(empty), Err(Error::InvalidInput));
+        assert_eq!(
+            WeightedLogIndex::new([0.0, f64::NAN]),
+            Err(Error::InvalidWeight)
+        );
+        assert_eq!(
+            WeightedLogIndex::new([0.0, f32::INFINITY]),
+            Err(Error::InvalidWeight)
+        );
+        assert_eq!(
+            WeightedLogIndex::new([f64::NEG_INFINITY; 3]),
+            Err(Error::InsufficientNonZero)
+        );
+    }
+
+    #[test]
+    fn test_extreme_log_weights() {
+        let mut rng = crate::test::rng(730);
+
+        // Zero weights are never picked
+        let distr = WeightedLogIndex::new([f64::NEG_INFINITY, -1e300, f64::NEG_INFINITY]).unwrap();
+        for _ in 0..100 {
+            assert_eq!(distr.sample(&mut rng), 1);
+        }
+
+        // Negligible weights are never picked
+        let distr = WeightedLogIndex::new([1e10, 0.0, -1e300]).unwrap();
+        for _ in 0..100 {
+            assert_eq!(distr.sample(&mut rng), 0);
+        }
+        assert_eq!(distr.total_log_weight(), 1e10);
+
+        let distr = WeightedLogIndex::new([-1000.0f64; 4]).unwrap();
+        assert_almost_eq!(distr.total_log_weight(), -1000.0 + 4f64.ln(), 1e-12);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_gof() {
+        // The same distribution as `WeightedIndex` with weights 1..=10, for
+        // any offset of the log-weights
+        let pmf: [f64; 10] = core::array::from_fn(|i| (i + 1) as f64 / 55.0);
+        for (offset, seed) in [(0.0, 731), (-1e4, 732), (1e4, 733)] {
+            let log_weights: [f64; 10] = core::array::from_fn(|i| offset + ((i + 1) as f64).ln());
+            let distr = WeightedLogIndex::new(log_weights).unwrap();
+            crate::test::check_discrete_gof(distr.map(|i| i as u64), seed, &pmf);
+        }
+    }
+}
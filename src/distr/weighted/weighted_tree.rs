@@ -0,0 +1,482 @@
+// Copyright 2024 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! This module contains an implementation of a tree structure for sampling random
+//! indices with probabilities proportional to a collection of weights.
+
+use core::ops::SubAssign;
+
+use super::{Error, Weight};
+use crate::distr::uniform::{SampleBorrow, SampleUniform, UniformSampler};
+use crate::distr::Distribution;
+use crate::Rng;
+use alloc::vec::Vec;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A distribution using weighted sampling to pick a discretely selected item.
+///
+/// Sampling a [`WeightedTreeIndex<W>`] distribution returns the index of a randomly
+/// selected element from the vector used to create the [`WeightedTreeIndex<W>`].
+/// The chance of a given element being picked is proportional to the value of
+/// the element. The weights can have any type `W` for which an implementation of
+/// [`Weight`] exists.
+///
+/// # Key differences
+///
+/// The main distinction between [`WeightedTreeIndex<W>`] and [`WeightedIndex<W>`]
+/// lies in the internal representation of weights. In [`WeightedTreeIndex<W>`],
+/// weights are structured as a tree, which is optimized for frequent updates of
+/// the weights, and weights may be added or removed at the end.
+///
+/// As with [`WeightedIndex<W>`], elements with zero weight are never picked,
+/// even when the weights are floating point numbers. Unlike
+/// [`WeightedIndex<W>`], a tree may be empty or have only zero weights; use
+/// [`WeightedTreeIndex::is_valid`] or [`WeightedTreeIndex::try_sample`] to
+/// check whether it can be sampled.
+///
+/// # Floating point types
+///
+/// Subtotals are recomputed from the stored weights on each modification, so
+/// rounding errors do not accumulate when weights are updated many times.
+/// Sampling probabilities are nevertheless subject to the usual rounding of
+/// floating point sums.
+///
+/// # Performance
+///
+/// A [`WeightedTreeIndex<W>`] with `n` elements requires `O(n)` memory.
+///
+/// Time complexity for the operations of a [`WeightedTreeIndex<W>`] are:
+/// * Constructing: Building the initial tree from an iterator of weights takes `O(n)` time.
+/// * Sampling: Choosing an index (traversing down the tree) requires `O(log n)` time.
+/// * Weight Update: Modifying a weight (traversing up the tree), requires `O(log n)` time.
+/// * Weight Addition (Pushing): Adding a new weight (traversing up the tree), requires `O(log n)` time.
+/// * Weight Removal (Popping): Removing a weight (traversing up the tree), requires `O(log n)` time.
+///
+/// # Example
+///
+/// ```
+/// use rand::distr::weighted::WeightedTreeIndex;
+/// use rand::prelude::*;
+///
+/// let choices = vec!['a', 'b', 'c'];
+/// let weights = vec![2, 0];
+/// let mut dist = WeightedTreeIndex::new(&weights).unwrap();
+/// dist.push(1).unwrap();
+/// dist.update(1, 1).unwrap();
+/// assert_eq!(dist.total_weight(), 4);
+/// let mut rng = rand::rng();
+/// let mut samples = [0; 3];
+/// for _ in 0..100 {
+///     // 50% chance to print 'a', 25% chance to print 'b', 25% chance to print 'c'
+///     let i = dist.sample(&mut rng);
+///     samples[i] += 1;
+/// }
+/// println!("Results: {:?}", choices.iter().zip(samples.iter()).collect::<Vec<_>>());
+/// ```
+///
+/// [`WeightedTreeIndex<W>`]: WeightedTreeIndex
+/// [`WeightedIndex<W>`]: super::WeightedIndex
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct WeightedTreeIndex<
+    W: Clone + PartialEq + PartialOrd + SampleUniform + SubAssign<W> + Weight,
+> {
+    weights: Vec<W>,
+    /// The sum of the weights in the subtree rooted at each index, where the
+    /// children of index `i` are `2 i + 1` and `2 i + 2`
+    subtotals: Vec<W>,
+}
+
+impl<W: Clone + PartialEq + PartialOrd + SampleUniform + SubAssign<W> + Weight>
+    WeightedTreeIndex<W>
+{
+    /// Creates a new [`WeightedTreeIndex`] from a slice of weights.
+    ///
+    /// The weights may be empty or all zero, but the tree may then not be
+    /// sampled until a non-zero weight is added.
+    ///
+    /// Error cases:
+    /// -   [`Error::InvalidWeight`] when a weight is not-a-number, infinite or
+    ///     negative.
+    /// -   [`Error::Overflow`] when the sum of all weights overflows.
+    pub fn new<I>(weights: I) -> Result<Self, Error>
+    where
+        I: IntoIterator,
+        I::Item: SampleBorrow<W>,
+    {
+        let weights: Vec<W> = weights.into_iter().map(|x| x.borrow().clone()).collect();
+        if !weights.iter().all(is_valid_weight) {
+            return Err(Error::InvalidWeight);
+        }
+        let mut tree = Self {
+            subtotals: weights.clone(),
+            weights,
+        };
+        for i in (0..tree.len()).rev() {
+            tree.subtotals[i] = tree.sum_children(i)?;
+        }
+        Ok(tree)
+    }
+
+    /// Returns `true` if the tree contains no weights.
+    pub fn is_empty(&self) -> bool {
+        self.weights.is_empty()
+    }
+
+    /// Returns the number of weights.
+    pub fn len(&self) -> usize {
+        self.weights.len()
+    }
+
+    /// Returns `true` if we can sample.
+    ///
+    /// This is the case if the total weight of the tree is greater than zero.
+    pub fn is_valid(&self) -> bool {
+        self.total_weight() > W::ZERO
+    }
+
+    /// Returns the weight at the given index, if it exists.
+    ///
+    /// If the index is out of bounds, this will return `None`.
+    pub fn weight(&self, index: usize) -> Option<W> {
+        self.weights.get(index).cloned()
+    }
+
+    /// Returns the current weights of this distribution.
+    pub fn weights(&self) -> &[W] {
+        &self.weights
+    }
+
+    /// Returns the sum of all weights in this distribution.
+    ///
+    /// This is zero if the tree is empty.
+    pub fn total_weight(&self) -> W {
+        self.subtotal(0)
+    }
+
+    /// Removes the last weight and returns it, or [`None`] if it is empty.
+    pub fn pop(&mut self) -> Option<W> {
+        let weight = self.weights.pop()?;
+        self.subtotals.pop();
+        let index = self.len();
+        if index != 0 {
+            // Sums only decrease, so cannot overflow
+            self.update_ancestors((index - 1) / 2).unwrap();
+        }
+        Some(weight)
+    }
+
+    /// Appends a new weight at the end.
+    ///
+    /// Error cases:
+    /// -   [`Error::InvalidWeight`] when a weight is not-a-number, infinite or
+    ///     negative.
+    /// -   [`Error::Overflow`] when the sum of all weights overflows.
+    pub fn push(&mut self, weight: W) -> Result<(), Error> {
+        if !is_valid_weight(&weight) {
+            return Err(Error::InvalidWeight);
+        }
+        let index = self.len();
+        self.weights.push(weight.clone());
+        self.subtotals.push(weight);
+        if let Err(err) = self.update_ancestors(index) {
+            self.pop();
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    /// Updates the weight at an index.
+    ///
+    /// Error cases:
+    /// -   [`Error::InvalidInput`] when `index` is out of bounds.
+    /// -   [`Error::InvalidWeight`] when a weight is not-a-number, infinite or
+    ///     negative.
+    /// -   [`Error::Overflow`] when the sum of all weights overflows.
+    pub fn update(&mut self, index: usize, weight: W) -> Result<(), Error> {
+        if index >= self.len() {
+            return Err(Error::InvalidInput);
+        }
+        if !is_valid_weight(&weight) {
+            return Err(Error::InvalidWeight);
+        }
+        let old_weight = core::mem::replace(&mut self.weights[index], weight);
+        if let Err(err) = self.update_ancestors(index) {
+            // Restore the previous (valid) state
+            self.weights[index] = old_weight;
+            self.update_ancestors(index).unwrap();
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    fn subtotal(&self, index: usize) -> W {
+        if index < self.subtotals.len() {
+            self.subtotals[index].clone()
+        } else {
+            W::ZERO
+        }
+    }
+
+    /// Computes the subtotal at `index` from its weight and its children.
+    fn sum_children(&self, index: usize) -> Result<W, Error> {
+        let mut sum = self.weights[index].clone();
+        for child in [2 * index + 1, 2 * index + 2] {
+            sum.checked_add_assign(&self.subtotal(child))
+                .map_err(|()| Error::Overflow)?;
+        }
+        // Floating point sums overflow to infinity
+        if !is_finite(&sum) {
+            return Err(Error::Overflow);
+        }
+        Ok(sum)
+    }
+
+    /// Recomputes the subtotals of `index` and all its ancestors.
+    ///
+    /// On error, subtotals along the path may be inconsistent.
+    fn update_ancestors(&mut self, mut index: usize) -> Result<(), Error> {
+        loop {
+            self.subtotals[index] = self.sum_children(index)?;
+            if index == 0 {
+                return Ok(());
+            }
+            index = (index - 1) / 2;
+        }
+    }
+}
+
+/// Returns `true` if `weight` is finite (always the case for integers).
+///
+/// Only a finite value minus itself is zero: infinity gives NaN.
+fn is_finite<W: Clone + PartialEq + SubAssign<W> + Weight>(weight: &W) -> bool {
+    let mut diff = weight.clone();
+    diff -= weight.clone();
+    diff == W::ZERO
+}
+
+/// Returns `true` if `weight` is non-negative and finite.
+fn is_valid_weight<W: Clone + PartialOrd + SubAssign<W> + Weight>(weight: &W) -> bool {
+    *weight >= W::ZERO && is_finite(weight)
+}
+
+impl<W: Clone + PartialEq + PartialOrd + SampleUniform + SubAssign<W> + Weight>
+    WeightedTreeIndex<W>
+{
+    /// Samples a randomly selected index from the weighted distribution.
+    ///
+    /// Returns an error if there are no elements or all weights are zero. This
+    /// is unlike [`Distribution::sample`], which panics in those cases.
+    pub fn try_sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<usize, Error> {
+        let total_weight = self.total_weight();
+        if !(total_weight > W::ZERO) {
+            return Err(Error::InsufficientNonZero);
+        }
+        let mut target_weight = W::Sampler::sample_single(W::ZERO, total_weight, rng)
+            .map_err(|_| Error::InvalidWeight)?;
+        // Invariant: the subtotal at `index` is positive
+        let mut index = 0;
+        loop {
+            // Maybe pick this index.
+            let weight = &self.weights[index];
+            if target_weight < *weight {
+                return Ok(index);
+            }
+            target_weight -= weight.clone();
+
+            // Maybe descend into the left sub tree.
+            let left_index = 2 * index + 1;
+            let left_subtotal = self.subtotal(left_index);
+            if target_weight < left_subtotal {
+                index = left_index;
+                continue;
+            }
+            target_weight -= left_subtotal.clone();
+
+            // Otherwise descend into the right sub tree, or (if rounding
+            // took us past the end) the last other non-empty choice.
+            let right_index = 2 * index + 2;
+            if self.subtotal(right_index) > W::ZERO {
+                index = right_index;
+            } else if left_subtotal > W::ZERO {
+                index = left_index;
+            } else {
+                return Ok(index);
+            }
+        }
+    }
+}
+
+/// Samples a randomly selected index from the weighted distribution.
+///
+/// Caution: This method panics if there are no elements or all weights are zero. However,
+/// it is guaranteed that this method will not panic if a call to [`WeightedTreeIndex::is_valid`]
+/// returns `true`.
+impl<W: Clone + PartialEq + PartialOrd + SampleUniform + SubAssign<W> + Weight> Distribution<usize>
+    for WeightedTreeIndex<W>
+{
+    #[track_caller]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        self.try_sample(rng).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_weighted_tree_index_serde() {
+        let tree = WeightedTreeIndex::new([1, 2, 3, 4, 5, 6, 7, 8, 9, 10]).unwrap();
+
+        let ser_tree = bincode::serialize(&tree).unwrap();
+        let de_tree: WeightedTreeIndex<i32> = bincode::deserialize(&ser_tree).unwrap();
+
+        assert_eq!(de_tree, tree);
+    }
+
+    #[test]
+    fn test_no_item_error() {
+        let mut rng = crate::test::rng(0x9c9fa0b0580a7031);
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        let tree = WeightedTreeIndex::<f64>::new(&[]).unwrap();
+        assert_eq!(
+            tree.try_sample(&mut rng).unwrap_err(),
+            Error::InsufficientNonZero
+        );
+        assert!(!tree.is_valid());
+        assert_eq!(tree.total_weight(), 0.0);
+    }
+
+    #[test]
+    fn test_overflow_error() {
+        assert_eq!(WeightedTreeIndex::new([i32::MAX, 2]), Err(Error::Overflow));
+        let mut tree = WeightedTreeIndex::new([i32::MAX - 2, 1]).unwrap();
+        let expected = tree.clone();
+        assert_eq!(tree.push(3), Err(Error::Overflow));
+        assert_eq!(tree, expected);
+        assert_eq!(tree.update(1, 4), Err(Error::Overflow));
+        assert_eq!(tree, expected);
+        tree.update(1, 2).unwrap();
+        assert_eq!(tree.total_weight(), i32::MAX);
+    }
+
+    #[test]
+    fn test_non_finite_error() {
+        assert_eq!(
+            WeightedTreeIndex::new([1.0, f64::INFINITY]),
+            Err(Error::InvalidWeight)
+        );
+        assert_eq!(
+            WeightedTreeIndex::new([f64::MAX, f64::MAX]),
+            Err(Error::Overflow)
+        );
+
+        let mut tree = WeightedTreeIndex::new([f64::MAX, 1.0]).unwrap();
+        let expected = tree.clone();
+        assert_eq!(tree.push(f64::INFINITY), Err(Error::InvalidWeight));
+        assert_eq!(tree.push(f64::MAX), Err(Error::Overflow));
+        assert_eq!(tree, expected);
+        assert_eq!(tree.update(1, f64::INFINITY), Err(Error::InvalidWeight));
+        assert_eq!(tree.update(1, f64::MAX), Err(Error::Overflow));
+        assert_eq!(tree, expected);
+
+        assert!(tree.is_valid());
+        let mut rng = crate::test::rng(0x9c9fa0b0580a7031);
+        for _ in 0..100 {
+            assert!(tree.try_sample(&mut rng).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_all_weights_zero_error() {
+        let tree = WeightedTreeIndex::<f64>::new([0.0, 0.0]).unwrap();
+        let mut rng = crate::test::rng(0x9c9fa0b0580a7031);
+        assert_eq!(
+            tree.try_sample(&mut rng).unwrap_err(),
+            Error::InsufficientNonZero
+        );
+    }
+
+    #[test]
+    fn test_invalid_weight_error() {
+        assert_eq!(
+            WeightedTreeIndex::<i32>::new([1, -1]).unwrap_err(),
+            Error::InvalidWeight
+        );
+        assert_eq!(
+            WeightedTreeIndex::<f32>::new([1.0, f32::NAN]).unwrap_err(),
+            Error::InvalidWeight
+        );
+        #[allow(clippy::needless_borrows_for_generic_args)]
+        let mut tree = WeightedTreeIndex::<i32>::new(&[]).unwrap();
+        assert_eq!(tree.push(-1).unwrap_err(), Error::InvalidWeight);
+        tree.push(1).unwrap();
+        assert_eq!(tree.update(0, -1).unwrap_err(), Error::InvalidWeight);
+        assert_eq!(tree.update(1, 1).unwrap_err(), Error::InvalidInput);
+    }
+
+    #[test]
+    fn test_tree_modifications() {
+        let mut tree = WeightedTreeIndex::new([9, 1, 2]).unwrap();
+        tree.push(3).unwrap();
+        tree.push(5).unwrap();
+        tree.update(0, 0).unwrap();
+        assert_eq!(tree.pop(), Some(5));
+        let expected = WeightedTreeIndex::new([0, 1, 2, 3]).unwrap();
+        assert_eq!(tree, expected);
+        assert_eq!(tree.weights(), &[0, 1, 2, 3]);
+        assert_eq!(tree.weight(3), Some(3));
+        assert_eq!(tree.weight(4), None);
+        assert_eq!(tree.total_weight(), 6);
+
+        while tree.pop().is_some() {}
+        assert!(tree.is_empty());
+        assert_eq!(tree.total_weight(), 0);
+    }
+
+    #[test]
+    fn test_zero_weights_never_sampled() {
+        let mut rng = crate::test::rng(0x9c9fa0b0580a7031);
+        let mut tree = WeightedTreeIndex::new([0.1f64; 100]).unwrap();
+        for i in 0..1000 {
+            tree.update(i % 100, 0.1 + (i as f64) * 1e-3).unwrap();
+        }
+        for i in (0..100).filter(|i| i % 3 != 0) {
+            tree.update(i, 0.0).unwrap();
+        }
+        for _ in 0..1000 {
+            assert_eq!(tree.sample(&mut rng) % 3, 0);
+        }
+
+        // After zeroing all weights, the total is exactly zero
+        for i in 0..100 {
+            tree.update(i, 0.0).unwrap();
+        }
+        assert_eq!(tree.total_weight(), 0.0);
+        assert!(!tree.is_valid());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_gof() {
+        let mut tree = WeightedTreeIndex::new([5u32; 20]).unwrap();
+        for i in 0..20 {
+            tree.update(i, (i * i % 7) as u32).unwrap();
+        }
+        for i in 0..10 {
+            tree.push(i).unwrap();
+        }
+        tree.pop();
+        let total = tree.total_weight() as f64;
+        let pmf: Vec<f64> = tree.weights().iter().map(|&w| w as f64 / total).collect();
+        crate::test::check_discrete_gof(tree.map(|i| i as u64), 720, &pmf);
+    }
+}
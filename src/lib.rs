@@ -96,6 +96,27 @@ macro_rules! error { ($($x:tt)*) => (
     }
 ) }
 
+/// Assert that two numbers are almost equal to each other.
+///
+/// On panic, this macro will print the values of the expressions with their
+/// debug representations.
+#[cfg(test)]
+#[allow(unused)]
+macro_rules! assert_almost_eq {
+    ($a:expr, $b:expr, $prec:expr) => {
+        let diff = ($a - $b).abs();
+        assert!(
+            diff <= $prec,
+            "assertion failed: `abs(left - right) = {:.1e} < {:e}`, \
+                (left: `{}`, right: `{}`)",
+            diff,
+            $prec,
+            $a,
+            $b
+        );
+    };
+}
+
 // Re-export rand_core itself
 pub use rand_core;
 
@@ -108,6 +129,7 @@ pub mod prelude;
 mod rng;
 pub mod rngs;
 pub mod seq;
+pub mod stable;
 
 // Public exports
 #[cfg(feature = "thread_rng")]
@@ -308,6 +330,184 @@ mod test {
         rand_pcg::Pcg32::new(seed, INC)
     }
 
+    /// Pearson's chi-squared goodness-of-fit test for a discrete distribution
+    ///
+    /// `pmf[k]` is the expected probability of sampling `k`; any remaining
+    /// probability mass is expected beyond the end of `pmf`. Adjacent
+    /// outcomes are grouped such that each group has an expected count of at
+    /// least 5. The test fails at significance level 0.001.
+    #[allow(unused)]
+    #[track_caller]
+    pub fn check_discrete_gof<D: crate::distr::Distribution<u64>>(
+        distr: D,
+        seed: u64,
+        pmf: &[f64],
+    ) {
+        const N: u64 = 100_000;
+        const MAX_LEN: usize = 1024;
+        assert!(pmf.len() <= MAX_LEN);
+
+        let mut rng = self::rng(seed);
+        let mut counts = [0u64; MAX_LEN + 1];
+        for _ in 0..N {
+            let x = distr.sample(&mut rng);
+            counts[core::cmp::min(x, pmf.len() as u64) as usize] += 1;
+        }
+
+        let n = N as f64;
+        let tail = (1.0 - pmf.iter().sum::<f64>()).max(0.0);
+        let expected = pmf.iter().chain(core::iter::once(&tail));
+
+        // Group outcomes, delaying each completed group by one step such that
+        // a small final group may be merged into the previous one.
+        let (mut chi2, mut groups) = (0.0, 0);
+        let mut add = |(o, e): (f64, f64)| {
+            chi2 += (o - e) * (o - e) / e;
+            groups += 1;
+        };
+        let mut prev: Option<(f64, f64)> = None;
+        let (mut o, mut e) = (0.0, 0.0);
+        for (&count, &p) in counts.iter().zip(expected) {
+            o += count as f64;
+            e += p * n;
+            if e >= 5.0 {
+                if let Some(group) = prev {
+                    add(group);
+                }
+                prev = Some((o, e));
+                (o, e) = (0.0, 0.0);
+            }
+        }
+        match prev {
+            Some((po, pe)) if e < 5.0 => add((po + o, pe + e)),
+            Some(group) => {
+                add(group);
+                add((o, e));
+            }
+            None => panic!("expected counts too small"),
+        }
+
+        // Critical value at p = 0.001 (Wilson-Hilferty approximation)
+        let df = (groups - 1) as f64;
+        let z = 3.09;
+        let a = 2.0 / (9.0 * df);
+        let crit = df * (1.0 - a + z * a.sqrt()).powi(3);
+        assert!(
+            chi2 < crit,
+            "chi-squared statistic {} exceeds critical value {} ({} groups)",
+            chi2,
+            crit,
+            groups
+        );
+    }
+
+    /// Complementary error function, with absolute error below 1.2e-7
+    /// (Numerical Recipes, section 6.2)
+    #[allow(unused)]
+    pub fn erfc(x: f64) -> f64 {
+        let t = 1.0 / (1.0 + 0.5 * x.abs());
+        let poly = [
+            -1.26551223,
+            1.00002368,
+            0.37409196,
+            0.09678418,
+            -0.18628806,
+            0.27886807,
+            -1.13520398,
+            1.48851587,
+            -0.82215223,
+            0.17087277,
+        ];
+        let y = t * (-x * x + poly.iter().rev().fold(0.0, |acc, c| acc * t + c)).exp();
+        if x >= 0.0 {
+            y
+        } else {
+            2.0 - y
+        }
+    }
+
+    /// Check samples of a continuous distribution against its CDF
+    ///
+    /// At each point `x` in `xs`, the fraction of samples not exceeding `x`
+    /// must be within five standard errors of `cdf(x)`. Points far in the
+    /// tails thus check that tail probabilities are not grossly wrong.
+    #[allow(unused)]
+    #[track_caller]
+    pub fn check_cdf<D: crate::distr::Distribution<f64>>(
+        distr: D,
+        seed: u64,
+        cdf: impl Fn(f64) -> f64,
+        xs: &[f64],
+    ) {
+        const N: usize = 100_000;
+        const MAX_LEN: usize = 16;
+        assert!(xs.len() <= MAX_LEN);
+
+        let mut rng = self::rng(seed);
+        let mut counts = [0usize; MAX_LEN];
+        for _ in 0..N {
+            let x = distr.sample(&mut rng);
+            for (count, &p) in counts.iter_mut().zip(xs) {
+                if x <= p {
+                    *count += 1;
+                }
+            }
+        }
+
+        let n = N as f64;
+        for (&count, &x) in counts.iter().zip(xs) {
+            let expected = cdf(x);
+            let observed = count as f64 / n;
+            let std_err = (expected * (1.0 - expected) / n).sqrt();
+            assert!(
+                (observed - expected).abs() <= 5.0 * std_err + 1.0 / n,
+                "at x = {}: observed CDF {} but expected {}",
+                x,
+                observed,
+                expected
+            );
+        }
+    }
+
+    /// Assert that the sample mean and variance of `distr` match the expected
+    /// moments, within `rel_tol` relative to the standard deviation
+    #[allow(unused)]
+    #[track_caller]
+    pub fn check_moments<D: crate::distr::Distribution<f64>>(
+        distr: D,
+        seed: u64,
+        mean: f64,
+        variance: f64,
+        rel_tol: f64,
+    ) {
+        const N: usize = 100_000;
+        let mut rng = self::rng(seed);
+
+        // Welford's online algorithm
+        let (mut m, mut s) = (0.0, 0.0);
+        for i in 0..N {
+            let x = distr.sample(&mut rng);
+            let delta = x - m;
+            m += delta / (i + 1) as f64;
+            s += delta * (x - m);
+        }
+        let var = s / (N - 1) as f64;
+
+        let std_dev = variance.sqrt();
+        assert!(
+            (m - mean).abs() <= rel_tol * std_dev,
+            "sample mean {} != expected {}",
+            m,
+            mean
+        );
+        assert!(
+            (var - variance).abs() <= rel_tol * variance,
+            "sample variance {} != expected {}",
+            var,
+            variance
+        );
+    }
+
     #[test]
     #[cfg(feature = "thread_rng")]
     fn test_random() {
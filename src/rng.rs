@@ -167,6 +167,37 @@ pub trait Rng: RngCore {
         range.sample_single(self).unwrap()
     }
 
+    /// Generate a random value in the given range, without bias.
+    ///
+    /// This is like [`Rng::random_range`], but always uses an unbiased
+    /// algorithm for integer types, as if the `unbiased` feature flag were
+    /// enabled (without affecting other crates). For the provided types,
+    /// results are identical to those of [`Rng::random_range`] when the
+    /// `unbiased` feature flag is enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty, or if `high - low` overflows for floats.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::Rng;
+    ///
+    /// let mut rng = rand::rng();
+    /// let n: u64 = rng.random_range_unbiased(..1_000_000_007);
+    /// println!("{}", n);
+    /// ```
+    #[track_caller]
+    fn random_range_unbiased<T, R>(&mut self, range: R) -> T
+    where
+        T: SampleUniform,
+        R: SampleRange<T>,
+    {
+        assert!(!range.is_empty(), "cannot sample empty range");
+        range.sample_single_unbiased(self).unwrap()
+    }
+
     /// Return a bool with a probability `p` of being true.
     ///
     /// See also the [`Bernoulli`] distribution, which may be faster if
@@ -315,6 +346,43 @@ pub trait Rng: RngCore {
         dest.fill(self)
     }
 
+    /// Append `len` random bytes to `vec`
+    ///
+    /// The new bytes are written directly into the spare capacity of `vec`
+    /// using [`RngCore::fill_uninit`], avoiding the cost of zero-initializing
+    /// them first where the generator supports this.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the generator's [`RngCore::fill_uninit`] does not return the
+    /// slice it was given.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rand::Rng;
+    ///
+    /// let mut buf = Vec::new();
+    /// rand::rng().append_bytes(&mut buf, 32);
+    /// assert_eq!(buf.len(), 32);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn append_bytes(&mut self, vec: &mut alloc::vec::Vec<u8>, len: usize) {
+        vec.reserve(len);
+        let spare = &mut vec.spare_capacity_mut()[..len];
+        let ptr = spare.as_ptr() as *const u8;
+        let filled = self.fill_uninit(spare);
+        // `fill_uninit` is safe to implement, so it may return any slice:
+        // only trust it if the result covers exactly the spare capacity.
+        assert!(
+            core::ptr::eq(filled.as_ptr(), ptr) && filled.len() == len,
+            "fill_uninit did not return the initialized destination"
+        );
+        // SAFETY: the first `len` bytes of the spare capacity, which `reserve`
+        // guarantees to exist, are initialized since `filled` refers to them.
+        unsafe { vec.set_len(vec.len() + len) };
+    }
+
     /// Alias for [`Rng::random`].
     #[inline]
     #[deprecated(
@@ -589,6 +657,45 @@ mod test {
         let _c: u8 = StandardUniform.sample(&mut r);
     }
 
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_append_bytes() {
+        let mut r = StepRng::new(0x11_22_33_44_55_66_77_88, 0);
+        let mut v = alloc::vec![0xffu8; 3];
+        r.append_bytes(&mut v, 13);
+        assert_eq!(v.len(), 16);
+        assert_eq!(&v[..3], &[0xff; 3]);
+        assert_eq!(&v[3..11], &0x11_22_33_44_55_66_77_88u64.to_le_bytes());
+        r.append_bytes(&mut v, 0);
+        assert_eq!(v.len(), 16);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[should_panic]
+    fn test_append_bytes_bad_fill_uninit() {
+        use core::mem::MaybeUninit;
+
+        struct BadRng(StepRng);
+        impl RngCore for BadRng {
+            fn next_u32(&mut self) -> u32 {
+                self.0.next_u32()
+            }
+            fn next_u64(&mut self) -> u64 {
+                self.0.next_u64()
+            }
+            fn fill_bytes(&mut self, dst: &mut [u8]) {
+                self.0.fill_bytes(dst)
+            }
+            fn fill_uninit<'a>(&mut self, _: &'a mut [MaybeUninit<u8>]) -> &'a mut [u8] {
+                &mut []
+            }
+        }
+
+        let mut v = alloc::vec::Vec::new();
+        BadRng(StepRng::new(0, 1)).append_bytes(&mut v, 8);
+    }
+
     #[test]
     #[cfg(feature = "alloc")]
     fn test_rng_boxed_trait() {
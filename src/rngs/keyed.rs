@@ -0,0 +1,418 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! A stateless keyed random function
+
+use crate::distr::{Distribution, StandardUniform};
+use core::fmt;
+use core::mem::MaybeUninit;
+use rand_core::block::{BlockRng64, BlockRngCore};
+use rand_core::le::read_u64_into;
+use rand_core::{RngCore, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+// Philox4x64 multipliers and Weyl sequence increments ("key bumps")
+const MULTIPLIERS: [u64; 2] = [0xD2E7_470E_E14C_6C93, 0xCA5A_8263_9512_1157];
+const BUMPS: [u64; 2] = [0x9E37_79B9_7F4A_7C15, 0xBB67_AE85_84CA_A73B];
+const ROUNDS: usize = 10;
+
+/// The Philox4x64-10 block function of Salmon et al.
+///
+/// This is a copy of the block function of `rand_random123::Philox4x64Core`,
+/// kept here so that `rand` does not depend on that crate. The two must
+/// agree, which a test checks.
+#[inline]
+fn philox4x64(mut key: [u64; 2], mut ctr: [u64; 4]) -> [u64; 4] {
+    for round in 0..ROUNDS {
+        if round > 0 {
+            key[0] = key[0].wrapping_add(BUMPS[0]);
+            key[1] = key[1].wrapping_add(BUMPS[1]);
+        }
+        let p0 = u128::from(MULTIPLIERS[0]) * u128::from(ctr[0]);
+        let p1 = u128::from(MULTIPLIERS[1]) * u128::from(ctr[2]);
+        ctr = [
+            (p1 >> 64) as u64 ^ ctr[1] ^ key[0],
+            p1 as u64,
+            (p0 >> 64) as u64 ^ ctr[3] ^ key[1],
+            p0 as u64,
+        ];
+    }
+    ctr
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Types usable as the index (coordinates) of a [`KeyedRandom`]
+///
+/// This trait is sealed: it is implemented for primitive integer types, for
+/// homogeneous tuples of two or three integers, and for integer arrays of
+/// length one to three.
+/// Signed integers are converted by sign-extension to `i64` and then
+/// reinterpreted as `u64`; unsigned integers are zero-extended.
+///
+/// The number of dimensions [`Self::DIM`] is part of the index: `5`, `(5, 0)`
+/// and `(5, 0, 0)` are distinct indices with unrelated values.
+pub trait Coordinates: private::Sealed {
+    /// The number of coordinates, in the range `1..=3`
+    const DIM: usize;
+
+    /// Convert to coordinate words
+    ///
+    /// Only the first [`Self::DIM`] words are used; others must be zero.
+    fn to_words(&self) -> [u64; 3];
+}
+
+macro_rules! impl_coordinates {
+    ($($ty:ty => $wide:ty),*) => {$(
+        impl private::Sealed for $ty {}
+        impl Coordinates for $ty {
+            const DIM: usize = 1;
+            #[inline]
+            fn to_words(&self) -> [u64; 3] {
+                [*self as $wide as u64, 0, 0]
+            }
+        }
+
+        impl private::Sealed for ($ty, $ty) {}
+        impl Coordinates for ($ty, $ty) {
+            const DIM: usize = 2;
+            #[inline]
+            fn to_words(&self) -> [u64; 3] {
+                [self.0 as $wide as u64, self.1 as $wide as u64, 0]
+            }
+        }
+
+        impl private::Sealed for ($ty, $ty, $ty) {}
+        impl Coordinates for ($ty, $ty, $ty) {
+            const DIM: usize = 3;
+            #[inline]
+            fn to_words(&self) -> [u64; 3] {
+                [
+                    self.0 as $wide as u64,
+                    self.1 as $wide as u64,
+                    self.2 as $wide as u64,
+                ]
+            }
+        }
+
+        impl private::Sealed for [$ty; 1] {}
+        impl Coordinates for [$ty; 1] {
+            const DIM: usize = 1;
+            #[inline]
+            fn to_words(&self) -> [u64; 3] {
+                self[0].to_words()
+            }
+        }
+
+        impl private::Sealed for [$ty; 2] {}
+        impl Coordinates for [$ty; 2] {
+            const DIM: usize = 2;
+            #[inline]
+            fn to_words(&self) -> [u64; 3] {
+                (self[0], self[1]).to_words()
+            }
+        }
+
+        impl private::Sealed for [$ty; 3] {}
+        impl Coordinates for [$ty; 3] {
+            const DIM: usize = 3;
+            #[inline]
+            fn to_words(&self) -> [u64; 3] {
+                (self[0], self[1], self[2]).to_words()
+            }
+        }
+    )*}
+}
+
+impl_coordinates!(
+    u8 => u64, u16 => u64, u32 => u64, u64 => u64, usize => u64,
+    i8 => i64, i16 => i64, i32 => i64, i64 => i64, isize => i64
+);
+
+/// A stateless keyed random function
+///
+/// This maps a key and an index, which may be an integer or a tuple of up to
+/// three integer coordinates (see [`Coordinates`]), to a random value. There
+/// is no mutable state: the value at an index is a pure function of the key
+/// and the index, and may be computed in any order, on any thread. This is
+/// useful for procedural generation, for bucketing by identifier, and for
+/// parallel sampling where results must not depend on scheduling.
+///
+/// Unlike most generators in this module, the output of
+/// [`KeyedRandom::u64_at`] and of the generators returned by
+/// [`KeyedRandom::rng_at`] is [portable] and value-stable: it will not change
+/// across platforms or releases (other than as a breaking change).
+/// [`KeyedRandom::sample_at`] and [`KeyedRandom::random_at`] pass these
+/// generators to [`Distribution`] implementations, whose results are only as
+/// stable as the distribution used (see [`crate::stable`] for frozen
+/// implementations).
+///
+/// # Algorithm
+///
+/// Values are computed with the Philox4x64-10 block function of Salmon et
+/// al., ["Parallel Random Numbers: As Easy as 1, 2, 3"][philox] (SC 2011),
+/// using the 128-bit key. The 256-bit counter comprises the three coordinate
+/// words ([`Coordinates::to_words`]) followed by the word
+/// `(block << 2) | DIM`, where `block` is the block number in the
+/// [`KeyedRng`] stream at that index (zero for [`KeyedRandom::u64_at`]).
+///
+/// This is not a cryptographic function.
+///
+/// # Example
+///
+/// ```
+/// use rand::rngs::KeyedRandom;
+/// use rand::distr::Uniform;
+///
+/// let terrain = KeyedRandom::new([0x5eed, 0]);
+/// let height = Uniform::new(0, 100).unwrap();
+/// let h1: i32 = terrain.sample_at((3, -7), height);
+/// // The same index always yields the same value
+/// assert_eq!(h1, terrain.sample_at((3, -7), height));
+///
+/// let bucket = terrain.u64_at(12345u64) % 16;
+/// # let _ = bucket;
+/// ```
+///
+/// [portable]: https://rust-random.github.io/book/crate-reprod.html
+/// [philox]: https://doi.org/10.1145/2063384.2063405
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct KeyedRandom {
+    key: [u64; 2],
+}
+
+impl KeyedRandom {
+    /// Construct with the given key
+    #[inline]
+    pub fn new(key: [u64; 2]) -> Self {
+        KeyedRandom { key }
+    }
+
+    /// Get a uniformly distributed `u64` value at index `at`
+    ///
+    /// This is the first output of [`KeyedRandom::rng_at`].
+    #[inline]
+    pub fn u64_at<C: Coordinates>(&self, at: C) -> u64 {
+        self.block(&at, 0)[0]
+    }
+
+    /// Sample a value from `distr` at index `at`
+    ///
+    /// This is equivalent to `distr.sample(&mut self.rng_at(at))`.
+    #[inline]
+    pub fn sample_at<C: Coordinates, T, D: Distribution<T>>(&self, at: C, distr: D) -> T {
+        distr.sample(&mut self.rng_at(at))
+    }
+
+    /// Get a random value of type `T` at index `at`
+    ///
+    /// This is equivalent to `self.sample_at(at, StandardUniform)`.
+    #[inline]
+    pub fn random_at<C: Coordinates, T>(&self, at: C) -> T
+    where
+        StandardUniform: Distribution<T>,
+    {
+        self.sample_at(at, StandardUniform)
+    }
+
+    /// Get a generator for the stream of random values at index `at`
+    ///
+    /// The returned generator yields an effectively unbounded stream of
+    /// values which is a pure function of the key and `at`.
+    #[inline]
+    pub fn rng_at<C: Coordinates>(&self, at: C) -> KeyedRng {
+        KeyedRng {
+            rng: BlockRng64::new(KeyedCore {
+                key: self.key,
+                words: at.to_words(),
+                tag: C::DIM as u64,
+                block: 0,
+            }),
+        }
+    }
+
+    #[inline]
+    fn block<C: Coordinates>(&self, at: &C, block: u64) -> [u64; 4] {
+        let [x, y, z] = at.to_words();
+        philox4x64(self.key, [x, y, z, (block << 2) | C::DIM as u64])
+    }
+}
+
+// Custom Debug implementation that does not expose the key
+impl fmt::Debug for KeyedRandom {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "KeyedRandom {{}}")
+    }
+}
+
+impl SeedableRng for KeyedRandom {
+    type Seed = [u8; 16];
+
+    /// The seed is used as the key (two little-endian `u64` words).
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        let mut key = [0; 2];
+        read_u64_into(&seed, &mut key);
+        KeyedRandom::new(key)
+    }
+}
+
+#[derive(Clone)]
+struct KeyedCore {
+    key: [u64; 2],
+    words: [u64; 3],
+    tag: u64,
+    block: u64,
+}
+
+impl BlockRngCore for KeyedCore {
+    type Item = u64;
+    type Results = [u64; 4];
+
+    #[inline]
+    fn generate(&mut self, results: &mut Self::Results) {
+        let [x, y, z] = self.words;
+        *results = philox4x64(self.key, [x, y, z, (self.block << 2) | self.tag]);
+        // 2^62 blocks suffice for all practical purposes; wrap afterwards
+        self.block = self.block.wrapping_add(1) & (u64::MAX >> 2);
+    }
+}
+
+/// The generator returned by [`KeyedRandom::rng_at`]
+///
+/// This yields the stream of values at a single index of a [`KeyedRandom`].
+#[derive(Clone)]
+pub struct KeyedRng {
+    rng: BlockRng64<KeyedCore>,
+}
+
+// Custom Debug implementation that does not expose the internal state
+impl fmt::Debug for KeyedRng {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "KeyedRng {{}}")
+    }
+}
+
+impl RngCore for KeyedRng {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    #[inline]
+    fn fill_uninit<'a>(&mut self, dst: &'a mut [MaybeUninit<u8>]) -> &'a mut [u8] {
+        self.rng.fill_uninit(dst)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::distr::Uniform;
+
+    #[test]
+    fn test_philox4x64_kat() {
+        // Known-answer vector from Random123 (kat_vectors).
+        let block = philox4x64([u64::MAX; 2], [u64::MAX; 4]);
+        let expected = [
+            0x87b092c3013fe90b,
+            0x438c3c67be8d0224,
+            0x9cc7d7c69cd777b6,
+            0xa09caebf594f0ba0,
+        ];
+        assert_eq!(block, expected);
+    }
+
+    #[test]
+    fn test_philox4x64_matches_rand_random123() {
+        use crate::Rng;
+        use rand_random123::Philox4x64Core;
+
+        let mut rng = crate::test::rng(290);
+        for _ in 0..100 {
+            let key: [u64; 2] = rng.random();
+            let ctr: [u64; 4] = rng.random();
+            assert_eq!(philox4x64(key, ctr), Philox4x64Core::new(key).block(ctr));
+        }
+    }
+
+    #[test]
+    fn test_keyed_rng_fill_uninit() {
+        let f = KeyedRandom::new([1, 2]);
+        let mut a = [0u8; 100];
+        let mut b = [MaybeUninit::uninit(); 100];
+        f.rng_at(5u64).fill_bytes(&mut a);
+        assert_eq!(f.rng_at(5u64).fill_uninit(&mut b), &a[..]);
+    }
+
+    #[test]
+    fn test_keyed_random_values() {
+        // Value-stability test
+        let f = KeyedRandom::new([1, 2]);
+        assert_eq!(f.u64_at(0u64), 0x95569ef2e8c4a56f);
+        assert_eq!(f.u64_at(7u64), 0x371b29ae2f50854f);
+        assert_eq!(f.u64_at((3i32, -7i32)), 0x749c821da71c6ca5);
+        assert_eq!(f.u64_at([1u8, 2, 3]), 0x203f674688e41d2e);
+
+        let mut rng = f.rng_at(0u64);
+        let mut results = [0u64; 5];
+        for x in results.iter_mut() {
+            *x = rng.next_u64();
+        }
+        let expected = [
+            0x95569ef2e8c4a56f,
+            0x02267266cb9a3216,
+            0x296533ffa159770f,
+            0x0a25037d33758a5e,
+            0x7d356b94e8deab07,
+        ];
+        assert_eq!(results, expected);
+    }
+
+    #[test]
+    fn test_keyed_random_coordinates() {
+        let f = KeyedRandom::from_seed([3; 16]);
+
+        // Equivalent representations give equal values
+        assert_eq!(f.u64_at(5u8), f.u64_at(5u64));
+        assert_eq!(f.u64_at(-5i8), f.u64_at(-5i64));
+        assert_eq!(f.u64_at([5u32]), f.u64_at(5u32));
+        assert_eq!(f.u64_at([1u64, 2]), f.u64_at((1u64, 2u64)));
+        assert_eq!(f.u64_at([1i16, 2, 3]), f.u64_at((1i64, 2i64, 3i64)));
+
+        // Dimension is part of the index
+        assert_ne!(f.u64_at(5u64), f.u64_at((5u64, 0u64)));
+        assert_ne!(f.u64_at((5u64, 0u64)), f.u64_at((5u64, 0u64, 0u64)));
+    }
+
+    #[test]
+    fn test_keyed_random_sample() {
+        let f = KeyedRandom::new([1, 2]);
+        let distr = Uniform::new(0.0f64, 1.0).unwrap();
+        let x: f64 = f.sample_at(10u32, distr);
+        assert_eq!(x, f.sample_at(10u32, distr));
+        assert_eq!(x, distr.sample(&mut f.rng_at(10u32)));
+
+        let b: bool = f.random_at((1u32, 2u32));
+        assert_eq!(b, f.random_at((1u32, 2u32)));
+    }
+}
@@ -29,11 +29,27 @@
 //! -   [`SmallRng`] is a relatively simple, insecure generator designed to be
 //!     fast, use little memory, and pass various statistical tests of
 //!     randomness quality.
+//! -   [`Xoshiro256PlusPlusX4`] and [`Xoshiro256PlusPlusX8`] run several
+//!     xoshiro256++ streams in parallel using SIMD instructions, for fast bulk
+//!     generation. Unlike [`SmallRng`], the algorithm is fixed.
 //!
 //! The algorithms selected for [`StdRng`] and [`SmallRng`] may change in any
 //! release and may be platform-dependent, therefore they are not
 //! [reproducible][portable].
 //!
+//! ### Versioned generators
+//!
+//! [`StdRngV1`] and [`SmallRngV1`] are [portable] versions of [`StdRng`] and
+//! [`SmallRng`], fixed to the algorithms used at the time of introduction.
+//! Their output (including that of `seed_from_u64`) will never change; new
+//! algorithms will be introduced under new names.
+//!
+//! ### Keyed random functions
+//!
+//! [`KeyedRandom`] is not a generator but a stateless function from a key
+//! and an index (or coordinates) to random values. Unlike the above, its raw
+//! output (`u64_at` and `rng_at`) is [portable].
+//!
 //! ### Additional generators
 //!
 //! -   The [`rdrand`] crate provides an interface to the RDRAND and RDSEED
@@ -77,8 +93,16 @@
 //! [PCG]: https://www.pcg-random.org/
 //! [xoshiro]: https://prng.di.unimi.it/
 
+mod keyed;
 mod reseeding;
-pub use reseeding::ReseedingRng;
+#[cfg(any(feature = "std_rng", feature = "small_rng"))]
+mod versioned;
+pub use keyed::{Coordinates, KeyedRandom, KeyedRng};
+pub use reseeding::{ReseedingRng, TryReseedingRng};
+#[cfg(feature = "small_rng")]
+pub use versioned::SmallRngV1;
+#[cfg(feature = "std_rng")]
+pub use versioned::StdRngV1;
 
 pub mod mock; // Public so we don't export `StepRng` directly, making it a bit
               // more clear it is intended for testing.
@@ -92,6 +116,8 @@ mod small;
 mod xoshiro128plusplus;
 #[cfg(all(feature = "small_rng", target_pointer_width = "64"))]
 mod xoshiro256plusplus;
+#[cfg(feature = "small_rng")]
+mod xoshiro256plusplus_lanes;
 
 #[cfg(feature = "std_rng")]
 mod std;
@@ -104,6 +130,8 @@ pub use self::small::SmallRng;
 pub use self::std::StdRng;
 #[cfg(feature = "thread_rng")]
 pub use self::thread::ThreadRng;
+#[cfg(feature = "small_rng")]
+pub use self::xoshiro256plusplus_lanes::{Xoshiro256PlusPlusX4, Xoshiro256PlusPlusX8};
 
 #[cfg(feature = "os_rng")]
 pub use rand_core::OsRng;
@@ -10,9 +10,12 @@
 //! A wrapper around another PRNG that reseeds it after it
 //! generates a certain number of random bytes.
 
-use core::mem::size_of_val;
+use core::mem::{size_of_val, MaybeUninit};
 
-use rand_core::block::{BlockRng, BlockRngCore, CryptoBlockRng};
+use rand_core::block::{
+    BlockRng, BlockRngCore, CryptoBlockRng, TryBlockRng, TryBlockRngCore, TryCryptoBlockRng,
+};
+use rand_core::impls::UninitBytes;
 use rand_core::{CryptoRng, RngCore, SeedableRng, TryCryptoRng, TryRngCore};
 
 /// A wrapper around any PRNG that implements [`BlockRngCore`], that adds the
@@ -48,7 +51,8 @@ use rand_core::{CryptoRng, RngCore, SeedableRng, TryCryptoRng, TryRngCore};
 /// data from the wrapped PRNG without reseeding.
 ///
 /// Manually calling [`reseed()`] will not have this retry or delay logic, but
-/// reports the error.
+/// reports the error. To have all reseeding errors reported, use
+/// [`TryReseedingRng`] instead.
 ///
 /// # Example
 ///
@@ -122,6 +126,10 @@ where
     fn fill_bytes(&mut self, dest: &mut [u8]) {
         self.0.fill_bytes(dest)
     }
+
+    fn fill_uninit<'a>(&mut self, dest: &'a mut [MaybeUninit<u8>]) -> &'a mut [u8] {
+        self.0.fill_uninit(dest)
+    }
 }
 
 impl<R, Rsdr> Clone for ReseedingRng<R, Rsdr>
@@ -143,6 +151,96 @@ where
 {
 }
 
+/// A fallible version of [`ReseedingRng`], reporting reseeding errors.
+///
+/// This reseeds the underlying PRNG in the same cases as [`ReseedingRng`],
+/// but when periodic reseeding fails, the error of the reseeder is returned
+/// by the [`TryRngCore`] method which triggered it instead of being logged.
+/// No output is generated until reseeding succeeds: the next call retries
+/// reseeding.
+///
+/// # Example
+///
+/// ```
+/// use rand::TryRngCore;
+/// use rand::rngs::{OsRng, TryReseedingRng};
+/// use rand_chacha::ChaCha20Core;
+///
+/// let mut rng = TryReseedingRng::<ChaCha20Core, _>::new(1 << 20, OsRng).unwrap();
+///
+/// println!("{}", rng.try_next_u64().unwrap());
+/// ```
+#[derive(Debug)]
+pub struct TryReseedingRng<R, Rsdr>(TryBlockRng<ReseedingCore<R, Rsdr>>)
+where
+    R: BlockRngCore + SeedableRng,
+    Rsdr: TryRngCore;
+
+impl<R, Rsdr> TryReseedingRng<R, Rsdr>
+where
+    R: BlockRngCore + SeedableRng,
+    Rsdr: TryRngCore,
+{
+    /// Create a new `TryReseedingRng` from an existing PRNG, combined with a
+    /// RNG to use as reseeder.
+    ///
+    /// `threshold` sets the number of generated bytes after which to reseed the
+    /// PRNG. Set it to zero to never reseed based on the number of generated
+    /// values.
+    pub fn new(threshold: u64, reseeder: Rsdr) -> Result<Self, Rsdr::Error> {
+        Ok(TryReseedingRng(TryBlockRng::new(ReseedingCore::new(
+            threshold, reseeder,
+        )?)))
+    }
+
+    /// Immediately reseed the generator
+    ///
+    /// This discards any remaining random data in the cache.
+    pub fn reseed(&mut self) -> Result<(), Rsdr::Error> {
+        self.0.reset();
+        self.0.core.reseed()
+    }
+}
+
+impl<R, Rsdr> TryRngCore for TryReseedingRng<R, Rsdr>
+where
+    R: BlockRngCore<Item = u32> + SeedableRng,
+    Rsdr: TryRngCore,
+{
+    type Error = Rsdr::Error;
+
+    #[inline(always)]
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        self.0.try_next_u32()
+    }
+
+    #[inline(always)]
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        self.0.try_next_u64()
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Self::Error> {
+        self.0.try_fill_bytes(dest)
+    }
+}
+
+impl<R, Rsdr> Clone for TryReseedingRng<R, Rsdr>
+where
+    R: BlockRngCore + SeedableRng + Clone,
+    Rsdr: TryRngCore + Clone,
+{
+    fn clone(&self) -> TryReseedingRng<R, Rsdr> {
+        TryReseedingRng(TryBlockRng::new(self.0.core.clone()))
+    }
+}
+
+impl<R, Rsdr> TryCryptoRng for TryReseedingRng<R, Rsdr>
+where
+    R: BlockRngCore<Item = u32> + SeedableRng + CryptoBlockRng,
+    Rsdr: TryCryptoRng,
+{
+}
+
 #[derive(Debug)]
 struct ReseedingCore<R, Rsdr> {
     inner: R,
@@ -170,6 +268,53 @@ where
         self.bytes_until_reseed -= num_bytes as i64;
         self.inner.generate(results);
     }
+
+    fn generate_bytes(&mut self, mut dest: &mut [u8]) -> bool {
+        while !dest.is_empty() {
+            let len = self.direct_len(dest.len());
+            let (head, rest) = core::mem::take(&mut dest).split_at_mut(len);
+            if !self.inner.generate_bytes(head) {
+                return false;
+            }
+            self.bytes_until_reseed -= len as i64;
+            dest = rest;
+        }
+        true
+    }
+
+    fn generate_uninit(&mut self, dest: &mut UninitBytes<'_>) {
+        while dest.remaining() > 0 {
+            let len = self.direct_len(dest.remaining());
+            let filled = dest.filled();
+            dest.limit(len, |chunk| self.inner.generate_uninit(chunk));
+            let written = dest.filled() - filled;
+            self.bytes_until_reseed -= written as i64;
+            if written < len {
+                break;
+            }
+        }
+    }
+}
+
+impl<R, Rsdr> TryBlockRngCore for ReseedingCore<R, Rsdr>
+where
+    R: BlockRngCore + SeedableRng,
+    Rsdr: TryRngCore,
+{
+    type Item = <R as BlockRngCore>::Item;
+    type Results = <R as BlockRngCore>::Results;
+    type Error = Rsdr::Error;
+
+    fn try_generate(&mut self, results: &mut Self::Results) -> Result<(), Self::Error> {
+        if self.bytes_until_reseed <= 0 {
+            trace!("Reseeding RNG (periodic reseed)");
+            self.reseed()?;
+        }
+        let num_bytes = size_of_val(results.as_ref());
+        self.bytes_until_reseed -= num_bytes as i64;
+        self.inner.generate(results);
+        Ok(())
+    }
 }
 
 impl<R, Rsdr> ReseedingCore<R, Rsdr>
@@ -212,6 +357,24 @@ where
         })
     }
 
+    /// Reseed if due, then get the number of bytes (whole blocks, at most
+    /// `len`) which may be generated directly before the next reseed.
+    fn direct_len(&mut self, len: usize) -> usize {
+        if self.bytes_until_reseed <= 0 {
+            trace!("Reseeding RNG (periodic reseed)");
+            if let Err(e) = self.reseed() {
+                warn!("Reseeding RNG failed: {}", e);
+                let _ = e;
+            }
+            self.bytes_until_reseed = self.threshold;
+        }
+
+        let block_len = size_of_val(<Self as BlockRngCore>::Results::default().as_ref()) as i64;
+        let until_reseed =
+            self.bytes_until_reseed.saturating_add(block_len - 1) / block_len * block_len;
+        (len as u64).min(until_reseed as u64) as usize
+    }
+
     #[inline(never)]
     fn reseed_and_generate(&mut self, results: &mut <Self as BlockRngCore>::Results) {
         trace!("Reseeding RNG (periodic reseed)");
@@ -250,14 +413,21 @@ where
 {
 }
 
+impl<R, Rsdr> TryCryptoBlockRng for ReseedingCore<R, Rsdr>
+where
+    R: BlockRngCore<Item = u32> + SeedableRng + CryptoBlockRng,
+    Rsdr: TryCryptoRng,
+{
+}
+
 #[cfg(feature = "std_rng")]
 #[cfg(test)]
 mod test {
     use crate::rngs::mock::StepRng;
     use crate::rngs::std::Core;
-    use crate::Rng;
+    use crate::{Rng, RngCore, TryRngCore};
 
-    use super::ReseedingRng;
+    use super::{ReseedingRng, TryReseedingRng};
 
     #[test]
     fn test_reseeding() {
@@ -278,6 +448,34 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_reseeding_fill_bytes() {
+        let zero = StepRng::new(0, 0);
+        let thresh = 1; // reseed every time a block is generated
+        let mut reseeding = ReseedingRng::<Core, _>::new(thresh, zero).unwrap();
+
+        // large requests are generated directly, but must still reseed per block
+        let mut buf = [0u8; 4 * 256 + 12];
+        reseeding.fill_bytes(&mut buf);
+        for block in buf.chunks(256).skip(1) {
+            assert_eq!(block, &buf[..block.len()]);
+        }
+    }
+
+    #[test]
+    fn test_reseeding_fill_uninit() {
+        use core::mem::MaybeUninit;
+
+        let zero = StepRng::new(0, 0);
+        let mut reseeding = ReseedingRng::<Core, _>::new(1, zero).unwrap();
+
+        let mut buf = [MaybeUninit::uninit(); 4 * 256 + 12];
+        let buf = reseeding.fill_uninit(&mut buf);
+        for block in buf.chunks(256).skip(1) {
+            assert_eq!(block, &buf[..block.len()]);
+        }
+    }
+
     #[test]
     #[allow(clippy::redundant_clone)]
     fn test_clone_reseeding() {
@@ -292,4 +490,57 @@ mod test {
         let mut rng2 = rng1.clone();
         assert_eq!(first, rng2.random::<u32>());
     }
+
+    // Reseeder which fails once `remaining` calls are used up
+    struct FlakyRng {
+        inner: StepRng,
+        remaining: usize,
+    }
+
+    impl TryRngCore for FlakyRng {
+        type Error = &'static str;
+
+        fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+            self.try_next_u64().map(|x| x as u32)
+        }
+
+        fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+            let mut buf = [0; 8];
+            self.try_fill_bytes(&mut buf)?;
+            Ok(u64::from_le_bytes(buf))
+        }
+
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+            if self.remaining == 0 {
+                return Err("reseeder failed");
+            }
+            self.remaining -= 1;
+            self.inner.fill_bytes(dst);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_try_reseeding_errors() {
+        let reseeder = FlakyRng {
+            inner: StepRng::new(0, 0),
+            remaining: 2,
+        };
+        let thresh = 1; // reseed every time the buffer is exhausted
+        let mut reseeding = TryReseedingRng::<Core, _>::new(thresh, reseeder).unwrap();
+        let mut expected = ReseedingRng::<Core, _>::new(thresh, StepRng::new(0, 0)).unwrap();
+
+        // first block (initial seed) and second block (one reseed)
+        for _ in 0..128 {
+            assert_eq!(reseeding.try_next_u32(), Ok(expected.next_u32()));
+        }
+
+        // further reseeding fails, repeatedly
+        assert_eq!(reseeding.try_next_u32(), Err("reseeder failed"));
+        assert_eq!(reseeding.try_next_u32(), Err("reseeder failed"));
+        assert_eq!(reseeding.reseed(), Err("reseeder failed"));
+
+        reseeding.0.core.reseeder.remaining = 1;
+        assert_eq!(reseeding.try_next_u32(), Ok(expected.next_u32()));
+    }
 }
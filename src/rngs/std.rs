@@ -8,6 +8,7 @@
 
 //! The standard RNG
 
+use core::mem::MaybeUninit;
 use rand_core::{CryptoRng, RngCore, SeedableRng};
 
 #[cfg(any(test, feature = "os_rng"))]
@@ -84,6 +85,11 @@ impl RngCore for StdRng {
     fn fill_bytes(&mut self, dst: &mut [u8]) {
         self.0.fill_bytes(dst)
     }
+
+    #[inline(always)]
+    fn fill_uninit<'a>(&mut self, dst: &'a mut [MaybeUninit<u8>]) -> &'a mut [u8] {
+        self.0.fill_uninit(dst)
+    }
 }
 
 impl SeedableRng for StdRng {
@@ -9,6 +9,7 @@
 //! Thread-local random number generator
 
 use core::cell::UnsafeCell;
+use core::mem::MaybeUninit;
 use std::fmt;
 use std::rc::Rc;
 use std::thread_local;
@@ -189,6 +190,14 @@ impl RngCore for ThreadRng {
         let rng = unsafe { &mut *self.rng.get() };
         rng.fill_bytes(dest)
     }
+
+    #[inline(always)]
+    fn fill_uninit<'a>(&mut self, dest: &'a mut [MaybeUninit<u8>]) -> &'a mut [u8] {
+        // SAFETY: We must make sure to stop using `rng` before anyone else
+        // creates another mutable reference
+        let rng = unsafe { &mut *self.rng.get() };
+        rng.fill_uninit(dest)
+    }
 }
 
 impl CryptoRng for ThreadRng {}
@@ -0,0 +1,277 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Versioned generators with frozen algorithms
+
+#[cfg(feature = "std_rng")]
+use core::mem::MaybeUninit;
+#[cfg(feature = "std_rng")]
+use rand_core::CryptoRng;
+use rand_core::{RngCore, SeedableRng};
+
+/// Expand a `u64` into a 32-byte seed
+///
+/// This is the algorithm of the default `SeedableRng::seed_from_u64` as of
+/// `rand_core` 0.9 (PCG32 output, little-endian), frozen here for the
+/// versioned generators.
+#[cfg(feature = "std_rng")]
+fn pcg32_seed(mut state: u64) -> [u8; 32] {
+    const MUL: u64 = 6364136223846793005;
+    const INC: u64 = 11634580027462260723;
+
+    let mut seed = [0u8; 32];
+    for chunk in seed.chunks_exact_mut(4) {
+        state = state.wrapping_mul(MUL).wrapping_add(INC);
+        let xorshifted = (((state >> 18) ^ state) >> 27) as u32;
+        let rot = (state >> 59) as u32;
+        chunk.copy_from_slice(&xorshifted.rotate_right(rot).to_le_bytes());
+    }
+    seed
+}
+
+/// Version 1 of [`StdRng`]: ChaCha12, guaranteed never to change
+///
+/// Unlike [`StdRng`], this generator is [portable] and its output will not
+/// change in any future release, including via [`SeedableRng::seed_from_u64`].
+/// It is the algorithm used by [`StdRng`] at the time of introduction, and
+/// is value-equivalent to `rand_chacha::ChaCha12Rng` (with the
+/// `seed_from_u64` of `rand_core` 0.9).
+///
+/// Seeding via [`SeedableRng::from_rng`] reads the seed from the given
+/// generator using [`RngCore::fill_bytes`], and is thus only reproducible if
+/// that generator is.
+///
+/// [`StdRng`]: crate::rngs::StdRng
+/// [portable]: https://rust-random.github.io/book/crate-reprod.html
+#[cfg(feature = "std_rng")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StdRngV1(rand_chacha::ChaCha12Rng);
+
+#[cfg(feature = "std_rng")]
+impl RngCore for StdRngV1 {
+    #[inline(always)]
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    #[inline(always)]
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    #[inline(always)]
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        self.0.fill_bytes(dst)
+    }
+
+    #[inline(always)]
+    fn fill_uninit<'a>(&mut self, dst: &'a mut [MaybeUninit<u8>]) -> &'a mut [u8] {
+        self.0.fill_uninit(dst)
+    }
+}
+
+#[cfg(feature = "std_rng")]
+impl SeedableRng for StdRngV1 {
+    type Seed = [u8; 32];
+
+    #[inline(always)]
+    fn from_seed(seed: Self::Seed) -> Self {
+        StdRngV1(rand_chacha::ChaCha12Rng::from_seed(seed))
+    }
+
+    #[inline]
+    fn seed_from_u64(state: u64) -> Self {
+        Self::from_seed(pcg32_seed(state))
+    }
+}
+
+#[cfg(feature = "std_rng")]
+impl CryptoRng for StdRngV1 {}
+
+/// Version 1 of [`SmallRng`]: xoshiro256++, guaranteed never to change
+///
+/// Unlike [`SmallRng`], this generator is [portable]: it uses the same 64-bit
+/// algorithm on all platforms and its output will not change in any future
+/// release, including via [`SeedableRng::seed_from_u64`]. It is the algorithm
+/// used by [`SmallRng`] on 64-bit platforms at the time of introduction.
+///
+/// Seeding via [`SeedableRng::from_seed`] uses the seed as four little-endian
+/// `u64` words of state (an all-zero seed is mapped to
+/// `seed_from_u64(0)`), while [`SeedableRng::seed_from_u64`] expands its input
+/// with SplitMix64. Both match the `rand_xoshiro` crate.
+///
+/// This generator is not suitable for cryptographic purposes.
+///
+/// [`SmallRng`]: crate::rngs::SmallRng
+/// [portable]: https://rust-random.github.io/book/crate-reprod.html
+#[cfg(feature = "small_rng")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SmallRngV1 {
+    s: [u64; 4],
+}
+
+#[cfg(feature = "small_rng")]
+impl RngCore for SmallRngV1 {
+    #[inline]
+    fn next_u32(&mut self) -> u32 {
+        // The lowest bits have some linear dependencies, so we use the
+        // upper bits instead.
+        (self.next_u64() >> 32) as u32
+    }
+
+    #[inline]
+    fn next_u64(&mut self) -> u64 {
+        let res = self.s[0]
+            .wrapping_add(self.s[3])
+            .rotate_left(23)
+            .wrapping_add(self.s[0]);
+
+        let t = self.s[1] << 17;
+
+        self.s[2] ^= self.s[0];
+        self.s[3] ^= self.s[1];
+        self.s[1] ^= self.s[2];
+        self.s[0] ^= self.s[3];
+
+        self.s[2] ^= t;
+
+        self.s[3] = self.s[3].rotate_left(45);
+
+        res
+    }
+
+    #[inline]
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        // Little-endian `u64` words; a tail of up to 4 bytes uses one `u32`
+        let mut chunks = dst.chunks_exact_mut(8);
+        for chunk in &mut chunks {
+            chunk.copy_from_slice(&self.next_u64().to_le_bytes());
+        }
+        let left = chunks.into_remainder();
+        let n = left.len();
+        if n > 4 {
+            left.copy_from_slice(&self.next_u64().to_le_bytes()[..n]);
+        } else if n > 0 {
+            left.copy_from_slice(&self.next_u32().to_le_bytes()[..n]);
+        }
+    }
+}
+
+#[cfg(feature = "small_rng")]
+impl SeedableRng for SmallRngV1 {
+    type Seed = [u8; 32];
+
+    #[inline]
+    fn from_seed(seed: Self::Seed) -> Self {
+        let mut s = [0; 4];
+        for (x, chunk) in s.iter_mut().zip(seed.chunks_exact(8)) {
+            *x = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        if s.iter().all(|&x| x == 0) {
+            return Self::seed_from_u64(0);
+        }
+        SmallRngV1 { s }
+    }
+
+    /// Expand `state` with SplitMix64
+    #[inline]
+    fn seed_from_u64(mut state: u64) -> Self {
+        const PHI: u64 = 0x9e3779b97f4a7c15;
+        let mut s = [0; 4];
+        for x in s.iter_mut() {
+            state = state.wrapping_add(PHI);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            z = z ^ (z >> 31);
+            *x = z;
+        }
+        // A non-zero PHI guarantees a non-zero state, so from_seed and
+        // seed_from_u64 cannot recurse.
+        debug_assert_ne!(s, [0; 4]);
+        SmallRngV1 { s }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[rustfmt::skip]
+    const SEED: [u8; 32] = [1,0,0,0, 23,0,0,0, 200,1,0,0, 210,30,0,0,
+                            0,0,0,0, 0,0,0,0, 0,0,0,0, 0,0,0,0];
+
+    // Known-answer tests: these values must never change.
+
+    #[cfg(feature = "std_rng")]
+    #[test]
+    fn std_rng_v1_kat() {
+        let mut rng = StdRngV1::from_seed(SEED);
+        assert_eq!(rng.next_u64(), 10719222850664546238);
+        assert_eq!(rng.next_u32(), 2562745551);
+
+        let mut rng = StdRngV1::seed_from_u64(0);
+        assert_eq!(rng.next_u64(), 13486662071293341567);
+        let mut buf = [0u8; 8];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(buf, [123, 105, 39, 142, 148, 124, 1, 198]);
+
+        let mut rng = StdRngV1::seed_from_u64(u64::MAX);
+        assert_eq!(rng.next_u64(), 1128037667460439992);
+    }
+
+    #[cfg(feature = "std_rng")]
+    #[test]
+    fn std_rng_v1_seed_from_u64_matches_default() {
+        for &state in &[0, 1, 42, u64::MAX] {
+            let mut a = StdRngV1::seed_from_u64(state);
+            let mut b = rand_chacha::ChaCha12Rng::seed_from_u64(state);
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+
+    #[cfg(feature = "small_rng")]
+    #[test]
+    fn small_rng_v1_kat() {
+        let mut rng = SmallRngV1::from_seed(SEED);
+        assert_eq!(rng.next_u64(), 828662430228807681);
+        assert_eq!(rng.next_u32(), 1652689893);
+
+        let mut rng = SmallRngV1::seed_from_u64(0);
+        assert_eq!(rng.next_u64(), 5987356902031041503);
+        let mut buf = [0u8; 8];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(buf, [7, 213, 128, 195, 61, 111, 218, 97]);
+
+        let mut rng = SmallRngV1::from_seed([0; 32]);
+        assert_eq!(rng.next_u64(), 5987356902031041503);
+        let mut buf = [0u8; 11];
+        rng.fill_bytes(&mut buf);
+        assert_eq!(buf, [7, 213, 128, 195, 61, 111, 218, 97, 145, 223, 15]);
+    }
+
+    #[cfg(feature = "small_rng")]
+    #[test]
+    fn small_rng_v1_reference() {
+        #[rustfmt::skip]
+        let mut rng = SmallRngV1::from_seed([1,0,0,0,0,0,0,0, 2,0,0,0,0,0,0,0,
+                                             3,0,0,0,0,0,0,0, 4,0,0,0,0,0,0,0]);
+        // Values produced with the reference implementation:
+        // http://xoshiro.di.unimi.it/xoshiro256plusplus.c
+        let expected = [
+            41943041,
+            58720359,
+            3588806011781223,
+            3591011842654386,
+            9228616714210784205,
+        ];
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+        }
+    }
+}
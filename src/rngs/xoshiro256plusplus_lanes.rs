@@ -0,0 +1,538 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Interleaved multi-lane xoshiro256++ generators
+
+use core::mem::MaybeUninit;
+use rand_core::block::{BlockRng64, BlockRngCore};
+use rand_core::impls::UninitBytes;
+use rand_core::le::read_u64_into;
+use rand_core::{RngCore, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// One step of the scalar xoshiro256++ generator
+#[inline]
+fn next_scalar(s: &mut [u64; 4]) -> u64 {
+    let res = s[0].wrapping_add(s[3]).rotate_left(23).wrapping_add(s[0]);
+    let t = s[1] << 17;
+    s[2] ^= s[0];
+    s[3] ^= s[1];
+    s[1] ^= s[2];
+    s[0] ^= s[3];
+    s[2] ^= t;
+    s[3] = s[3].rotate_left(45);
+    res
+}
+
+/// Advance the scalar state by 2<sup>128</sup> steps
+fn jump(s: &mut [u64; 4]) {
+    // From the reference `xoshiro256plusplus.c`
+    const JUMP: [u64; 4] = [
+        0x180ec6d33cfd0aba,
+        0xd5a61266f0c9392c,
+        0xa9582618e03fc9aa,
+        0x39abdc4529b1661c,
+    ];
+    let mut t = [0u64; 4];
+    for &j in JUMP.iter() {
+        for b in 0..64 {
+            if j & (1 << b) != 0 {
+                for (t, s) in t.iter_mut().zip(s.iter()) {
+                    *t ^= *s;
+                }
+            }
+            next_scalar(s);
+        }
+    }
+    *s = t;
+}
+
+/// Lane states from the state of lane 0: lane `i` is jumped `i` times
+fn lanes_from_state<const N: usize>(mut state: [u64; 4]) -> [[u64; N]; 4] {
+    let mut s = [[0; N]; 4];
+    for lane in 0..N {
+        if lane > 0 {
+            jump(&mut state);
+        }
+        for (row, &x) in s.iter_mut().zip(state.iter()) {
+            row[lane] = x;
+        }
+    }
+    s
+}
+
+/// One step of all lanes, portable version
+#[cfg(not(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    any(target_feature = "sse2", target_feature = "avx2")
+)))]
+#[inline(always)]
+fn step<const N: usize>(s: &mut [[u64; N]; 4], out: &mut [u64; N]) {
+    let [s0, s1, s2, s3] = s;
+    for i in 0..N {
+        out[i] = s0[i]
+            .wrapping_add(s3[i])
+            .rotate_left(23)
+            .wrapping_add(s0[i]);
+        let t = s1[i] << 17;
+        s2[i] ^= s0[i];
+        s3[i] ^= s1[i];
+        s1[i] ^= s2[i];
+        s0[i] ^= s3[i];
+        s2[i] ^= t;
+        s3[i] = s3[i].rotate_left(45);
+    }
+}
+
+/// One step of all lanes using SSE2 (two lanes per vector) or AVX2 (four)
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    any(target_feature = "sse2", target_feature = "avx2")
+))]
+#[inline(always)]
+fn step<const N: usize>(s: &mut [[u64; N]; 4], out: &mut [u64; N]) {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    #[cfg(target_feature = "avx2")]
+    macro_rules! simd {
+        (W) => {
+            4
+        };
+        (V) => {
+            __m256i
+        };
+        (load $p:expr) => {
+            _mm256_loadu_si256($p as *const __m256i)
+        };
+        (store $p:expr, $x:expr) => {
+            _mm256_storeu_si256($p as *mut __m256i, $x)
+        };
+        (add $a:expr, $b:expr) => {
+            _mm256_add_epi64($a, $b)
+        };
+        (xor $a:expr, $b:expr) => {
+            _mm256_xor_si256($a, $b)
+        };
+        (or $a:expr, $b:expr) => {
+            _mm256_or_si256($a, $b)
+        };
+        (shl $a:expr, $n:literal) => {
+            _mm256_slli_epi64::<$n>($a)
+        };
+        (shr $a:expr, $n:literal) => {
+            _mm256_srli_epi64::<$n>($a)
+        };
+    }
+    #[cfg(not(target_feature = "avx2"))]
+    macro_rules! simd {
+        (W) => {
+            2
+        };
+        (V) => {
+            __m128i
+        };
+        (load $p:expr) => {
+            _mm_loadu_si128($p as *const __m128i)
+        };
+        (store $p:expr, $x:expr) => {
+            _mm_storeu_si128($p as *mut __m128i, $x)
+        };
+        (add $a:expr, $b:expr) => {
+            _mm_add_epi64($a, $b)
+        };
+        (xor $a:expr, $b:expr) => {
+            _mm_xor_si128($a, $b)
+        };
+        (or $a:expr, $b:expr) => {
+            _mm_or_si128($a, $b)
+        };
+        (shl $a:expr, $n:literal) => {
+            _mm_slli_epi64::<$n>($a)
+        };
+        (shr $a:expr, $n:literal) => {
+            _mm_srli_epi64::<$n>($a)
+        };
+    }
+
+    debug_assert_eq!(N % simd!(W), 0);
+    let [s0, s1, s2, s3] = s;
+    for i in (0..N).step_by(simd!(W)) {
+        // SAFETY: the target feature is enabled at compile time, and all
+        // loads and stores (unaligned) are of `W` lanes starting at `i`,
+        // within the bounds of arrays of length `N`, a multiple of `W`.
+        unsafe {
+            let mut x0: simd!(V) = simd!(load s0.as_ptr().add(i));
+            let mut x1: simd!(V) = simd!(load s1.as_ptr().add(i));
+            let mut x2: simd!(V) = simd!(load s2.as_ptr().add(i));
+            let mut x3: simd!(V) = simd!(load s3.as_ptr().add(i));
+
+            let sum = simd!(add x0, x3);
+            let rot = simd!(or simd!(shl sum, 23), simd!(shr sum, 41));
+            simd!(store out.as_mut_ptr().add(i), simd!(add rot, x0));
+
+            let t = simd!(shl x1, 17);
+            x2 = simd!(xor x2, x0);
+            x3 = simd!(xor x3, x1);
+            x1 = simd!(xor x1, x2);
+            x0 = simd!(xor x0, x3);
+            x2 = simd!(xor x2, t);
+            x3 = simd!(or simd!(shl x3, 45), simd!(shr x3, 19));
+
+            simd!(store s0.as_mut_ptr().add(i), x0);
+            simd!(store s1.as_mut_ptr().add(i), x1);
+            simd!(store s2.as_mut_ptr().add(i), x2);
+            simd!(store s3.as_mut_ptr().add(i), x3);
+        }
+    }
+}
+
+macro_rules! lanes_impl {
+    ($Rng:ident, $Core:ident, $lanes:literal) => {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        struct $Core {
+            s: [[u64; $lanes]; 4],
+        }
+
+        impl BlockRngCore for $Core {
+            type Item = u64;
+            type Results = [u64; $lanes];
+
+            #[inline]
+            fn generate(&mut self, results: &mut Self::Results) {
+                step(&mut self.s, results);
+            }
+
+            #[inline]
+            fn generate_bytes(&mut self, dest: &mut [u8]) -> bool {
+                // Work on a local copy, which the compiler may keep in registers
+                let mut s = self.s;
+                let mut results = [0; $lanes];
+                for chunk in dest.chunks_exact_mut(8 * $lanes) {
+                    step(&mut s, &mut results);
+                    for (bytes, x) in chunk.chunks_exact_mut(8).zip(results.iter()) {
+                        bytes.copy_from_slice(&x.to_le_bytes());
+                    }
+                }
+                self.s = s;
+                true
+            }
+
+            #[inline]
+            fn generate_uninit(&mut self, dest: &mut UninitBytes<'_>) {
+                let mut s = self.s;
+                let mut results = [0; $lanes];
+                let mut bytes = [0; 8 * $lanes];
+                while dest.remaining() >= bytes.len() {
+                    step(&mut s, &mut results);
+                    for (b, x) in bytes.chunks_exact_mut(8).zip(results.iter()) {
+                        b.copy_from_slice(&x.to_le_bytes());
+                    }
+                    dest.write(&bytes);
+                }
+                self.s = s;
+            }
+        }
+
+        #[doc = concat!("A xoshiro256++ generator with ", stringify!($lanes), " interleaved lanes.")]
+        ///
+        /// Each step advances all lanes at once, producing a whole vector of
+        /// outputs. Lane `i` is a xoshiro256++ generator whose state is that of
+        /// lane 0 advanced by `i` times 2<sup>128</sup> steps (using the `jump`
+        /// function of the reference implementation), so the lanes do not
+        /// overlap. The outputs of a step are used in lane order:
+        /// [`next_u64`] returns lane 0 of the first step, then lane 1 and so
+        /// on, and [`next_u32`] returns one half of such a value.
+        ///
+        /// Stepping uses SSE2 or AVX2 instructions via [`core::arch`] where the
+        /// corresponding target feature is enabled at compile time (for
+        /// example, SSE2 is enabled by default on `x86_64`), and a portable
+        /// implementation otherwise. Output does not depend on the
+        /// implementation used. With SSE2 only, [`Xoshiro256PlusPlusX4`] is
+        /// usually the faster of the two types.
+        ///
+        /// The bulk methods [`Self::fill_u64`] and [`Self::fill_f64`] generate
+        /// whole steps directly into their destination.
+        ///
+        /// Like the scalar xoshiro256++ used by [`SmallRng`], this algorithm is
+        /// not suitable for cryptographic purposes.
+        ///
+        /// [`next_u64`]: RngCore::next_u64
+        /// [`next_u32`]: RngCore::next_u32
+        /// [`SmallRng`]: crate::rngs::SmallRng
+        #[derive(Debug, Clone)]
+        #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+        pub struct $Rng(BlockRng64<$Core>);
+
+        impl $Rng {
+            /// The number of lanes
+            pub const LANES: usize = $lanes;
+
+            /// Fill `dest` with random `u64` values
+            ///
+            /// This is equivalent to (but faster than) assigning
+            /// [`RngCore::next_u64`] to each element in order.
+            pub fn fill_u64(&mut self, dest: &mut [u64]) {
+                if dest.is_empty() {
+                    return;
+                }
+                // Use up buffered values first
+                dest[0] = self.0.next_u64();
+                let mut read_len = 1;
+                while read_len < dest.len() && self.0.index() < $lanes {
+                    dest[read_len] = self.0.next_u64();
+                    read_len += 1;
+                }
+
+                let mut s = self.0.core.s;
+                let mut chunks = dest[read_len..].chunks_exact_mut($lanes);
+                for chunk in &mut chunks {
+                    step(&mut s, chunk.try_into().unwrap());
+                }
+                self.0.core.s = s;
+                for x in chunks.into_remainder() {
+                    *x = self.0.next_u64();
+                }
+            }
+
+            /// Fill `dest` with random `f64` values in the half-open range `[0, 1)`
+            ///
+            /// This is equivalent to (but faster than) sampling each element
+            /// in order from [`StandardUniform`].
+            ///
+            /// [`StandardUniform`]: crate::distr::StandardUniform
+            pub fn fill_f64(&mut self, dest: &mut [f64]) {
+                const SCALE: f64 = 1.0 / (1u64 << 53) as f64;
+                let mut buf = [0u64; 4 * $lanes];
+                for chunk in dest.chunks_mut(buf.len()) {
+                    let buf = &mut buf[..chunk.len()];
+                    self.fill_u64(buf);
+                    for (x, &u) in chunk.iter_mut().zip(buf.iter()) {
+                        *x = (u >> 11) as f64 * SCALE;
+                    }
+                }
+            }
+        }
+
+        impl RngCore for $Rng {
+            #[inline(always)]
+            fn next_u32(&mut self) -> u32 {
+                self.0.next_u32()
+            }
+
+            #[inline(always)]
+            fn next_u64(&mut self) -> u64 {
+                self.0.next_u64()
+            }
+
+            #[inline(always)]
+            fn fill_bytes(&mut self, dst: &mut [u8]) {
+                self.0.fill_bytes(dst)
+            }
+
+            #[inline(always)]
+            fn fill_uninit<'a>(&mut self, dst: &'a mut [MaybeUninit<u8>]) -> &'a mut [u8] {
+                self.0.fill_uninit(dst)
+            }
+        }
+
+        impl SeedableRng for $Rng {
+            type Seed = [u8; 32];
+
+            /// Create a new generator, with lane 0 seeded as
+            /// `Xoshiro256PlusPlus::from_seed(seed)`. If `seed` is entirely 0,
+            /// it will be mapped to a different seed.
+            #[inline]
+            fn from_seed(seed: [u8; 32]) -> Self {
+                let mut state = [0; 4];
+                read_u64_into(&seed, &mut state);
+                if state.iter().all(|&x| x == 0) {
+                    return Self::seed_from_u64(0);
+                }
+                $Rng(BlockRng64::new($Core {
+                    s: lanes_from_state(state),
+                }))
+            }
+
+            /// Create a new generator from a `u64` seed, with lane 0 seeded as
+            /// `Xoshiro256PlusPlus::seed_from_u64(state)`.
+            ///
+            /// This uses the SplitMix64 generator internally.
+            #[inline]
+            fn seed_from_u64(mut state: u64) -> Self {
+                const PHI: u64 = 0x9e3779b97f4a7c15;
+                let mut s = [0; 4];
+                for i in s.iter_mut() {
+                    state = state.wrapping_add(PHI);
+                    let mut z = state;
+                    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+                    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+                    z = z ^ (z >> 31);
+                    *i = z;
+                }
+                debug_assert_ne!(s, [0; 4]);
+                $Rng(BlockRng64::new($Core {
+                    s: lanes_from_state(s),
+                }))
+            }
+        }
+    };
+}
+
+lanes_impl!(Xoshiro256PlusPlusX4, Xoshiro256PlusPlusX4Core, 4);
+lanes_impl!(Xoshiro256PlusPlusX8, Xoshiro256PlusPlusX8Core, 8);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::distr::{Distribution, StandardUniform};
+
+    #[test]
+    fn jump_commutes_with_next() {
+        let mut a = [1, 2, 3, 4];
+        let mut b = a;
+        jump(&mut a);
+        next_scalar(&mut a);
+        next_scalar(&mut b);
+        jump(&mut b);
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn reference_lane0() {
+        let seed = [
+            1, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0,
+            0, 0, 0,
+        ];
+        // Produced with the reference implementation (see xoshiro256plusplus.rs)
+        let expected = [
+            41943041,
+            58720359,
+            3588806011781223,
+            3591011842654386,
+            9228616714210784205,
+        ];
+        let mut rng = Xoshiro256PlusPlusX4::from_seed(seed);
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+            for _ in 1..4 {
+                rng.next_u64();
+            }
+        }
+        let mut rng = Xoshiro256PlusPlusX8::from_seed(seed);
+        for &e in &expected {
+            assert_eq!(rng.next_u64(), e);
+            for _ in 1..8 {
+                rng.next_u64();
+            }
+        }
+    }
+
+    macro_rules! lanes_tests {
+        ($name:ident, $Rng:ident) => {
+            mod $name {
+                use super::*;
+
+                // Each lane is the scalar generator, jumped once per lane
+                #[cfg(target_pointer_width = "64")]
+                #[test]
+                fn lanes_vs_scalar() {
+                    use crate::rngs::xoshiro256plusplus::Xoshiro256PlusPlus;
+
+                    let mut seed = [0u8; 32];
+                    for (i, b) in seed.iter_mut().enumerate() {
+                        *b = i as u8 + 1;
+                    }
+                    let mut rng = $Rng::from_seed(seed);
+
+                    let mut state = [0; 4];
+                    read_u64_into(&seed, &mut state);
+                    let mut lanes = [[0u64; 4]; $Rng::LANES];
+                    for lane in lanes.iter_mut() {
+                        *lane = state;
+                        jump(&mut state);
+                    }
+
+                    let mut scalar: [Xoshiro256PlusPlus; $Rng::LANES] = core::array::from_fn(|i| {
+                        let mut seed = [0u8; 32];
+                        for (b, x) in seed.chunks_exact_mut(8).zip(lanes[i].iter()) {
+                            b.copy_from_slice(&x.to_le_bytes());
+                        }
+                        Xoshiro256PlusPlus::from_seed(seed)
+                    });
+                    for _ in 0..100 {
+                        for s in scalar.iter_mut() {
+                            assert_eq!(rng.next_u64(), s.next_u64());
+                        }
+                    }
+                }
+
+                #[test]
+                fn fill_u64_vs_next_u64() {
+                    let mut rng1 = $Rng::seed_from_u64(1);
+                    let mut rng2 = rng1.clone();
+                    for &len in &[0, 1, 3, 7, 8, 9, 31, 100] {
+                        let mut a = [0u64; 100];
+                        rng1.fill_u64(&mut a[..len]);
+                        for &x in &a[..len] {
+                            assert_eq!(x, rng2.next_u64());
+                        }
+                        rng1.next_u32();
+                        rng2.next_u32();
+                    }
+                    assert_eq!(rng1.next_u64(), rng2.next_u64());
+                }
+
+                #[test]
+                fn fill_f64_vs_standard() {
+                    let mut rng1 = $Rng::seed_from_u64(2);
+                    let mut rng2 = rng1.clone();
+                    let mut a = [0f64; 301];
+                    rng1.fill_f64(&mut a);
+                    for &x in &a[..] {
+                        let y: f64 = StandardUniform.sample(&mut rng2);
+                        assert_eq!(x, y);
+                    }
+                }
+
+                #[test]
+                fn fill_bytes_vs_next_u64() {
+                    let mut rng1 = $Rng::seed_from_u64(3);
+                    let mut rng2 = rng1.clone();
+                    rng1.next_u64();
+                    rng2.next_u64();
+                    let mut a = [0u8; 8 * 37];
+                    rng1.fill_bytes(&mut a);
+                    for chunk in a.chunks(8) {
+                        assert_eq!(chunk, &rng2.next_u64().to_le_bytes());
+                    }
+                }
+
+                #[test]
+                fn fill_uninit_vs_fill_bytes() {
+                    let mut rng1 = $Rng::seed_from_u64(4);
+                    let mut rng2 = rng1.clone();
+                    rng1.next_u64();
+                    rng2.next_u64();
+                    let mut a = [0u8; 8 * 37];
+                    let mut b = [MaybeUninit::uninit(); 8 * 37];
+                    rng1.fill_bytes(&mut a);
+                    assert_eq!(rng2.fill_uninit(&mut b), &a[..]);
+                    assert_eq!(rng1.next_u64(), rng2.next_u64());
+                }
+            }
+        };
+    }
+
+    lanes_tests!(x4, Xoshiro256PlusPlusX4);
+    lanes_tests!(x8, Xoshiro256PlusPlusX8);
+}
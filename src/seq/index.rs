@@ -287,7 +287,8 @@ where
 /// Results are in arbitrary order (there is no guarantee of shuffling or
 /// ordering).
 ///
-/// Function `weight` is called once for each index to provide weights.
+/// Function `weight` is called once for each index to provide weights. For
+/// weights given as logarithms, see [`sample_weighted_log`].
 ///
 /// This method is used internally by the slice sampling methods, but it can
 /// sometimes be useful to have the indices themselves so this is provided as
@@ -432,6 +433,149 @@ where
     ))
 }
 
+/// Randomly sample exactly `amount` distinct indices from `0..length`, with
+/// weights given as natural logarithms
+///
+/// Results are in arbitrary order (there is no guarantee of shuffling or
+/// ordering).
+///
+/// Function `log_weight` is called once for each index to provide the
+/// log-weight. The result has the same distribution as [`sample_weighted`]
+/// with weights `exp(log_weight(i))`, but log-weights may be any finite value,
+/// for example log-likelihoods, or logarithms of weights too small or too
+/// large to represent. A log-weight of `-∞` is a weight of zero; such indices
+/// are never picked.
+///
+/// Error cases:
+/// -   [`WeightError::InvalidWeight`] when a log-weight is not-a-number or `+∞`.
+/// -   [`WeightError::InsufficientNonZero`] when fewer than `amount` log-weights are
+///     greater than `-∞`.
+///
+/// This implementation uses the Gumbel-top-k trick: it keeps the `amount`
+/// indices with the largest keys `log_weight(i) + Gᵢ`, where the `Gᵢ` are
+/// independent samples from the standard [Gumbel distribution]. It uses
+/// `O(amount)` space and `O(length log amount)` time.
+///
+/// [Gumbel distribution]: crate::distr::Gumbel
+#[cfg(all(feature = "std", feature = "distr_math"))]
+pub fn sample_weighted_log<R, F, X>(
+    rng: &mut R,
+    length: usize,
+    log_weight: F,
+    amount: usize,
+) -> Result<IndexVec, WeightError>
+where
+    R: Rng + ?Sized,
+    F: Fn(usize) -> X,
+    X: Into<f64>,
+{
+    if length > (u32::MAX as usize) {
+        #[cfg(target_pointer_width = "32")]
+        unreachable!();
+
+        #[cfg(target_pointer_width = "64")]
+        {
+            let amount = amount as u64;
+            let length = length as u64;
+            sample_gumbel_top_k(rng, length, log_weight, amount)
+        }
+    } else {
+        assert!(amount <= u32::MAX as usize);
+        let amount = amount as u32;
+        let length = length as u32;
+        sample_gumbel_top_k(rng, length, log_weight, amount)
+    }
+}
+
+/// Randomly sample exactly `amount` distinct indices from `0..length` with
+/// log-weights given by `log_weight`, using the Gumbel-top-k trick.
+///
+/// Error cases:
+/// -   [`WeightError::InvalidWeight`] when a log-weight is not-a-number or `+∞`.
+/// -   [`WeightError::InsufficientNonZero`] when fewer than `amount` log-weights are
+///     greater than `-∞`.
+#[cfg(all(feature = "std", feature = "distr_math"))]
+fn sample_gumbel_top_k<R, F, X, N>(
+    rng: &mut R,
+    length: N,
+    log_weight: F,
+    amount: N,
+) -> Result<IndexVec, WeightError>
+where
+    R: Rng + ?Sized,
+    F: Fn(usize) -> X,
+    X: Into<f64>,
+    N: UInt,
+    IndexVec: From<Vec<N>>,
+{
+    use crate::distr::Exp1;
+    use num_traits::Float;
+    use std::{cmp::Ordering, collections::BinaryHeap};
+
+    if amount == N::zero() {
+        return Ok(IndexVec::U32(Vec::new()));
+    }
+
+    struct Element<N> {
+        index: N,
+        key: f64,
+    }
+
+    impl<N> PartialOrd for Element<N> {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl<N> Ord for Element<N> {
+        fn cmp(&self, other: &Self) -> Ordering {
+            // Keys are never NaN. We reverse so that BinaryHeap::peek shows
+            // the smallest item
+            self.key.total_cmp(&other.key).reverse()
+        }
+    }
+
+    impl<N> PartialEq for Element<N> {
+        fn eq(&self, other: &Self) -> bool {
+            self.key == other.key
+        }
+    }
+
+    impl<N> Eq for Element<N> {}
+
+    let mut candidates = BinaryHeap::with_capacity(amount.as_usize());
+    let mut index = N::zero();
+    while index < length {
+        let log_weight = log_weight(index.as_usize()).into();
+        if log_weight.is_nan() || log_weight == f64::INFINITY {
+            return Err(WeightError::InvalidWeight);
+        }
+        if log_weight > f64::NEG_INFINITY {
+            // A standard Gumbel sample is `-ln(E)` where `E` is `Exp(1)`.
+            // Adding it (rather than scaling) keeps keys accurate for any
+            // finite log-weight.
+            let e: f64 = rng.sample(Exp1);
+            let key = log_weight - Float::ln(e);
+            if candidates.len() < amount.as_usize() {
+                candidates.push(Element { index, key });
+            } else if key > candidates.peek().unwrap().key {
+                candidates.pop();
+                candidates.push(Element { index, key });
+            }
+        }
+
+        index += N::one();
+    }
+
+    if candidates.len() < amount.as_usize() {
+        return Err(WeightError::InsufficientNonZero);
+    }
+
+    Ok(IndexVec::from(
+        candidates.iter().map(|elt| elt.index).collect(),
+    ))
+}
+
 /// Randomly sample exactly `amount` indices from `0..length`, using Floyd's
 /// combination algorithm.
 ///
@@ -656,6 +800,85 @@ mod test {
         assert_eq!(r.unwrap_err(), WeightError::InsufficientNonZero);
     }
 
+    #[cfg(all(feature = "std", feature = "distr_math"))]
+    #[test]
+    fn test_sample_weighted_log() {
+        let seed_rng = crate::test::rng;
+        for &(amount, len) in &[(0, 10), (5, 10), (9, 10), (3, 1000)] {
+            let log_weight = |i| -1e6 + (i as f64).ln();
+            let v = sample_weighted_log(&mut seed_rng(424), len, log_weight, amount).unwrap();
+            let mut indices = v.into_vec();
+            assert_eq!(indices.len(), amount);
+            indices.sort_unstable();
+            indices.dedup();
+            assert_eq!(indices.len(), amount);
+            assert!(indices.iter().all(|&i| 0 < i && i < len));
+        }
+
+        let r = sample_weighted_log(&mut seed_rng(424), 10, |i| (i as f64).ln(), 10);
+        assert_eq!(r.unwrap_err(), WeightError::InsufficientNonZero);
+        let r = sample_weighted_log(&mut seed_rng(424), 10, |i| [0.0, f64::NAN][i % 2], 1);
+        assert_eq!(r.unwrap_err(), WeightError::InvalidWeight);
+        let r = sample_weighted_log(&mut seed_rng(424), 10, |_| f64::INFINITY, 1);
+        assert_eq!(r.unwrap_err(), WeightError::InvalidWeight);
+    }
+
+    #[cfg(all(feature = "std", feature = "distr_math"))]
+    #[test]
+    #[cfg_attr(miri, ignore)] // Miri is too slow
+    fn test_sample_weighted_log_distribution() {
+        // Sample two of four indices with weights 1, 2, 3, 4, returning the
+        // index of the (unordered) pair
+        struct Pairs {
+            log_offset: Option<f64>,
+        }
+        impl Distribution<u64> for Pairs {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+                let weight = |i: usize| (i + 1) as f64;
+                let v = match self.log_offset {
+                    Some(offset) => {
+                        sample_weighted_log(rng, 4, |i| offset + weight(i).ln(), 2).unwrap()
+                    }
+                    None => sample_weighted(rng, 4, weight, 2).unwrap(),
+                };
+                let (i, j) = (v.index(0).min(v.index(1)), v.index(0).max(v.index(1)));
+                [0, 0, 1, 2, 0, 3, 4, 0, 0, 5][i * 3 + j] as u64
+            }
+        }
+
+        let w = [1.0, 2.0, 3.0, 4.0];
+        let p = |i: usize, j: usize| w[i] / 10.0 * w[j] / (10.0 - w[i]);
+        let mut pmf = [0.0; 6];
+        let pairs = [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)];
+        for (k, &(i, j)) in pairs.iter().enumerate() {
+            pmf[k] = p(i, j) + p(j, i);
+        }
+
+        let check = crate::test::check_discrete_gof;
+        check(Pairs { log_offset: None }, 425, &pmf);
+        check(
+            Pairs {
+                log_offset: Some(0.0),
+            },
+            426,
+            &pmf,
+        );
+        check(
+            Pairs {
+                log_offset: Some(-1e4),
+            },
+            427,
+            &pmf,
+        );
+        check(
+            Pairs {
+                log_offset: Some(1e4),
+            },
+            428,
+            &pmf,
+        );
+    }
+
     #[test]
     fn value_stability_sample() {
         let do_test = |length, amount, values: &[u32]| {
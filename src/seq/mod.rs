@@ -24,7 +24,10 @@
 //!
 //! In order to make results reproducible across 32-64 bit architectures, all
 //! `usize` indices are sampled as a `u32` where possible (also providing a
-//! small performance boost in some cases).
+//! small performance boost in some cases). This is only best-effort: results
+//! may also depend on crate features and may change in future releases. Where
+//! identical results on every platform are required, use the functions of
+//! [`crate::stable::v1`] instead.
 
 mod coin_flipper;
 mod increasing_uniform;
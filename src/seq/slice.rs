@@ -93,11 +93,7 @@ pub trait IndexedRandom: Index<usize> {
         R: Rng + ?Sized,
     {
         let amount = core::cmp::min(amount, self.len());
-        SliceChooseIter {
-            slice: self,
-            _phantom: Default::default(),
-            indices: index::sample(rng, self.len(), amount).into_iter(),
-        }
+        SliceChooseIter::new(self, index::sample(rng, self.len(), amount))
     }
 
     /// Uniformly sample a fixed-size array of distinct elements from self
@@ -429,6 +425,17 @@ pub struct SliceChooseIter<'a, S: ?Sized + 'a, T: 'a> {
     indices: index::IndexVecIntoIter,
 }
 
+#[cfg(feature = "alloc")]
+impl<'a, S: Index<usize, Output = T> + ?Sized + 'a, T: 'a> SliceChooseIter<'a, S, T> {
+    pub(crate) fn new(slice: &'a S, indices: index::IndexVec) -> Self {
+        SliceChooseIter {
+            slice,
+            _phantom: Default::default(),
+            indices: indices.into_iter(),
+        }
+    }
+}
+
 #[cfg(feature = "alloc")]
 impl<'a, S: Index<usize, Output = T> + ?Sized + 'a, T: 'a> Iterator for SliceChooseIter<'a, S, T> {
     type Item = &'a T;
@@ -0,0 +1,50 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Versioned, value-stable sampling algorithms
+//!
+//! The distributions and sequence methods elsewhere in this crate are only
+//! value-stable on a *best-effort* basis: their algorithms may change in
+//! future releases, and some results depend on crate features (e.g.
+//! `unbiased`). This is usually what you want, since it allows the defaults
+//! to improve over time.
+//!
+//! Where results must be reproducible (for example in regression fixtures or
+//! replayed simulations), use a versioned module instead. Each version is
+//! frozen once released: given the same random number generator output, its
+//! results will never change, do not depend on crate features, and are the
+//! same on all platforms. Improved algorithms are introduced in new versions,
+//! alongside the old ones.
+//!
+//! Note that this only covers the sampling algorithm. For fully reproducible
+//! results, a [portable] generator is also required, such as
+//! [`StdRngV1`] or [`SmallRngV1`].
+//!
+//! # Example
+//!
+//! ```
+//! use rand::stable::v1;
+//! use rand::{Rng, SeedableRng};
+//! # #[cfg(feature = "small_rng")] {
+//! use rand::rngs::SmallRngV1;
+//!
+//! let mut rng = SmallRngV1::seed_from_u64(42);
+//! let die = v1::Uniform::new_inclusive(1, 6).unwrap();
+//! let roll = rng.sample(die);
+//!
+//! let mut deck: Vec<u32> = (1..=52).collect();
+//! v1::shuffle(&mut deck, &mut rng);
+//! # let _ = roll;
+//! # }
+//! ```
+//!
+//! [portable]: https://rust-random.github.io/book/crate-reprod.html
+//! [`StdRngV1`]: crate::rngs::StdRngV1
+//! [`SmallRngV1`]: crate::rngs::SmallRngV1
+
+pub mod v1;
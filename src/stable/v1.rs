@@ -0,0 +1,1120 @@
+// Copyright 2025 Developers of the Rand project.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// https://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or https://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Version 1 of the value-stable sampling algorithms
+//!
+//! These are copies of the default algorithms of rand 0.9 without the
+//! `unbiased` feature. They are implemented in this module rather than
+//! forwarded, so that changes to the default algorithms cannot affect them:
+//!
+//! -   [`StandardUniform`], [`Open01`] and [`OpenClosed01`] match the
+//!     distributions of the same name in [`crate::distr`], for the types they
+//!     are implemented for here.
+//! -   [`Uniform`] and [`WeightedIndex`] match [`crate::distr::Uniform`] and
+//!     [`crate::distr::weighted::WeightedIndex`].
+//! -   [`choose`], [`choose_mut`], [`choose_multiple`], [`shuffle`] and
+//!     [`partial_shuffle`] match the methods of [`IndexedRandom`],
+//!     [`IndexedMutRandom`] and [`SliceRandom`] on slices, and
+//!     [`sample_indices`] matches [`index::sample`].
+//!
+//! # Portability
+//!
+//! Results are the same on all platforms, in particular on 32-bit and 64-bit
+//! targets: lengths and indices are always sampled as `u32`, and algorithm
+//! selection does not depend on the width of `usize`. The only exception is
+//! lengths greater than `u32::MAX`, which cannot occur on 32-bit targets; these
+//! are sampled as `u64`.
+//!
+//! See the [module-level documentation](super) for the stability guarantee.
+//!
+//! [`IndexedRandom`]: crate::seq::IndexedRandom
+//! [`IndexedMutRandom`]: crate::seq::IndexedMutRandom
+//! [`SliceRandom`]: crate::seq::SliceRandom
+//! [`index::sample`]: crate::seq::index::sample
+
+use crate::distr::uniform::{Error, SampleBorrow, SampleUniform};
+use crate::distr::utils::WideningMultiply;
+#[cfg(feature = "alloc")]
+use crate::distr::weighted::{Error as WeightError, Weight};
+use crate::distr::Distribution;
+#[cfg(feature = "alloc")]
+use crate::seq::index::IndexVec;
+#[cfg(feature = "alloc")]
+use crate::seq::SliceChooseIter;
+use crate::Rng;
+#[cfg(not(feature = "std"))]
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeSet;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::hash::Hash;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+
+mod private {
+    use crate::distr::uniform::Error;
+    use crate::Rng;
+    #[cfg(feature = "serde")]
+    use serde::{Deserialize, Serialize};
+
+    pub trait Sealed: Copy {
+        type SamplerV1: Clone + Copy + core::fmt::Debug + PartialEq;
+
+        fn new_v1(low: Self, high: Self) -> Result<Self::SamplerV1, Error>;
+        fn new_inclusive_v1(low: Self, high: Self) -> Result<Self::SamplerV1, Error>;
+        fn sample_v1<R: Rng + ?Sized>(sampler: &Self::SamplerV1, rng: &mut R) -> Self;
+    }
+
+    /// Integer sampler using Lemire's method
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct UniformInt<X> {
+        pub(super) low: X,
+        pub(super) range: X,
+        pub(super) thresh: X,
+    }
+
+    /// `usize` sampler: as [`UniformInt<u32>`] if the upper bound fits in
+    /// `u32`, otherwise as [`UniformInt<u64>`]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct UniformUsize {
+        pub(super) low: u64,
+        pub(super) range: u64,
+        pub(super) thresh: u64,
+        pub(super) mode64: bool,
+    }
+
+    /// Float sampler: `low + scale * value0_1`
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+    pub struct UniformFloat<X> {
+        pub(super) low: X,
+        pub(super) scale: X,
+    }
+}
+
+/// Types supported by [`Uniform`] and [`WeightedIndex`]
+///
+/// This trait is sealed: it is implemented for all primitive integer types
+/// except `isize`, for `f32` and for `f64`.
+pub trait SampleUniformV1: SampleUniform + private::Sealed {}
+
+/// The standard distribution, version 1
+///
+/// Implemented for all primitive integer types except `usize` and `isize`
+/// (whose output would depend on the platform), and for `bool`, `char`,
+/// `f32` and `f64`.
+///
+/// See [`crate::distr::StandardUniform`] for details of the output.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct StandardUniform;
+
+/// A distribution to sample floating point numbers uniformly in the open
+/// interval `(0, 1)`, version 1
+///
+/// See [`crate::distr::Open01`] for details.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Open01;
+
+/// A distribution to sample floating point numbers uniformly in the half-open
+/// interval `(0, 1]`, version 1
+///
+/// See [`crate::distr::OpenClosed01`] for details.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct OpenClosed01;
+
+impl Distribution<u32> for StandardUniform {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u32 {
+        rng.next_u32()
+    }
+}
+
+impl Distribution<u64> for StandardUniform {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        rng.next_u64()
+    }
+}
+
+impl Distribution<u128> for StandardUniform {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u128 {
+        // Use LE; we explicitly generate one value before the next.
+        let x = u128::from(rng.next_u64());
+        let y = u128::from(rng.next_u64());
+        (y << 64) | x
+    }
+}
+
+macro_rules! standard_int_impl {
+    ($($ty:ty => $uty:ty),*) => {$(
+        impl Distribution<$ty> for StandardUniform {
+            #[inline]
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $ty {
+                Distribution::<$uty>::sample(self, rng) as $ty
+            }
+        }
+    )*};
+}
+
+standard_int_impl!(u8 => u32, u16 => u32, i8 => u32, i16 => u32, i32 => u32);
+standard_int_impl!(i64 => u64, i128 => u128);
+
+impl Distribution<bool> for StandardUniform {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> bool {
+        // We can compare against an arbitrary bit of an u32 to get a bool.
+        // Because the least significant bits of a lower quality RNG can have
+        // simple patterns, we compare against the most significant bit.
+        (rng.next_u32() as i32) < 0
+    }
+}
+
+impl Distribution<char> for StandardUniform {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
+        // A valid `char` is either in the interval `[0, 0xD800)` or
+        // `(0xDFFF, 0x11_0000)`. All `char`s must therefore be in
+        // `[0, 0x11_0000)` but not in the "gap" `[0xD800, 0xDFFF]` which is
+        // reserved for surrogates. This is the size of that gap.
+        const GAP_SIZE: u32 = 0xDFFF - 0xD800 + 1;
+
+        // Uniform::new(0, 0x11_0000 - GAP_SIZE) can also be used, but it
+        // seemed slower.
+        let range = Uniform::new(GAP_SIZE, 0x11_0000).unwrap();
+
+        let mut n = range.sample(rng);
+        if n <= 0xDFFF {
+            n -= GAP_SIZE;
+        }
+        char::from_u32(n).unwrap()
+    }
+}
+
+macro_rules! standard_float_impl {
+    ($ty:ident, $uty:ident, $fraction_bits:expr) => {
+        impl Distribution<$ty> for StandardUniform {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $ty {
+                // Multiply-based method; 24/53 random bits; [0, 1) interval.
+                // We use the most significant bits because for simple RNGs
+                // those are usually more random.
+                let float_size = $uty::BITS;
+                let precision = $fraction_bits + 1;
+                let scale = 1.0 / (((1 as $uty) << precision) as $ty);
+
+                let value: $uty = StandardUniform.sample(rng);
+                let value = value >> (float_size - precision);
+                scale * (value as $ty)
+            }
+        }
+
+        impl Distribution<$ty> for OpenClosed01 {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $ty {
+                // Multiply-based method; 24/53 random bits; (0, 1] interval.
+                let float_size = $uty::BITS;
+                let precision = $fraction_bits + 1;
+                let scale = 1.0 / (((1 as $uty) << precision) as $ty);
+
+                let value: $uty = StandardUniform.sample(rng);
+                let value = value >> (float_size - precision);
+                // Add 1 to shift up; will not overflow because of right-shift:
+                scale * ((value + 1) as $ty)
+            }
+        }
+
+        impl Distribution<$ty> for Open01 {
+            fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> $ty {
+                // Transmute-based method; 23/52 random bits; (0, 1) interval.
+                // We use the most significant bits because for simple RNGs
+                // those are usually more random.
+                let float_size = $uty::BITS;
+
+                let value: $uty = StandardUniform.sample(rng);
+                let fraction = value >> (float_size - $fraction_bits);
+                // Set the exponent of 1.0, giving a value in [1, 2)
+                let value1_2 = $ty::from_bits(fraction | (1.0 as $ty).to_bits());
+                value1_2 - (1.0 - $ty::EPSILON / 2.0)
+            }
+        }
+    };
+}
+
+standard_float_impl!(f32, u32, 23);
+standard_float_impl!(f64, u64, 52);
+
+macro_rules! uniform_int_impl {
+    ($ty:ty, $uty:ty, $sample_ty:ty) => {
+        impl private::Sealed for $ty {
+            type SamplerV1 = private::UniformInt<$ty>;
+
+            #[inline]
+            fn new_v1(low: $ty, high: $ty) -> Result<Self::SamplerV1, Error> {
+                if !(low < high) {
+                    return Err(Error::EmptyRange);
+                }
+                Self::new_inclusive_v1(low, high - 1)
+            }
+
+            #[inline]
+            fn new_inclusive_v1(low: $ty, high: $ty) -> Result<Self::SamplerV1, Error> {
+                if !(low <= high) {
+                    return Err(Error::EmptyRange);
+                }
+
+                let range = high.wrapping_sub(low).wrapping_add(1) as $uty;
+                let thresh = if range > 0 {
+                    let range = <$sample_ty>::from(range);
+                    (range.wrapping_neg() % range)
+                } else {
+                    0
+                };
+
+                Ok(private::UniformInt {
+                    low,
+                    range: range as $ty,           // type: $uty
+                    thresh: thresh as $uty as $ty, // type: $sample_ty
+                })
+            }
+
+            #[inline]
+            fn sample_v1<R: Rng + ?Sized>(sampler: &Self::SamplerV1, rng: &mut R) -> $ty {
+                let range = sampler.range as $uty as $sample_ty;
+                if range == 0 {
+                    return StandardUniform.sample(rng);
+                }
+
+                let thresh = sampler.thresh as $uty as $sample_ty;
+                let hi = loop {
+                    let v: $sample_ty = StandardUniform.sample(rng);
+                    let (hi, lo) = v.wmul(range);
+                    if lo >= thresh {
+                        break hi;
+                    }
+                };
+                sampler.low.wrapping_add(hi as $ty)
+            }
+        }
+
+        impl SampleUniformV1 for $ty {}
+    };
+}
+
+uniform_int_impl!(i8, u8, u32);
+uniform_int_impl!(i16, u16, u32);
+uniform_int_impl!(i32, u32, u32);
+uniform_int_impl!(i64, u64, u64);
+uniform_int_impl!(i128, u128, u128);
+uniform_int_impl!(u8, u8, u32);
+uniform_int_impl!(u16, u16, u32);
+uniform_int_impl!(u32, u32, u32);
+uniform_int_impl!(u64, u64, u64);
+uniform_int_impl!(u128, u128, u128);
+
+impl private::Sealed for usize {
+    type SamplerV1 = private::UniformUsize;
+
+    #[inline]
+    fn new_v1(low: usize, high: usize) -> Result<Self::SamplerV1, Error> {
+        if !(low < high) {
+            return Err(Error::EmptyRange);
+        }
+        Self::new_inclusive_v1(low, high - 1)
+    }
+
+    #[inline]
+    fn new_inclusive_v1(low: usize, high: usize) -> Result<Self::SamplerV1, Error> {
+        if !(low <= high) {
+            return Err(Error::EmptyRange);
+        }
+
+        // Always false on 32-bit targets
+        let mode64 = high as u64 > u64::from(u32::MAX);
+        let (low, high) = (low as u64, high as u64);
+
+        let (range, thresh);
+        if !mode64 {
+            let range32 = (high as u32).wrapping_sub(low as u32).wrapping_add(1);
+            range = u64::from(range32);
+            thresh = if range32 > 0 {
+                u64::from(range32.wrapping_neg() % range32)
+            } else {
+                0
+            };
+        } else {
+            range = high.wrapping_sub(low).wrapping_add(1);
+            thresh = if range > 0 {
+                range.wrapping_neg() % range
+            } else {
+                0
+            };
+        }
+
+        Ok(private::UniformUsize {
+            low,
+            range,
+            thresh,
+            mode64,
+        })
+    }
+
+    #[inline]
+    fn sample_v1<R: Rng + ?Sized>(sampler: &Self::SamplerV1, rng: &mut R) -> usize {
+        if !sampler.mode64 {
+            let range = sampler.range as u32;
+            if range == 0 {
+                return rng.next_u32() as usize;
+            }
+
+            let thresh = sampler.thresh as u32;
+            let hi = loop {
+                let (hi, lo) = rng.next_u32().wmul(range);
+                if lo >= thresh {
+                    break hi;
+                }
+            };
+            (sampler.low as u32).wrapping_add(hi) as usize
+        } else {
+            let range = sampler.range;
+            if range == 0 {
+                return rng.next_u64() as usize;
+            }
+
+            let thresh = sampler.thresh;
+            let hi = loop {
+                let (hi, lo) = rng.next_u64().wmul(range);
+                if lo >= thresh {
+                    break hi;
+                }
+            };
+            sampler.low.wrapping_add(hi) as usize
+        }
+    }
+}
+
+impl SampleUniformV1 for usize {}
+
+macro_rules! uniform_float_impl {
+    ($ty:ident, $uty:ident, $bits_to_discard:expr) => {
+        impl private::Sealed for $ty {
+            type SamplerV1 = private::UniformFloat<$ty>;
+
+            fn new_v1(low: $ty, high: $ty) -> Result<Self::SamplerV1, Error> {
+                if !low.is_finite() || !high.is_finite() {
+                    return Err(Error::NonFinite);
+                }
+                if !(low < high) {
+                    return Err(Error::EmptyRange);
+                }
+
+                let scale = high - low;
+                if !scale.is_finite() {
+                    return Err(Error::NonFinite);
+                }
+
+                Ok(uniform_float_new_bounded!($ty, low, high, scale))
+            }
+
+            fn new_inclusive_v1(low: $ty, high: $ty) -> Result<Self::SamplerV1, Error> {
+                if !low.is_finite() || !high.is_finite() {
+                    return Err(Error::NonFinite);
+                }
+                if !(low <= high) {
+                    return Err(Error::EmptyRange);
+                }
+
+                let max_rand = 1.0 - $ty::EPSILON;
+                let scale = (high - low) / max_rand;
+                if !scale.is_finite() {
+                    return Err(Error::NonFinite);
+                }
+
+                Ok(uniform_float_new_bounded!($ty, low, high, scale))
+            }
+
+            fn sample_v1<R: Rng + ?Sized>(sampler: &Self::SamplerV1, rng: &mut R) -> $ty {
+                // Generate a value in the range [1, 2)
+                let value: $uty = StandardUniform.sample(rng);
+                let value1_2 = $ty::from_bits((value >> $bits_to_discard) | (1.0 as $ty).to_bits());
+
+                // Get a value in the range [0, 1) to avoid overflow when
+                // multiplying by scale. The order of multiplication and
+                // addition must not change.
+                let value0_1 = value1_2 - 1.0;
+                value0_1 * sampler.scale + sampler.low
+            }
+        }
+
+        impl SampleUniformV1 for $ty {}
+    };
+}
+
+/// Construct a float sampler, reducing `scale` as required to ensure that
+/// rounding can never yield values greater than `high`
+macro_rules! uniform_float_new_bounded {
+    ($ty:ident, $low:expr, $high:expr, $scale:expr) => {{
+        let (low, high, mut scale) = ($low, $high, $scale);
+        let max_rand = 1.0 - $ty::EPSILON;
+        while scale * max_rand + low > high {
+            scale = $ty::from_bits(scale.to_bits() - 1);
+        }
+        debug_assert!(0.0 <= scale);
+        private::UniformFloat { low, scale }
+    }};
+}
+
+uniform_float_impl!(f32, u32, 32 - 23);
+uniform_float_impl!(f64, u64, 64 - 52);
+
+/// Sample values uniformly between two bounds, version 1
+///
+/// Integers are sampled using Lemire's method (widening multiply with
+/// rejection), without bias. Floats are sampled as `low + scale * x` where `x`
+/// is uniform over `[0, 1)` with 23 or 52 bits of precision and `scale` is
+/// adjusted so that the result never exceeds `high`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(serialize = "X::SamplerV1: Serialize")))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "X::SamplerV1: Deserialize<'de>"))
+)]
+pub struct Uniform<X: SampleUniformV1>(X::SamplerV1);
+
+impl<X: SampleUniformV1> Uniform<X> {
+    /// Create a new `Uniform` instance, which samples uniformly from the half
+    /// open range `[low, high)` (excluding `high`).
+    ///
+    /// Fails with [`Error::EmptyRange`] if `low >= high`, or with
+    /// [`Error::NonFinite`] if `low`, `high` or `high - low` is not finite.
+    pub fn new<B1, B2>(low: B1, high: B2) -> Result<Uniform<X>, Error>
+    where
+        B1: SampleBorrow<X> + Sized,
+        B2: SampleBorrow<X> + Sized,
+    {
+        X::new_v1(*low.borrow(), *high.borrow()).map(Uniform)
+    }
+
+    /// Create a new `Uniform` instance, which samples uniformly from the closed
+    /// range `[low, high]` (inclusive).
+    ///
+    /// Fails with [`Error::EmptyRange`] if `low > high`, or with
+    /// [`Error::NonFinite`] if `low`, `high` or `high - low` is not finite.
+    pub fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Uniform<X>, Error>
+    where
+        B1: SampleBorrow<X> + Sized,
+        B2: SampleBorrow<X> + Sized,
+    {
+        X::new_inclusive_v1(*low.borrow(), *high.borrow()).map(Uniform)
+    }
+}
+
+impl<X: SampleUniformV1> Distribution<X> for Uniform<X> {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> X {
+        X::sample_v1(&self.0, rng)
+    }
+}
+
+/// A distribution using weighted sampling of discrete items, version 1
+///
+/// Samples a weight uniformly from `[0, total_weight)` using [`Uniform`], then
+/// returns the index of the first item whose cumulative weight exceeds it.
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(serialize = "X: Serialize, X::SamplerV1: Serialize"))
+)]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(deserialize = "X: Deserialize<'de>, X::SamplerV1: Deserialize<'de>"))
+)]
+pub struct WeightedIndex<X: SampleUniformV1 + PartialOrd> {
+    cumulative_weights: Vec<X>,
+    total_weight: X,
+    weight_distribution: Uniform<X>,
+}
+
+#[cfg(feature = "alloc")]
+impl<X: SampleUniformV1 + PartialOrd> WeightedIndex<X> {
+    /// Creates a new `WeightedIndex` from the given weights
+    ///
+    /// Error cases:
+    /// -   [`WeightError::InvalidInput`] when the iterator `weights` is empty.
+    /// -   [`WeightError::InvalidWeight`] when a weight is not-a-number or
+    ///     negative.
+    /// -   [`WeightError::InsufficientNonZero`] when the sum of all weights is
+    ///     zero.
+    /// -   [`WeightError::Overflow`] when the sum of all weights overflows.
+    pub fn new<I>(weights: I) -> Result<WeightedIndex<X>, WeightError>
+    where
+        I: IntoIterator,
+        I::Item: SampleBorrow<X>,
+        X: Weight,
+    {
+        let mut iter = weights.into_iter();
+        let mut total_weight: X = *iter.next().ok_or(WeightError::InvalidInput)?.borrow();
+
+        let zero = X::ZERO;
+        if !(total_weight >= zero) {
+            return Err(WeightError::InvalidWeight);
+        }
+
+        let mut weights = Vec::<X>::with_capacity(iter.size_hint().0);
+        for w in iter {
+            // Note that `!(w >= x)` is not equivalent to `w < x` for partially
+            // ordered types due to NaNs which are equal to nothing.
+            if !(w.borrow() >= &zero) {
+                return Err(WeightError::InvalidWeight);
+            }
+            weights.push(total_weight);
+
+            if let Err(()) = total_weight.checked_add_assign(w.borrow()) {
+                return Err(WeightError::Overflow);
+            }
+        }
+
+        if total_weight == zero {
+            return Err(WeightError::InsufficientNonZero);
+        }
+        let distr = Uniform::new(zero, total_weight).unwrap();
+
+        Ok(WeightedIndex {
+            cumulative_weights: weights,
+            total_weight,
+            weight_distribution: distr,
+        })
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<X: SampleUniformV1 + PartialOrd> Distribution<usize> for WeightedIndex<X> {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> usize {
+        let chosen_weight = self.weight_distribution.sample(rng);
+        // Find the first item which has a weight *higher* than the chosen weight.
+        self.cumulative_weights
+            .partition_point(|w| w <= &chosen_weight)
+    }
+}
+
+macro_rules! canon_impl {
+    ($name:ident, $ty:ty, $next:ident) => {
+        /// Sample from `low..=high` using Canon's method, with a single extra
+        /// sample to reduce bias
+        fn $name<R: Rng + ?Sized>(rng: &mut R, low: $ty, high: $ty) -> $ty {
+            debug_assert!(low <= high);
+            let range = high.wrapping_sub(low).wrapping_add(1);
+            if range == 0 {
+                return rng.$next();
+            }
+
+            let (mut result, lo_order) = rng.$next().wmul(range);
+            if lo_order > range.wrapping_neg() {
+                let (new_hi_order, _) = rng.$next().wmul(range);
+                let is_overflow = lo_order.checked_add(new_hi_order).is_none();
+                result += is_overflow as $ty;
+            }
+            low.wrapping_add(result)
+        }
+    };
+}
+
+canon_impl!(range_u32, u32, next_u32);
+canon_impl!(range_u64, u64, next_u64);
+
+/// Sample an index from `0..ubound`, where `ubound > 0`
+#[inline]
+fn sample_index<R: Rng + ?Sized>(rng: &mut R, ubound: usize) -> usize {
+    if ubound <= (u32::MAX as usize) {
+        range_u32(rng, 0, (ubound - 1) as u32) as usize
+    } else {
+        range_u64(rng, 0, (ubound - 1) as u64) as usize
+    }
+}
+
+/// Calculate `bound`, `count` such that `bound = m * (m + 1) * .. * (m + count - 1)`
+/// is the largest such product fitting in `u32`
+#[inline]
+fn calculate_bound_u32(m: u32) -> (u32, u8) {
+    debug_assert!(m > 0);
+    #[inline]
+    const fn inner(m: u32) -> (u32, u8) {
+        let mut product = m;
+        let mut current = m + 1;
+
+        loop {
+            if let Some(p) = u32::checked_mul(product, current) {
+                product = p;
+                current += 1;
+            } else {
+                // Count has a maximum value of 13 for when min is 1 or 2
+                let count = (current - m) as u8;
+                return (product, count);
+            }
+        }
+    }
+
+    const RESULT2: (u32, u8) = inner(2);
+    if m == 2 {
+        return RESULT2;
+    }
+
+    inner(m)
+}
+
+/// Sample indices from `0..=n`, `0..=n+1`, `0..=n+2`, ..., combining several
+/// small ranges into one sample where possible
+struct IncreasingUniform {
+    n: u32,
+    // Chunk is a random number in [0, (n + 1) * (n + 2) *..* (n + chunk_remaining) )
+    chunk: u32,
+    chunk_remaining: u8,
+}
+
+impl IncreasingUniform {
+    fn new(n: u32) -> Self {
+        // If n = 0, the first number returned will always be 0
+        let chunk_remaining = if n == 0 { 1 } else { 0 };
+        IncreasingUniform {
+            n,
+            chunk: 0,
+            chunk_remaining,
+        }
+    }
+
+    #[inline]
+    fn next_index<R: Rng + ?Sized>(&mut self, rng: &mut R) -> usize {
+        let next_n = self.n + 1;
+
+        let next_chunk_remaining = self.chunk_remaining.checked_sub(1).unwrap_or_else(|| {
+            let (bound, remaining) = calculate_bound_u32(next_n);
+            self.chunk = range_u32(rng, 0, bound - 1);
+            remaining - 1
+        });
+
+        let result = if next_chunk_remaining == 0 {
+            self.chunk as usize
+        } else {
+            let r = self.chunk % next_n;
+            self.chunk /= next_n;
+            r as usize
+        };
+
+        self.chunk_remaining = next_chunk_remaining;
+        self.n = next_n;
+        result
+    }
+}
+
+/// Uniformly sample one element of `slice`, version 1
+///
+/// Returns `None` if the slice is empty.
+pub fn choose<'a, T, R: Rng + ?Sized>(slice: &'a [T], rng: &mut R) -> Option<&'a T> {
+    if slice.is_empty() {
+        None
+    } else {
+        Some(&slice[sample_index(rng, slice.len())])
+    }
+}
+
+/// Uniformly sample one element of `slice` (mut), version 1
+///
+/// Returns `None` if the slice is empty.
+pub fn choose_mut<'a, T, R: Rng + ?Sized>(slice: &'a mut [T], rng: &mut R) -> Option<&'a mut T> {
+    if slice.is_empty() {
+        None
+    } else {
+        let index = sample_index(rng, slice.len());
+        Some(&mut slice[index])
+    }
+}
+
+/// Uniformly sample `amount` distinct elements of `slice`, version 1
+///
+/// Elements are chosen without repetition and in random order, using
+/// [`sample_indices`]. If `amount` is greater than the length of the slice,
+/// all elements are returned.
+#[cfg(feature = "alloc")]
+pub fn choose_multiple<'a, T, R: Rng + ?Sized>(
+    slice: &'a [T],
+    rng: &mut R,
+    amount: usize,
+) -> SliceChooseIter<'a, [T], T> {
+    let amount = core::cmp::min(amount, slice.len());
+    SliceChooseIter::new(slice, sample_indices(rng, slice.len(), amount))
+}
+
+/// Shuffle a slice in place, version 1
+///
+/// This is the Fisher-Yates shuffle, in which all permutations are
+/// (near-)equally likely.
+pub fn shuffle<T, R: Rng + ?Sized>(slice: &mut [T], rng: &mut R) {
+    if slice.len() <= 1 {
+        return;
+    }
+    partial_shuffle(slice, rng, slice.len());
+}
+
+/// Shuffle a slice in place, but exit early, version 1
+///
+/// Returns two mutable slices from the source slice. The first contains
+/// `amount` elements randomly permuted. The second has the remaining elements
+/// that are not fully shuffled. If `amount` is greater than the length of the
+/// slice, this performs a full shuffle.
+pub fn partial_shuffle<'a, T, R: Rng + ?Sized>(
+    slice: &'a mut [T],
+    rng: &mut R,
+    amount: usize,
+) -> (&'a mut [T], &'a mut [T]) {
+    let m = slice.len().saturating_sub(amount);
+
+    if slice.len() < (u32::MAX as usize) {
+        let mut chooser = IncreasingUniform::new(m as u32);
+        for i in m..slice.len() {
+            let index = chooser.next_index(rng);
+            slice.swap(i, index);
+        }
+    } else {
+        for i in m..slice.len() {
+            let index = sample_index(rng, i + 1);
+            slice.swap(i, index);
+        }
+    }
+    let r = slice.split_at_mut(m);
+    (r.1, r.0)
+}
+
+/// Randomly sample exactly `amount` distinct indices from `0..length`, version 1
+///
+/// The order of the results is not specified, other than being stable.
+///
+/// # Panics
+///
+/// Panics if `amount > length`.
+#[cfg(feature = "alloc")]
+pub fn sample_indices<R: Rng + ?Sized>(rng: &mut R, length: usize, amount: usize) -> IndexVec {
+    if amount > length {
+        panic!("`amount` of samples must be less than or equal to `length`");
+    }
+    if length > (u32::MAX as usize) {
+        #[cfg(target_pointer_width = "32")]
+        unreachable!();
+
+        #[cfg(target_pointer_width = "64")]
+        return sample_rejection(rng, length as u64, amount as u64);
+    }
+    let amount = amount as u32;
+    let length = length as u32;
+
+    if amount < 163 {
+        const C: [[f32; 2]; 2] = [[1.6, 8.0 / 45.0], [10.0, 70.0 / 9.0]];
+        let j = usize::from(length >= 500_000);
+        let amount_fp = amount as f32;
+        let m4 = C[0][j] * amount_fp;
+        if amount > 11 && (length as f32) < (C[1][j] + m4) * amount_fp {
+            sample_inplace(rng, length, amount)
+        } else {
+            sample_floyd(rng, length, amount)
+        }
+    } else {
+        const C: [f32; 2] = [270.0, 330.0 / 9.0];
+        let j = usize::from(length >= 500_000);
+        if (length as f32) < C[j] * (amount as f32) {
+            sample_inplace(rng, length, amount)
+        } else {
+            sample_rejection(rng, length, amount)
+        }
+    }
+}
+
+/// Floyd's combination algorithm, with output fully shuffled
+#[cfg(feature = "alloc")]
+fn sample_floyd<R: Rng + ?Sized>(rng: &mut R, length: u32, amount: u32) -> IndexVec {
+    let mut indices = Vec::with_capacity(amount as usize);
+    for j in length - amount..length {
+        let t = range_u32(rng, 0, j);
+        if let Some(pos) = indices.iter().position(|&x| x == t) {
+            indices[pos] = j;
+        }
+        indices.push(t);
+    }
+    IndexVec::from(indices)
+}
+
+/// Rejection sampling with a set of the indices already chosen
+#[cfg(feature = "alloc")]
+fn sample_rejection<X, R>(rng: &mut R, length: X, amount: X) -> IndexVec
+where
+    X: SampleUniformV1 + Hash + Ord + From<u32> + Into<u64>,
+    R: Rng + ?Sized,
+    IndexVec: From<Vec<X>>,
+{
+    let amount = amount.into() as usize;
+    #[cfg(feature = "std")]
+    let mut cache = HashSet::with_capacity(amount);
+    #[cfg(not(feature = "std"))]
+    let mut cache = BTreeSet::new();
+    let distr = Uniform::new(X::from(0u32), length).unwrap();
+    let mut indices = Vec::with_capacity(amount);
+    for _ in 0..amount {
+        let mut pos = distr.sample(rng);
+        while !cache.insert(pos) {
+            pos = distr.sample(rng);
+        }
+        indices.push(pos);
+    }
+    IndexVec::from(indices)
+}
+
+/// Partial Fisher-Yates shuffle of all indices
+#[cfg(feature = "alloc")]
+fn sample_inplace<R: Rng + ?Sized>(rng: &mut R, length: u32, amount: u32) -> IndexVec {
+    let mut indices: Vec<u32> = Vec::with_capacity(length as usize);
+    indices.extend(0..length);
+    for i in 0..amount {
+        let j = range_u32(rng, i, length - 1);
+        indices.swap(i as usize, j as usize);
+    }
+    indices.truncate(amount as usize);
+    IndexVec::from(indices)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    // Tests in this module guarantee value-stability: expected values must
+    // never change. Most are shared with the (best-effort) value-stability
+    // tests of the default algorithms.
+
+    #[test]
+    fn value_stability_standard() {
+        fn test_samples<T: Copy + core::fmt::Debug + PartialEq, D: Distribution<T>>(
+            distr: &D,
+            zero: T,
+            expected: &[T],
+        ) {
+            let mut rng = crate::test::rng(0x6f44f5646c2a7334);
+            let mut buf = [zero; 3];
+            for x in &mut buf {
+                *x = rng.sample(distr);
+            }
+            assert_eq!(&buf, expected);
+        }
+
+        test_samples(
+            &StandardUniform,
+            0f32,
+            &[0.0035963655, 0.7346052, 0.09778172],
+        );
+        test_samples(
+            &StandardUniform,
+            0f64,
+            &[0.7346051961657583, 0.20298547462974248, 0.8166436635290655],
+        );
+        test_samples(&OpenClosed01, 0f32, &[0.003596425, 0.73460525, 0.09778178]);
+        test_samples(
+            &OpenClosed01,
+            0f64,
+            &[0.7346051961657584, 0.2029854746297426, 0.8166436635290656],
+        );
+        test_samples(&Open01, 0f32, &[0.0035963655, 0.73460525, 0.09778172]);
+        test_samples(
+            &Open01,
+            0f64,
+            &[0.7346051961657584, 0.20298547462974248, 0.8166436635290656],
+        );
+
+        test_samples(&StandardUniform, 0u8, &[108, 13, 251]);
+        test_samples(
+            &StandardUniform,
+            0i64,
+            &[
+                -4895670024822607508,
+                3744421101175323131,
+                -3382327413172305101,
+            ],
+        );
+        test_samples(&StandardUniform, false, &[false, true, false]);
+        test_samples(
+            &StandardUniform,
+            'a',
+            &['\u{f9f}', '\u{c7f1f}', '\u{1b0c3}'],
+        );
+    }
+
+    #[test]
+    fn value_stability_uniform() {
+        fn test_samples<T: SampleUniformV1 + Copy + core::fmt::Debug + PartialEq>(
+            lb: T,
+            ub: T,
+            expected: &[T],
+        ) {
+            let mut rng = crate::test::rng(897);
+            let mut buf = [lb; 3];
+            let distr = Uniform::new_inclusive(lb, ub).unwrap();
+            for x in &mut buf {
+                *x = rng.sample(distr);
+            }
+            assert_eq!(&buf, expected);
+        }
+
+        test_samples(-105i8, 111, &[-99, -48, 107]);
+        test_samples(2i16, 1352, &[43, 361, 1325]);
+        test_samples(-313853i32, 13513, &[-303803, -226673, 6912]);
+        test_samples(131521i64, 6542165, &[1838724, 5384489, 4893692]);
+        test_samples(
+            i128::MIN,
+            -1,
+            &[
+                -30725222750250982319765550926688025855,
+                -75088619368053423329503924805178012357,
+                -64950748766625548510467638647674468829,
+            ],
+        );
+        test_samples(11u8, 218, &[17, 66, 214]);
+        test_samples(11u64, 218, &[66, 181, 165]);
+        test_samples(11usize, 218, &[17, 66, 214]);
+        test_samples(0f32, 1e-2f32, &[0.00030701043, 0.002663075, 0.009798331]);
+        test_samples(
+            -1e10f64,
+            1e10f64,
+            &[-4673848682.87155, 6388267422.932356, 4857075081.198345],
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn value_stability_weighted_index() {
+        fn test_samples<X, I>(weights: I, expected: &[usize])
+        where
+            X: SampleUniformV1 + PartialOrd + crate::distr::weighted::Weight,
+            I: IntoIterator,
+            I::Item: SampleBorrow<X>,
+        {
+            let distr = WeightedIndex::new(weights).unwrap();
+            let mut rng = crate::test::rng(701);
+            let mut buf = [0; 10];
+            for r in buf.iter_mut() {
+                *r = rng.sample(&distr);
+            }
+            assert_eq!(buf, expected);
+        }
+
+        test_samples(
+            [1i32, 1, 1, 1, 1, 1, 1, 1, 1],
+            &[0, 6, 2, 6, 3, 4, 7, 8, 2, 5],
+        );
+        test_samples([0.7f32, 0.1, 0.1, 0.1], &[0, 0, 0, 1, 0, 0, 2, 3, 0, 0]);
+        test_samples(
+            [1.0f64, 0.999, 0.998, 0.997],
+            &[2, 2, 1, 3, 2, 1, 3, 3, 2, 1],
+        );
+    }
+
+    #[test]
+    #[cfg(target_pointer_width = "64")]
+    fn value_stability_large_usize() {
+        let mut rng = crate::test::rng(898);
+        let distr = Uniform::new_inclusive(1usize << 40, usize::MAX).unwrap();
+        let buf = [(); 3].map(|()| rng.sample(distr));
+        assert_eq!(
+            buf,
+            [
+                8286123973205913406,
+                6953664451895126280,
+                14735554518342421953
+            ]
+        );
+
+        #[cfg(feature = "alloc")]
+        {
+            let res = sample_indices(&mut rng, 1 << 40, 4);
+            assert_eq!(
+                res.into_vec(),
+                [719561902969, 23719209423, 134593646532, 216778138828]
+            );
+        }
+    }
+
+    #[test]
+    fn value_stability_slice() {
+        let mut r = crate::test::rng(413);
+        let chars = [
+            'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', 'l', 'm', 'n',
+        ];
+        let mut nums = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+
+        assert_eq!(choose(&chars, &mut r), Some(&'l'));
+        assert_eq!(choose_mut(&mut nums, &mut r), Some(&mut 3));
+        assert_eq!(choose(&chars[..0], &mut r), None);
+
+        #[cfg(feature = "alloc")]
+        {
+            let mut r = crate::test::rng(415);
+            let v: Vec<char> = choose_multiple(&chars, &mut r, 4).cloned().collect();
+            assert_eq!(v, ['i', 'b', 'g', 'd']);
+            assert_eq!(choose_multiple(&chars, &mut r, 20).len(), chars.len());
+        }
+
+        let mut r = crate::test::rng(414);
+        shuffle(&mut nums, &mut r);
+        assert_eq!(nums, [5, 11, 0, 8, 7, 12, 6, 4, 9, 3, 1, 2, 10]);
+        nums = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let res = partial_shuffle(&mut nums, &mut r, 6);
+        assert_eq!(res.0, &mut [7, 12, 6, 8, 1, 9]);
+        assert_eq!(res.1, &mut [0, 11, 2, 3, 4, 5, 10]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn value_stability_sample_indices() {
+        let do_test = |length, amount, values: &[u32]| {
+            let mut buf = [0u32; 8];
+            let mut rng = crate::test::rng(410);
+
+            let res = sample_indices(&mut rng, length, amount);
+            let len = res.len().min(buf.len());
+            for (x, y) in res.into_iter().zip(buf.iter_mut()) {
+                *y = x as u32;
+            }
+            assert_eq!(
+                &buf[0..len],
+                values,
+                "failed sampling {}, {}",
+                length,
+                amount
+            );
+        };
+
+        do_test(10, 6, &[0, 9, 5, 4, 6, 8]); // floyd
+        do_test(25, 10, &[24, 20, 19, 9, 22, 16, 0, 14]); // floyd
+        do_test(300, 8, &[30, 283, 243, 150, 218, 240, 1, 189]); // floyd
+        do_test(300, 80, &[31, 289, 248, 154, 221, 243, 7, 192]); // inplace
+        do_test(300, 180, &[31, 289, 248, 154, 221, 243, 7, 192]); // inplace
+        do_test(
+            1_000_000,
+            8,
+            &[103717, 963485, 826422, 509101, 736394, 807035, 5327, 632573],
+        ); // floyd
+        do_test(
+            1_000_000,
+            180,
+            &[103718, 963490, 826426, 509103, 736396, 807036, 5327, 632573],
+        ); // rejection
+    }
+}